@@ -0,0 +1,50 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+// 逐项求逆的拉格朗日插值，即批量求逆之前的实现
+fn reconstruct_per_term(shares: &[(Scalar, Scalar)]) -> Scalar {
+    let mut value = Scalar::ZERO;
+    for (i, &(x_i, y_i)) in shares.iter().enumerate() {
+        let mut numerator = Scalar::ONE;
+        let mut denominator = Scalar::ONE;
+        for (j, &(x_j, _)) in shares.iter().enumerate() {
+            if i != j {
+                numerator *= -x_j;
+                denominator *= x_i - x_j;
+            }
+        }
+        value += y_i * numerator * denominator.invert().unwrap();
+    }
+    value
+}
+
+// 比较逐项求逆与批量求逆的恢复耗时
+// 运行方式：cargo bench --bench batch_inversion
+fn main() {
+    let mut rng = OsRng;
+    let t = 64;
+    let rounds = 200;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, t, t, &mut rng);
+    assert_eq!(reconstruct_per_term(&shares), reconstruct_secret(&shares));
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        std::hint::black_box(reconstruct_per_term(std::hint::black_box(&shares)));
+    }
+    let per_term = start.elapsed() / rounds;
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        std::hint::black_box(reconstruct_secret(std::hint::black_box(&shares)));
+    }
+    let batched = start.elapsed() / rounds;
+
+    println!("t = {}", t);
+    println!("  per-term inversion: {:?}", per_term);
+    println!("  batched inversion:  {:?}", batched);
+    println!("  speedup: {:.2}x", per_term.as_secs_f64() / batched.as_secs_f64());
+}
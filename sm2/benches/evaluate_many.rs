@@ -0,0 +1,39 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::polynomial::Polynomial;
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+// 比较逐点求值与 evaluate_many 的耗时
+// 运行方式：cargo bench --bench evaluate_many
+fn main() {
+    let mut rng = OsRng;
+    let t = 128;
+    let n = 1000;
+    let rounds = 20;
+    let poly = Polynomial::new(Scalar::random(&mut rng), t - 1, &mut rng);
+
+    let consecutive: Vec<Scalar> = (1..=n as u64).map(Scalar::from).collect();
+    let random: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+
+    println!("t = {}, n = {}", t, n);
+    for (name, xs) in [("x = 1..=n", &consecutive), ("random x", &random)] {
+        let start = Instant::now();
+        for _ in 0..rounds {
+            let values: Vec<Scalar> = xs.iter().map(|&x| poly.evaluate(x)).collect();
+            std::hint::black_box(values);
+        }
+        let naive = start.elapsed() / rounds;
+
+        let start = Instant::now();
+        for _ in 0..rounds {
+            std::hint::black_box(poly.evaluate_many(xs));
+        }
+        let batch = start.elapsed() / rounds;
+
+        println!("{}:", name);
+        println!("  evaluate loop: {:?}", naive);
+        println!("  evaluate_many: {:?}", batch);
+        println!("  speedup: {:.2}x", naive.as_secs_f64() / batch.as_secs_f64());
+    }
+}
@@ -0,0 +1,37 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::polynomial::{FeldmanCommitter, Polynomial};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+// 比较逐个系数标量乘法与预计算表的 Feldman 承诺耗时
+// 运行方式：cargo bench --bench feldman_commit
+fn main() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let t = 128;
+    let rounds = 20;
+    let poly = Polynomial::new(Scalar::random(&mut rng), t - 1, &mut rng);
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        std::hint::black_box(poly.feldman_commit(g));
+    }
+    let naive = start.elapsed() / rounds;
+
+    let start = Instant::now();
+    let committer = FeldmanCommitter::new(g);
+    let setup = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        std::hint::black_box(committer.commit(&poly));
+    }
+    let table = start.elapsed() / rounds;
+
+    println!("t = {}", t);
+    println!("feldman_commit:          {:?}", naive);
+    println!("FeldmanCommitter::new:   {:?}", setup);
+    println!("FeldmanCommitter::commit {:?}", table);
+    println!("speedup: {:.2}x", naive.as_secs_f64() / table.as_secs_f64());
+}
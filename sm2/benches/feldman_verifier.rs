@@ -0,0 +1,36 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::secret_sharing::{generate_shares_with_feldman_vss, verify_share_with_feldman_vss, FeldmanVerifier};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+// 比较逐个调用 verify_share_with_feldman_vss 与复用 FeldmanVerifier 验证同一组承诺下大量份额的耗时
+// 运行方式：cargo bench --bench feldman_verifier
+fn main() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let (n, t) = (1000, 16);
+    let (shares, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), n, t, g, &mut rng);
+
+    let start = Instant::now();
+    for &share in &shares {
+        assert!(std::hint::black_box(verify_share_with_feldman_vss(share, &commitments, g)));
+    }
+    let standalone = start.elapsed();
+
+    let start = Instant::now();
+    let verifier = FeldmanVerifier::new(&commitments, g);
+    let setup = start.elapsed();
+
+    let start = Instant::now();
+    for &share in &shares {
+        assert!(std::hint::black_box(verifier.verify(share)));
+    }
+    let reused = start.elapsed();
+
+    println!("n = {}, t = {}", n, t);
+    println!("verify_share_with_feldman_vss: {:?}", standalone);
+    println!("FeldmanVerifier::new:          {:?}", setup);
+    println!("FeldmanVerifier::verify:       {:?}", reused);
+    println!("speedup (including setup): {:.2}x", standalone.as_secs_f64() / (setup + reused).as_secs_f64());
+}
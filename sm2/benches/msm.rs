@@ -0,0 +1,43 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::evaluate_commitments;
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+// 比较逐项计算 C_i * x^i 与多标量乘法计算承诺多项式的耗时
+// 运行方式：cargo bench --bench msm
+fn main() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let rounds = 20;
+
+    for len in [64, 256] {
+        let poly = Polynomial::new(Scalar::random(&mut rng), len - 1, &mut rng);
+        let commitments = poly.feldman_commit(g);
+        let x = Scalar::random(&mut rng);
+
+        let start = Instant::now();
+        for _ in 0..rounds {
+            let mut acc = ProjectivePoint::IDENTITY;
+            let mut power = Scalar::ONE;
+            for commitment in &commitments {
+                acc += *commitment * power;
+                power *= x;
+            }
+            std::hint::black_box(acc);
+        }
+        let naive = start.elapsed() / rounds;
+
+        let start = Instant::now();
+        for _ in 0..rounds {
+            std::hint::black_box(evaluate_commitments(&commitments, x));
+        }
+        let msm = start.elapsed() / rounds;
+
+        println!("len = {}", len);
+        println!("naive: {:?}", naive);
+        println!("msm:   {:?}", msm);
+        println!("speedup: {:.2}x", naive.as_secs_f64() / msm.as_secs_f64());
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::{generate_shares, generate_shares_with_feldman_vss, verify_share_with_feldman_vss, verify_shares_with_feldman_vss};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+// 比较顺序与 rayon 并行的份额生成和批量验证耗时
+// 运行方式：cargo bench --features rayon --bench parallel
+// 并行生成的每一块都要重新建立差分表，只有在多核机器上才会比顺序版本快
+fn main() {
+    let mut rng = OsRng;
+    let n = 10_000;
+    let t = 64;
+    let rounds = 5;
+    let secret = Scalar::random(&mut rng);
+    let xs: Vec<Scalar> = (1..=n as u64).map(Scalar::from).collect();
+    println!("n = {}, t = {}", n, t);
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        let poly = Polynomial::new(secret, t - 1, &mut rng);
+        std::hint::black_box(poly.evaluate_many(&xs));
+    }
+    let sequential = start.elapsed() / rounds;
+    let start = Instant::now();
+    for _ in 0..rounds {
+        std::hint::black_box(generate_shares(secret, n, t, &mut rng));
+    }
+    let parallel = start.elapsed() / rounds;
+    println!("generate:");
+    println!("  sequential: {:?}", sequential);
+    println!("  parallel:   {:?}", parallel);
+    println!("  speedup: {:.2}x", sequential.as_secs_f64() / parallel.as_secs_f64());
+
+    let g = ProjectivePoint::GENERATOR;
+    let (shares, commitments) = generate_shares_with_feldman_vss(secret, 1000, t, g, &mut rng);
+    let start = Instant::now();
+    let results: Vec<bool> = shares
+        .iter()
+        .map(|&share| verify_share_with_feldman_vss(share, &commitments, g))
+        .collect();
+    std::hint::black_box(results);
+    let sequential = start.elapsed();
+    let start = Instant::now();
+    std::hint::black_box(verify_shares_with_feldman_vss(&shares, &commitments, g));
+    let parallel = start.elapsed();
+    println!("verify 1000 shares:");
+    println!("  sequential: {:?}", sequential);
+    println!("  parallel:   {:?}", parallel);
+    println!("  speedup: {:.2}x", sequential.as_secs_f64() / parallel.as_secs_f64());
+}
@@ -0,0 +1,34 @@
+use std::time::Instant;
+use rand::rngs::OsRng;
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret, reconstruct_secret_fast};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+// 比较通用插值与利用 x = 0 结构的恢复耗时
+// 运行方式：cargo bench --bench reconstruct_fast
+fn main() {
+    let mut rng = OsRng;
+    let rounds = 200;
+    for t in [16, 64, 256] {
+        let secret = Scalar::random(&mut rng);
+        let shares = generate_shares(secret, t, t, &mut rng);
+        assert_eq!(reconstruct_secret_fast(&shares), Ok(reconstruct_secret(&shares)));
+
+        let start = Instant::now();
+        for _ in 0..rounds {
+            std::hint::black_box(reconstruct_secret(std::hint::black_box(&shares)));
+        }
+        let general = start.elapsed() / rounds;
+
+        let start = Instant::now();
+        for _ in 0..rounds {
+            std::hint::black_box(reconstruct_secret_fast(std::hint::black_box(&shares)).unwrap());
+        }
+        let fast = start.elapsed() / rounds;
+
+        println!("t = {}", t);
+        println!("  reconstruct_secret:      {:?}", general);
+        println!("  reconstruct_secret_fast: {:?}", fast);
+        println!("  speedup: {:.2}x", general.as_secs_f64() / fast.as_secs_f64());
+    }
+}
@@ -0,0 +1,36 @@
+/* shamir_secret_sharing 的 C 语言接口，需要启用 `capi` 特性。
+ *
+ * 标量均为 32 字节大端编码；每条份额记录为 64 字节：32 字节 x 坐标 || 32 字节 y 坐标。
+ * 所有函数成功时返回 SS_OK，失败时返回负的错误码且不写入输出缓冲区。
+ */
+
+#ifndef SHAMIR_SECRET_SHARING_H
+#define SHAMIR_SECRET_SHARING_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define SS_OK 0
+#define SS_ERR_NULL_POINTER -1
+#define SS_ERR_INVALID_THRESHOLD -2
+#define SS_ERR_BUFFER_TOO_SMALL -3
+#define SS_ERR_NON_CANONICAL -4
+#define SS_ERR_DUPLICATE_SHARE -5
+#define SS_ERR_INVALID_SHARES -6
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* 分享 secret_bytes 指向的 32 字节秘密，将 n 条份额记录写入 out_buf。
+ * out_len 至少为 64 * n。 */
+int ss_generate_shares(const uint8_t *secret_bytes, size_t n, size_t t, uint8_t *out_buf, size_t out_len);
+
+/* 从 shares_bytes 指向的 count 条份额记录恢复秘密，写入 out_secret 指向的 32 字节。 */
+int ss_reconstruct(const uint8_t *shares_bytes, size_t count, uint8_t *out_secret);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SHAMIR_SECRET_SHARING_H */
@@ -0,0 +1,23 @@
+//! 在 `no_std` + `alloc` 环境中使用秘密共享的最小示例，只用于编译检查
+
+#![no_std]
+
+extern crate alloc;
+
+use rand_core::{CryptoRng, RngCore};
+use shamir_secret_sharing::secret_sharing::{
+    generate_shares, generate_shares_with_feldman_vss, reconstruct_secret, verify_share_with_feldman_vss,
+};
+use sm2::{ProjectivePoint, Scalar};
+
+/// 生成份额、验证并恢复秘密，随机数生成器由调用者（例如硬件 TRNG 驱动）提供
+pub fn split_verify_reconstruct<R: RngCore + CryptoRng>(secret: Scalar, rng: &mut R) -> bool {
+    let g = ProjectivePoint::GENERATOR;
+    let (shares, commitments) = generate_shares_with_feldman_vss(secret, 5, 3, g, rng);
+    let all_valid = shares
+        .iter()
+        .all(|&share| verify_share_with_feldman_vss(share, &commitments, g));
+
+    let plain = generate_shares(secret, 3, 2, rng);
+    all_valid && reconstruct_secret(&shares[..3]) == secret && reconstruct_secret(&plain[1..]) == secret
+}
@@ -0,0 +1,111 @@
+//! 流式恢复秘密
+//!
+//! 份额逐个到达时，[`ReconstructionAccumulator`] 不必等到全部份额到齐才开始计算。
+//! 在 x = 0 处，第 i 个份额的拉格朗日系数为 λ_i = Π_{j≠i} x_j / Π_{j≠i} (x_j - x_i)，
+//! 它依赖于整个点集：每加入一个新份额，所有已有份额的系数都会改变。
+//! 累加器因此维护公共分子 P = Π x_j 和每个份额的分母 d_i = Π_{j≠i} (x_j - x_i)，
+//! 每次加入份额只需 O(k) 次乘法更新它们；[`ReconstructionAccumulator::try_finalize`] 时
+//! 再用 λ_i = P / (x_i * d_i) 对已累积的全部份额重新加权，只进行一次批量求逆。
+
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::PrimeField;
+use sm2::Scalar;
+use crate::error::SecretSharingError;
+use crate::secret_sharing::batch_invert;
+
+/// 逐个接收份额的秘密恢复累加器
+#[derive(Debug, Clone)]
+pub struct ReconstructionAccumulator<F: PrimeField = Scalar> {
+    // 已接收的份额 (x_i, y_i)
+    shares: Vec<(F, F)>,
+    // 与 shares 一一对应的分母 d_i = Π_{j≠i} (x_j - x_i)
+    denominators: Vec<F>,
+    // 公共分子 P = Π x_j
+    numerator: F,
+}
+
+impl<F: PrimeField> Default for ReconstructionAccumulator<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> ReconstructionAccumulator<F> {
+    /// 创建一个空的累加器
+    pub fn new() -> Self {
+        ReconstructionAccumulator {
+            shares: Vec::new(),
+            denominators: Vec::new(),
+            numerator: F::ONE,
+        }
+    }
+
+    /// 已接收的份额个数
+    pub fn len(&self) -> usize {
+        self.shares.len()
+    }
+
+    /// 是否尚未接收任何份额
+    pub fn is_empty(&self) -> bool {
+        self.shares.is_empty()
+    }
+
+    /// 加入一个份额，并更新所有份额的分母
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 份额的 x 坐标
+    /// * `y` - 份额的 y 坐标
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), SecretSharingError>` - x 为 0 时返回 `ZeroShareIndex`，
+    ///   与已有份额的 x 坐标重复时返回 `DuplicateShareIndex`；出错时累加器保持不变
+    pub fn add_share(&mut self, x: F, y: F) -> Result<(), SecretSharingError> {
+        if bool::from(x.is_zero()) {
+            return Err(SecretSharingError::ZeroShareIndex);
+        }
+        if self.shares.iter().any(|&(x_i, _)| x_i == x) {
+            return Err(SecretSharingError::DuplicateShareIndex);
+        }
+
+        let mut denominator = F::ONE;
+        for (&(x_i, _), d_i) in self.shares.iter().zip(&mut self.denominators) {
+            *d_i *= x - x_i;
+            denominator *= x_i - x;
+        }
+        self.shares.push((x, y));
+        self.denominators.push(denominator);
+        self.numerator *= x;
+        Ok(())
+    }
+
+    /// 在份额数量达到门限值时，用已累积的全部份额恢复秘密
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - 恢复秘密所需的最小份额数
+    ///
+    /// # Returns
+    ///
+    /// * `Option<F>` - 恢复的秘密；份额少于 t 个（或 t 为 0）时返回 None
+    pub fn try_finalize(&self, t: usize) -> Option<F> {
+        if t == 0 || self.shares.len() < t {
+            return None;
+        }
+        // λ_i = P / (x_i * d_i)，所有 x_i * d_i 一次批量求逆
+        let weights: Vec<F> = self
+            .shares
+            .iter()
+            .zip(&self.denominators)
+            .map(|(&(x_i, _), &d_i)| x_i * d_i)
+            .collect();
+        let inverses = batch_invert(&weights)?;
+        Some(
+            self.shares
+                .iter()
+                .zip(inverses)
+                .fold(F::ZERO, |acc, (&(_, y_i), inverse)| acc + y_i * self.numerator * inverse),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+use std::time::{SystemTime, UNIX_EPOCH};
+use sm2::Scalar;
+use sm3::{Digest, Sm3};
+use crate::secret_sharing::try_reconstruct_secret;
+
+/// 一次秘密恢复操作的审计记录，不包含秘密本身
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attestation {
+    /// 参与恢复的份额的 x 坐标，按数值从小到大排列
+    pub xs_used: Vec<Scalar>,
+    /// 恢复操作发生的时间（自 UNIX 纪元起的秒数）
+    pub timestamp: u64,
+    /// SM3(timestamp || xs_used) 摘要，将 x 坐标与时间绑定
+    pub transcript_hash: [u8; 32],
+}
+
+impl Attestation {
+    /// 根据排序后的 x 坐标和时间戳构造审计记录
+    fn new(mut xs_used: Vec<Scalar>, timestamp: u64) -> Self {
+        // 按照大端编码排序，即按数值排序
+        xs_used.sort_by_key(|x| x.to_bytes());
+        let transcript_hash = transcript_hash(&xs_used, timestamp);
+        Attestation {
+            xs_used,
+            timestamp,
+            transcript_hash,
+        }
+    }
+
+    /// 重新计算摘要，检查审计记录是否被篡改
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - 如果摘要与 x 坐标和时间戳一致，则返回 true；否则返回 false
+    pub fn verify(&self) -> bool {
+        self.transcript_hash == transcript_hash(&self.xs_used, self.timestamp)
+    }
+}
+
+/// 使用前 t 个份额恢复秘密，并返回记录了所用份额的审计记录
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `t` - 恢复秘密所需的最小份额数
+///
+/// # Returns
+///
+/// * `Option<(Scalar, Attestation)>` - 恢复的秘密和审计记录；t 为 0、份额不足 t 个，
+///   或所用份额的 x 坐标为 0 或重复时返回 None
+pub fn reconstruct_with_attestation(
+    shares: &[(Scalar, Scalar)],
+    t: usize,
+) -> Option<(Scalar, Attestation)> {
+    if t == 0 || shares.len() < t {
+        return None;
+    }
+
+    let used = &shares[..t];
+    let secret = try_reconstruct_secret(used).ok()?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let attestation = Attestation::new(used.iter().map(|&(x, _)| x).collect(), timestamp);
+
+    Some((secret, attestation))
+}
+
+// 计算 SM3(timestamp || x_1 || x_2 || ...)
+fn transcript_hash(xs: &[Scalar], timestamp: u64) -> [u8; 32] {
+    let mut hasher = Sm3::new();
+    hasher.update(timestamp.to_be_bytes());
+    for x in xs {
+        hasher.update(x.to_bytes());
+    }
+    hasher.finalize().into()
+}
@@ -0,0 +1,157 @@
+//! 秘密共享方案的构建器
+//!
+//! `secret_sharing` 模块中的函数参数较多（`secret, n, t, g, h, rng`），按位置传参时容易把 g 和 h 写反。
+//! [`SecretSharingBuilder`] 用命名的链式设置方法收集参数，并在执行前统一检查。
+
+use core::fmt;
+use sm2::elliptic_curve::group::Group;
+use sm2::ProjectivePoint;
+use rand::{CryptoRng, RngCore};
+use crate::error::SecretSharingError;
+use crate::polynomial::CoefficientField;
+use crate::secret_sharing::{
+    try_generate_shares, try_generate_shares_with_feldman_vss, try_generate_shares_with_pedersen_vss, FeldmanShares,
+    PedersenShares, ShamirShares,
+};
+
+/// 构建器参数不完整或不合法时返回的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderError {
+    /// 缺少必需的参数
+    MissingParameter(&'static str),
+    /// 生成元 h 是单位元或与 g 相同
+    InvalidGenerators,
+    /// 门限值或份额数量不合法
+    Invalid(SecretSharingError),
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderError::MissingParameter(name) => write!(f, "missing parameter `{}`", name),
+            BuilderError::InvalidGenerators => write!(f, "generator h must differ from g and the identity"),
+            BuilderError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuilderError {}
+
+impl From<SecretSharingError> for BuilderError {
+    fn from(e: SecretSharingError) -> Self {
+        BuilderError::Invalid(e)
+    }
+}
+
+/// 秘密共享方案的构建器
+///
+/// 必须设置 `secret`、`shares` 和 `threshold`；`generator_g` 默认为群的生成元，
+/// `generator_h` 只有 `split_pedersen` 需要。
+///
+/// 构建器持有秘密，因此不实现 `Copy`，`Debug` 输出也只表明是否已设置秘密。
+#[derive(Clone)]
+pub struct SecretSharingBuilder<G: Group = ProjectivePoint> {
+    secret: Option<G::Scalar>,
+    n: Option<usize>,
+    t: Option<usize>,
+    g: G,
+    h: Option<G>,
+}
+
+impl<G: Group> fmt::Debug for SecretSharingBuilder<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretSharingBuilder")
+            .field("secret", &self.secret.is_some())
+            .field("n", &self.n)
+            .field("t", &self.t)
+            .field("g", &self.g)
+            .field("h", &self.h)
+            .finish()
+    }
+}
+
+impl<G: Group> Default for SecretSharingBuilder<G>
+where
+    G::Scalar: CoefficientField,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Group> SecretSharingBuilder<G>
+where
+    G::Scalar: CoefficientField,
+{
+    /// 创建一个空的构建器
+    pub fn new() -> Self {
+        SecretSharingBuilder {
+            secret: None,
+            n: None,
+            t: None,
+            g: G::generator(),
+            h: None,
+        }
+    }
+
+    /// 设置要分享的秘密
+    pub fn secret(mut self, secret: G::Scalar) -> Self {
+        self.secret = Some(secret);
+        self
+    }
+
+    /// 设置份额的总数 n
+    pub fn shares(mut self, n: usize) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// 设置恢复秘密所需的最小份额数 t
+    pub fn threshold(mut self, t: usize) -> Self {
+        self.t = Some(t);
+        self
+    }
+
+    /// 设置承诺使用的生成元 g
+    pub fn generator_g(mut self, g: G) -> Self {
+        self.g = g;
+        self
+    }
+
+    /// 设置 Pedersen 承诺的第二个生成元 h，h 相对于 g 的离散对数必须无人知晓
+    pub fn generator_h(mut self, h: G) -> Self {
+        self.h = Some(h);
+        self
+    }
+
+    /// 生成 Shamir 份额，见 [`crate::secret_sharing::generate_shares`]
+    pub fn split<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Result<ShamirShares<G>, BuilderError> {
+        let (secret, n, t) = self.required()?;
+        try_generate_shares(secret, n, t, rng).map_err(|e| SecretSharingError::from(e).into())
+    }
+
+    /// 生成 Feldman 份额和承诺，见 [`crate::secret_sharing::generate_shares_with_feldman_vss`]
+    pub fn split_feldman<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Result<FeldmanShares<G>, BuilderError> {
+        let (secret, n, t) = self.required()?;
+        Ok(try_generate_shares_with_feldman_vss(secret, n, t, self.g, rng)?)
+    }
+
+    /// 生成 Pedersen 份额、承诺和盲化值，见 [`crate::secret_sharing::generate_shares_with_pedersen_vss`]
+    pub fn split_pedersen<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Result<PedersenShares<G>, BuilderError> {
+        let (secret, n, t) = self.required()?;
+        let h = self.h.ok_or(BuilderError::MissingParameter("generator_h"))?;
+        if bool::from(h.is_identity()) || h == self.g {
+            return Err(BuilderError::InvalidGenerators);
+        }
+        Ok(try_generate_shares_with_pedersen_vss(secret, n, t, self.g, h, rng)?)
+    }
+
+    // 取出所有方案都需要的参数
+    fn required(&self) -> Result<(G::Scalar, usize, usize), BuilderError> {
+        let secret = self.secret.ok_or(BuilderError::MissingParameter("secret"))?;
+        let n = self.n.ok_or(BuilderError::MissingParameter("shares"))?;
+        let t = self.t.ok_or(BuilderError::MissingParameter("threshold"))?;
+        Ok((secret, n, t))
+    }
+}
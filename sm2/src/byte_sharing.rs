@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+use sm2::Scalar;
+use rand::{CryptoRng, RngCore};
+use crate::error::{ReconstructError, ShareError};
+use crate::polynomial::Polynomial;
+use crate::secret_sharing::{interpolate_at, scalar_from_bytes, validate_threshold};
+
+// 每个分块的字节数，31 字节的值一定小于群的阶
+const CHUNK_LEN: usize = 31;
+// 长度前缀的字节数
+const LENGTH_PREFIX_LEN: usize = 4;
+
+/// 字节串秘密的份额：同一个 x 坐标上每个分块各自的 y 值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteShare {
+    index: Scalar,
+    values: Vec<Scalar>,
+}
+
+impl ByteShare {
+    /// 由 x 坐标和每个分块的 y 值创建份额
+    pub fn new(index: Scalar, values: Vec<Scalar>) -> Self {
+        ByteShare { index, values }
+    }
+
+    /// 返回份额的 x 坐标
+    pub fn index(&self) -> Scalar {
+        self.index
+    }
+
+    /// 返回每个分块的 y 值
+    pub fn values(&self) -> &[Scalar] {
+        &self.values
+    }
+}
+
+/// 将任意字节串秘密（如 AES 密钥、种子、口令）分成 n 个份额，至少需要 t 个份额才能恢复
+///
+/// 秘密前加上 4 字节大端长度前缀后按 31 字节分块，每个分块作为一个标量独立进行 Shamir 分享，
+/// 所有分块共享相同的 x 坐标。长度前缀保证恢复时不会丢失末尾的零字节。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的字节串
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<ByteShare>, ShareError>` - 份额列表；参数不合法时返回错误
+pub fn split_bytes<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<ByteShare>, ShareError> {
+    validate_threshold(n, t)?;
+
+    // 长度前缀 || 秘密
+    let mut payload = Vec::with_capacity(LENGTH_PREFIX_LEN + secret.len());
+    payload.extend_from_slice(&(secret.len() as u32).to_be_bytes());
+    payload.extend_from_slice(secret);
+
+    // 每个分块一个多项式
+    let polys: Vec<Polynomial> = payload
+        .chunks(CHUNK_LEN)
+        .map(|chunk| Polynomial::new(chunk_to_scalar(chunk), t - 1, rng))
+        .collect();
+
+    Ok((1..=n)
+        .map(|i| {
+            let x = Scalar::from(i as u64);
+            ByteShare::new(x, polys.iter().map(|poly| poly.evaluate(x)).collect())
+        })
+        .collect())
+}
+
+/// 从字节串份额恢复原始字节串
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+///
+/// # Returns
+///
+/// * `Result<Vec<u8>, ReconstructError>` - 恢复的字节串；份额结构不一致或解码失败时返回错误
+pub fn reconstruct_bytes(shares: &[ByteShare]) -> Result<Vec<u8>, ReconstructError> {
+    let chunks = shares
+        .first()
+        .ok_or(ReconstructError::InsufficientShares)?
+        .values
+        .len();
+    if shares.iter().any(|share| share.values.len() != chunks) {
+        return Err(ReconstructError::InconsistentShares);
+    }
+
+    // 逐个分块进行插值
+    let mut payload = Vec::with_capacity(chunks * CHUNK_LEN);
+    for k in 0..chunks {
+        let points: Vec<(Scalar, Scalar)> = shares
+            .iter()
+            .map(|share| (share.index, share.values[k]))
+            .collect();
+        let chunk = interpolate_at(&points, Scalar::ZERO)?.to_bytes();
+        // 合法的分块最高字节一定为 0
+        if chunk[0] != 0 {
+            return Err(ReconstructError::NonCanonicalEncoding);
+        }
+        payload.extend_from_slice(&chunk[1..]);
+    }
+
+    // 读取长度前缀，去掉最后一个分块的填充
+    if payload.len() < LENGTH_PREFIX_LEN {
+        return Err(ReconstructError::NonCanonicalEncoding);
+    }
+    let mut len = [0u8; LENGTH_PREFIX_LEN];
+    len.copy_from_slice(&payload[..LENGTH_PREFIX_LEN]);
+    let len = u32::from_be_bytes(len) as usize;
+
+    let body = &payload[LENGTH_PREFIX_LEN..];
+    // 分块数量必须恰好容纳长度前缀和秘密
+    if len > body.len() || (LENGTH_PREFIX_LEN + len).div_ceil(CHUNK_LEN) != chunks {
+        return Err(ReconstructError::NonCanonicalEncoding);
+    }
+    Ok(body[..len].to_vec())
+}
+
+// 将不超过 31 字节的分块编码为标量：最高字节为 0，分块紧随其后，不足 31 字节时在末尾补零
+fn chunk_to_scalar(chunk: &[u8]) -> Scalar {
+    let mut bytes = [0u8; 32];
+    bytes[1..1 + chunk.len()].copy_from_slice(chunk);
+    scalar_from_bytes(&bytes).expect("31-byte values are always canonical")
+}
@@ -0,0 +1,164 @@
+//! serde 支持以及 CBOR、bincode 编码
+//!
+//! [`ShareSet`] 和承诺列表 [`Commitments`] 的 serde 实现直接复用已有的紧凑二进制编码
+//! （`ShareSet::to_bytes` 和 `commitments_to_bytes`），序列化为一个字节串，
+//! 反序列化时执行相同的检查。因此在任何格式下，同一份数据的内容都与二进制编码一致，
+//! 不同格式之间可以互相转换而不会丢失信息。
+//!
+//! - `cbor` 特性提供 [`to_cbor`] 和 [`from_cbor`]，基于 `ciborium`，字节串使用定长编码；
+//! - `bincode` 特性提供 [`to_bincode`] 和 [`from_bincode`]，使用固定长度整数编码并拒绝多余的字节，
+//!   编码结果是确定的。
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+#[cfg(any(feature = "cbor", feature = "bincode"))]
+use serde::de::DeserializeOwned;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sm2::ProjectivePoint;
+use crate::error::DecodeError;
+use crate::secret_sharing::{commitments_from_bytes, commitments_to_bytes};
+use crate::share::ShareSet;
+
+/// 编码或解码失败
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecError {
+    /// 序列化失败
+    Encode,
+    /// 输入不是合法的编码，或内容未通过检查
+    Decode,
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Encode => write!(f, "failed to encode value"),
+            CodecError::Decode => write!(f, "failed to decode value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CodecError {}
+
+/// Feldman 承诺列表，序列化为 `commitments_to_bytes` 的编码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commitments(pub Vec<ProjectivePoint>);
+
+// 解码字节序列时最多预分配的字节数
+const MAX_PREALLOCATION: usize = 4096;
+
+// 把字节串交给已有的二进制解码函数；同时接受字节序列，兼容把字节串编码为数组的格式（如 JSON）
+struct BytesVisitor<T> {
+    decode: fn(&[u8]) -> Result<T, DecodeError>,
+    expecting: &'static str,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expecting)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        (self.decode)(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        // 长度提示来自输入，不可信，预分配的容量设有上限，更长的输入在读取时再扩容
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATION));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+impl Serialize for ShareSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for ShareSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor {
+            decode: ShareSet::from_bytes,
+            expecting: "an encoded share set",
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl Serialize for Commitments {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&commitments_to_bytes(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Commitments {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor {
+            decode: |bytes| commitments_from_bytes(bytes).map(Commitments),
+            expecting: "encoded commitments",
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// 编码为 CBOR
+///
+/// # Returns
+///
+/// * `Result<Vec<u8>, CodecError>` - CBOR 编码；值的序列化失败时返回 `Encode`
+#[cfg(feature = "cbor")]
+pub fn to_cbor<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CodecError> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).map_err(|_| CodecError::Encode)?;
+    Ok(bytes)
+}
+
+/// 从 CBOR 解码
+///
+/// # Returns
+///
+/// * `Result<T, CodecError>` - 解码得到的值；输入不是合法的 CBOR 或内容未通过检查时返回 `Decode`
+#[cfg(feature = "cbor")]
+pub fn from_cbor<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, CodecError> {
+    ciborium::from_reader(bytes).map_err(|_| CodecError::Decode)
+}
+
+// 固定长度整数编码、小端序，拒绝多余的字节
+#[cfg(feature = "bincode")]
+fn bincode_options() -> impl bincode::Options {
+    use bincode::Options;
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_little_endian()
+        .reject_trailing_bytes()
+}
+
+/// 编码为 bincode，相同的值总是得到相同的字节串
+///
+/// # Returns
+///
+/// * `Result<Vec<u8>, CodecError>` - bincode 编码；值的序列化失败时返回 `Encode`
+#[cfg(feature = "bincode")]
+pub fn to_bincode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CodecError> {
+    use bincode::Options;
+    bincode_options().serialize(value).map_err(|_| CodecError::Encode)
+}
+
+/// 从 bincode 解码
+///
+/// # Returns
+///
+/// * `Result<T, CodecError>` - 解码得到的值；输入截断、有多余的字节或内容未通过检查时返回 `Decode`
+#[cfg(feature = "bincode")]
+pub fn from_bincode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, CodecError> {
+    use bincode::Options;
+    bincode_options().deserialize(bytes).map_err(|_| CodecError::Decode)
+}
@@ -0,0 +1,226 @@
+//! 无可信分发者的分布式密钥生成 (DKG)
+//!
+//! 协议流程（Pedersen 的 DKG，使用 Feldman 承诺）：
+//!
+//! 1. n 个参与者的编号为 1 到 n。每个参与者 i 调用 [`DkgParticipant::new`] 生成自己的 t-1 次随机多项式 p_i，
+//!    并广播 Feldman 承诺 [`DkgParticipant::commitments`]。
+//! 2. 参与者 i 通过安全信道把子份额 p_i(j)（[`DkgParticipant::subshare_for`]）发送给每个参与者 j。
+//! 3. 参与者 j 用 i 广播的承诺验证收到的子份额（[`DkgParticipant::receive_subshare`]），验证失败时应公开投诉 i。
+//! 4. 收齐其余 n-1 个参与者的子份额后，[`DkgParticipant::finalize`] 把它们与自己的 p_j(j) 相加，
+//!    得到联合多项式 Σ p_i 在 x = j 处的值，即最终份额。
+//!
+//! 联合私钥 Σ p_i(0) 从未在任何一处出现，对应的公钥由 [`combine_public_key`] 将各参与者的常数项承诺相加得到。
+
+use alloc::vec::Vec;
+use core::fmt;
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use crate::polynomial::Polynomial;
+use crate::secret_sharing::{degree_or_panic, verify_commitment_degree, verify_share_with_feldman_vss};
+
+/// 处理子份额时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DkgError {
+    /// 发送方编号为 0、超出 n 或是接收者自己
+    UnknownDealer { dealer: usize },
+    /// 已经收到过该发送方的子份额
+    DuplicateDealer { dealer: usize },
+    /// 发送方广播的承诺个数与门限值不符或次数不足
+    InvalidCommitments { dealer: usize },
+    /// 子份额与发送方广播的承诺不一致
+    InvalidSubshare { dealer: usize },
+    /// 尚未收齐全部子份额
+    MissingSubshares { received: usize, expected: usize },
+    /// 请求为编号 0 计算子份额，p_i(0) 就是参与者 i 的秘密贡献
+    ZeroShareIndex,
+}
+
+impl fmt::Display for DkgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DkgError::UnknownDealer { dealer } => write!(f, "unknown dealer {}", dealer),
+            DkgError::DuplicateDealer { dealer } => write!(f, "already received a subshare from dealer {}", dealer),
+            DkgError::InvalidCommitments { dealer } => write!(f, "dealer {} published invalid commitments", dealer),
+            DkgError::InvalidSubshare { dealer } => write!(f, "subshare from dealer {} does not match its commitments", dealer),
+            DkgError::MissingSubshares { received, expected } => {
+                write!(f, "received {} of {} subshares", received, expected)
+            }
+            DkgError::ZeroShareIndex => write!(f, "recipient index must be nonzero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DkgError {}
+
+/// DKG 中的一个参与者，同时充当分发者和接收者
+pub struct DkgParticipant {
+    // 参与者编号，也是最终份额的 x 坐标
+    index: usize,
+    n: usize,
+    t: usize,
+    polynomial: Polynomial,
+    commitments: Vec<ProjectivePoint>,
+    // 已验证的子份额 (发送方编号, 子份额)
+    received: Vec<(usize, Scalar)>,
+}
+
+impl DkgParticipant {
+    /// 创建编号为 `index` 的参与者，并生成其随机多项式和 Feldman 承诺
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 参与者编号，范围为 1 到 n
+    /// * `n` - 参与者总数
+    /// * `t` - 恢复联合私钥所需的最小份额数
+    /// * `rng` - 随机数生成器
+    ///
+    /// # Panics
+    ///
+    /// 如果 `index` 不在 1 到 n 之间，或 t 为 0、t 大于 n 或超过 `MAX_THRESHOLD` 则 panic
+    pub fn new<R: RngCore + CryptoRng>(index: usize, n: usize, t: usize, rng: &mut R) -> Self {
+        assert!(index >= 1 && index <= n, "participant index must be in 1..=n");
+        let degree = degree_or_panic(t);
+        assert!(t <= n, "threshold must be in 1..=n");
+
+        let polynomial = Polynomial::new(Scalar::random(&mut *rng), degree, rng);
+        let commitments = polynomial.feldman_commit(ProjectivePoint::GENERATOR);
+        DkgParticipant {
+            index,
+            n,
+            t,
+            polynomial,
+            commitments,
+            received: Vec::with_capacity(n - 1),
+        }
+    }
+
+    /// 参与者编号
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// 需要广播的 Feldman 承诺，生成元为 SM2 基点
+    pub fn commitments(&self) -> &[ProjectivePoint] {
+        &self.commitments
+    }
+
+    /// 计算发送给参与者 `recipient` 的子份额 p_i(recipient)，必须通过安全信道发送
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Scalar, DkgError>` - 子份额；`recipient` 为 0 时返回 `ZeroShareIndex`，
+    ///   因为 p_i(0) 就是本参与者对联合私钥的秘密贡献
+    pub fn subshare_for(&self, recipient: usize) -> Result<Scalar, DkgError> {
+        if recipient == 0 {
+            return Err(DkgError::ZeroShareIndex);
+        }
+        Ok(self.polynomial.evaluate(Scalar::from(recipient as u64)))
+    }
+
+    /// 验证并保存来自参与者 `dealer` 的子份额
+    ///
+    /// # Arguments
+    ///
+    /// * `dealer` - 发送方编号
+    /// * `subshare` - 收到的子份额
+    /// * `dealer_commitments` - 发送方广播的 Feldman 承诺
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), DkgError>` - 子份额有效时返回 `Ok(())`，否则不保存并返回错误
+    pub fn receive_subshare(
+        &mut self,
+        dealer: usize,
+        subshare: Scalar,
+        dealer_commitments: &[ProjectivePoint],
+    ) -> Result<(), DkgError> {
+        if dealer == 0 || dealer > self.n || dealer == self.index {
+            return Err(DkgError::UnknownDealer { dealer });
+        }
+        if self.received.iter().any(|&(d, _)| d == dealer) {
+            return Err(DkgError::DuplicateDealer { dealer });
+        }
+        verify_commitment_degree(dealer_commitments, self.t).map_err(|_| DkgError::InvalidCommitments { dealer })?;
+
+        let x = Scalar::from(self.index as u64);
+        if !verify_share_with_feldman_vss((x, subshare), dealer_commitments, ProjectivePoint::GENERATOR) {
+            return Err(DkgError::InvalidSubshare { dealer });
+        }
+        self.received.push((dealer, subshare));
+        Ok(())
+    }
+
+    /// 合并自己的子份额和收到的全部子份额，得到最终份额
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(Scalar, Scalar), DkgError>` - 最终份额 (x, y)，x 为参与者编号；
+    ///   未收齐其余 n-1 个参与者的子份额时返回 `MissingSubshares`
+    pub fn finalize(&self) -> Result<(Scalar, Scalar), DkgError> {
+        if self.received.len() != self.n - 1 {
+            return Err(DkgError::MissingSubshares {
+                received: self.received.len(),
+                expected: self.n - 1,
+            });
+        }
+        let own = self.polynomial.evaluate(Scalar::from(self.index as u64));
+        let y = self.received.iter().fold(own, |acc, &(_, subshare)| acc + subshare);
+        Ok((Scalar::from(self.index as u64), y))
+    }
+}
+
+/// 将各参与者的常数项承诺相加，得到联合公钥 G * Σ p_i(0)
+///
+/// # Arguments
+///
+/// * `all_commitments` - 每个参与者广播的 Feldman 承诺
+///
+/// # Returns
+///
+/// * `ProjectivePoint` - 联合公钥
+pub fn combine_public_key(all_commitments: &[Vec<ProjectivePoint>]) -> ProjectivePoint {
+    all_commitments
+        .iter()
+        .filter_map(|commitments| commitments.first())
+        .fold(ProjectivePoint::IDENTITY, |acc, c| acc + c)
+}
+
+/// 接收者一次性核对从所有分发者收到的子份额，找出应当公开投诉的分发者
+///
+/// 对每个 (分发者 j, 子份额)，用分发者 j 广播的承诺以 `verify_share_with_feldman_vss` 验证子份额，
+/// 并检查承诺的个数与门限值相符。与逐个调用 [`DkgParticipant::receive_subshare`] 不同，
+/// 本函数不会在第一个失败处停止，而是汇总全部结果。
+///
+/// # Arguments
+///
+/// * `recipient` - 接收者编号，即子份额的 x 坐标
+/// * `t` - 门限值
+/// * `subshares` - 收到的 (分发者编号, 子份额)
+/// * `all_commitments` - 所有分发者广播的承诺，第 j-1 项属于编号为 j 的分发者
+///
+/// # Returns
+///
+/// * `Vec<usize>` - 子份额验证失败的分发者编号，按 `subshares` 中的顺序排列；
+///   分发者编号为 0 或没有对应的承诺时同样视为失败。全部通过时返回空列表
+pub fn cross_check_subshares(
+    recipient: usize,
+    t: usize,
+    subshares: &[(usize, Scalar)],
+    all_commitments: &[Vec<ProjectivePoint>],
+) -> Vec<usize> {
+    let x = Scalar::from(recipient as u64);
+    subshares
+        .iter()
+        .filter(|&&(dealer, subshare)| {
+            let commitments = match dealer.checked_sub(1).and_then(|i| all_commitments.get(i)) {
+                Some(commitments) => commitments,
+                None => return true,
+            };
+            verify_commitment_degree(commitments, t).is_err()
+                || !verify_share_with_feldman_vss((x, subshare), commitments, ProjectivePoint::GENERATOR)
+        })
+        .map(|&(dealer, _)| dealer)
+        .collect()
+}
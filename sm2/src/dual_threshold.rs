@@ -0,0 +1,161 @@
+//! 双门限秘密共享：t 个份额恢复秘密，d ≤ t 个份额即可检查份额是否被篡改
+//!
+//! 不超过 t 个份额总是位于某个 t-1 次多项式上，只靠秘密多项式本身无法发现篡改。
+//! 因此分发者除了秘密多项式 f（t-1 次）之外，还选择两个 d-2 次的校验多项式：
+//!
+//! - g，其常数项为非零的校验密钥 κ，第 i 个份额附带校验值 c_i = g(x_i)；
+//! - b，第 i 个份额附带标签 τ_i = κ * f(x_i) + b(x_i)。
+//!
+//! 检查时用 d 个份额的校验值插值出 g，多出的一个点验证它们确实位于 d-2 次多项式上，得到 κ = g(0)；
+//! 再检查 τ_i - κ * y_i 是否也位于同一个 d-2 次多项式上。篡改任意一个份额的 y、c 或 τ，
+//! 除非篡改者知道 κ，都会使其中一项检查失败。两项检查都复用 `verify_share_set_consistency`。
+//!
+//! 任意 d-1 个持有者合谋即可算出 κ，从而伪造能通过检查的份额，因此检查只能防范外部篡改，
+//! 以及少于 d-1 个持有者的合谋。校验值和标签不泄露秘密：不足 t 个份额的 y 值与 t-1 次 Shamir 分享相同。
+
+use alloc::vec::Vec;
+use core::fmt;
+use rand::{CryptoRng, RngCore};
+use crate::error::{ReconstructError, ShareError};
+use crate::polynomial::{CoefficientField, Polynomial};
+use crate::secret_sharing::{interpolate_at, validate_share_count, verify_share_set_consistency};
+
+/// 生成双门限份额时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DualThresholdError {
+    /// 检查门限 d 小于 2 或大于恢复门限 t
+    InvalidCheckThreshold,
+    /// 门限值或份额数量不合法
+    Invalid(ShareError),
+}
+
+impl fmt::Display for DualThresholdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DualThresholdError::InvalidCheckThreshold => {
+                write!(f, "check threshold must be at least 2 and at most the threshold")
+            }
+            DualThresholdError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DualThresholdError {}
+
+impl From<ShareError> for DualThresholdError {
+    fn from(e: ShareError) -> Self {
+        DualThresholdError::Invalid(e)
+    }
+}
+
+/// 双门限份额
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DualThresholdShare<F> {
+    /// x 坐标
+    pub x: F,
+    /// 秘密多项式在 x 处的值 f(x)
+    pub value: F,
+    /// 校验值 g(x)
+    pub check: F,
+    /// 标签 κ * f(x) + b(x)
+    pub tag: F,
+}
+
+impl<F: CoefficientField> DualThresholdShare<F> {
+    /// 返回普通的 Shamir 份额 (x, f(x))，可直接用于 `reconstruct_secret`
+    pub fn share(&self) -> (F, F) {
+        (self.x, self.value)
+    }
+}
+
+/// 生成 n 个双门限份额
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `d` - 检查篡改所需的最小份额数，2 ≤ d ≤ t
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<DualThresholdShare<F>>, DualThresholdError>` - x 坐标为 1 到 n 的份额；
+///   门限值不合法时返回 `Invalid`，d 不在 2..=t 范围内时返回 `InvalidCheckThreshold`
+pub fn generate_dual_threshold<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    n: usize,
+    t: usize,
+    d: usize,
+    rng: &mut R,
+) -> Result<Vec<DualThresholdShare<F>>, DualThresholdError> {
+    validate_share_count::<F>(n, t)?;
+    if d < 2 || d > t {
+        return Err(DualThresholdError::InvalidCheckThreshold);
+    }
+
+    // κ = 0 时标签不依赖 y，必须排除
+    let kappa = loop {
+        let candidate = F::random(&mut *rng);
+        if !bool::from(candidate.is_zero()) {
+            break candidate;
+        }
+    };
+    let f = Polynomial::new(secret, t - 1, rng);
+    let g = Polynomial::new(kappa, d - 2, rng);
+    let b = Polynomial::new(F::random(&mut *rng), d - 2, rng);
+
+    Ok((1..=n)
+        .map(|i| {
+            let x = F::from(i as u64);
+            let value = f.evaluate(x);
+            DualThresholdShare {
+                x,
+                value,
+                check: g.evaluate(x),
+                tag: kappa * value + b.evaluate(x),
+            }
+        })
+        .collect())
+}
+
+/// 用至少 d 个份额检查是否有份额被篡改
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表，至少 d 个
+/// * `d` - 生成份额时使用的检查门限
+///
+/// # Returns
+///
+/// * `Result<bool, ReconstructError>` - 发现篡改时返回 true，所有检查通过时返回 false；
+///   d 小于 2 或份额少于 d 个时返回 `InsufficientShares`，存在 x 坐标为 0 的份额时返回 `ZeroShareIndex`，
+///   x 坐标重复时返回 `DuplicateShareIndex`
+pub fn detect_tampering<F: CoefficientField>(shares: &[DualThresholdShare<F>], d: usize) -> Result<bool, ReconstructError> {
+    if d < 2 || shares.len() < d {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    if shares.iter().any(|share| bool::from(share.x.is_zero())) {
+        return Err(ReconstructError::ZeroShareIndex);
+    }
+    let mut xs: Vec<F::Repr> = shares.iter().map(|share| share.x.to_repr()).collect();
+    xs.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+    if xs.windows(2).any(|w| w[0].as_ref() == w[1].as_ref()) {
+        return Err(ReconstructError::DuplicateShareIndex);
+    }
+
+    // 校验值必须位于同一个 d-2 次多项式上
+    let checks: Vec<(F, F)> = shares.iter().map(|share| (share.x, share.check)).collect();
+    if !verify_share_set_consistency(&checks, d - 1) {
+        return Ok(true);
+    }
+    let kappa = interpolate_at(&checks[..d - 1], F::ZERO)?;
+
+    // τ_i - κ * y_i = b(x_i) 也必须位于同一个 d-2 次多项式上
+    let masks: Vec<(F, F)> = shares
+        .iter()
+        .map(|share| (share.x, share.tag - kappa * share.value))
+        .collect();
+    Ok(!verify_share_set_consistency(&masks, d - 1))
+}
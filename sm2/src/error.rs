@@ -0,0 +1,197 @@
+use core::fmt;
+
+/// 恢复秘密时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconstructError {
+    /// 提供的份额少于门限值
+    InsufficientShares,
+    /// 份额的字节编码不是规范的标量编码
+    NonCanonicalEncoding,
+    /// 存在 x 坐标相同的份额
+    DuplicateShareIndex,
+    /// 份额之间的结构不一致（例如分块数量不同）
+    InconsistentShares,
+    /// 错误份额过多，无法纠正
+    TooManyErrors,
+    /// 请求在 x = 0 处签发份额，该值就是秘密本身
+    ZeroShareIndex,
+    /// 恢复出的秘密与常数项的承诺不符，说明有损坏的份额参与了恢复
+    CommitmentMismatch,
+}
+
+impl fmt::Display for ReconstructError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReconstructError::InsufficientShares => write!(f, "not enough shares to reconstruct"),
+            ReconstructError::NonCanonicalEncoding => write!(f, "share is not a canonical scalar encoding"),
+            ReconstructError::DuplicateShareIndex => write!(f, "duplicate share x-coordinate"),
+            ReconstructError::InconsistentShares => write!(f, "shares have inconsistent structure"),
+            ReconstructError::TooManyErrors => write!(f, "too many corrupt shares to correct"),
+            ReconstructError::ZeroShareIndex => write!(f, "share x-coordinate must be nonzero"),
+            ReconstructError::CommitmentMismatch => write!(f, "reconstructed secret does not match the commitment"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReconstructError {}
+
+/// 对两个 x 坐标不同的份额进行运算时返回的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexMismatch;
+
+impl fmt::Display for IndexMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "shares have different x-coordinates")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexMismatch {}
+
+/// 生成份额时可能出现的参数错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareError {
+    /// 份额总数 n 为 0
+    ZeroShares,
+    /// 门限值 t 为 0
+    ZeroThreshold,
+    /// 门限值 t 大于份额总数 n
+    ThresholdExceedsShares,
+    /// 门限值 t 超过 `MAX_THRESHOLD`
+    ThresholdTooLarge,
+    /// 份额的 x 坐标为 0，此时份额的值就是秘密本身
+    ZeroShareIndex,
+    /// 份额的 x 坐标有重复
+    DuplicateShareIndex,
+    /// 提供的份额少于门限值
+    InsufficientShares,
+}
+
+impl fmt::Display for ShareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShareError::ZeroShares => write!(f, "number of shares must be positive"),
+            ShareError::ZeroThreshold => write!(f, "threshold must be positive"),
+            ShareError::ThresholdExceedsShares => write!(f, "threshold exceeds number of shares"),
+            ShareError::ThresholdTooLarge => write!(f, "threshold exceeds the maximum supported threshold"),
+            ShareError::ZeroShareIndex => write!(f, "share x-coordinate must be nonzero"),
+            ShareError::DuplicateShareIndex => write!(f, "duplicate share x-coordinate"),
+            ShareError::InsufficientShares => write!(f, "fewer shares than the threshold"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShareError {}
+
+/// 解码字节串时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// 输入在预期长度之前结束
+    Truncated,
+    /// 输入在预期长度之后还有多余的字节
+    TrailingBytes,
+    /// 魔数或版本号不受支持
+    UnsupportedVersion,
+    /// 标量编码不规范（不小于群的阶）
+    NonCanonicalScalar,
+    /// 点编码无效或不在曲线上
+    InvalidPoint,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated => write!(f, "input is truncated"),
+            DecodeError::TrailingBytes => write!(f, "input has trailing bytes"),
+            DecodeError::UnsupportedVersion => write!(f, "unsupported encoding version"),
+            DecodeError::NonCanonicalScalar => write!(f, "non-canonical scalar encoding"),
+            DecodeError::InvalidPoint => write!(f, "invalid curve point encoding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// 分发者公布的承诺向量不合法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitmentError {
+    /// 承诺的个数与门限值不一致
+    LengthMismatch { expected: usize, actual: usize },
+    /// 常数项或最高次项的承诺是单位元
+    IdentityCommitment { index: usize },
+}
+
+impl fmt::Display for CommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitmentError::LengthMismatch { expected, actual } => {
+                write!(f, "expected {} commitments, got {}", expected, actual)
+            }
+            CommitmentError::IdentityCommitment { index } => write!(f, "commitment {} is the identity point", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CommitmentError {}
+
+
+/// 公开的生成、恢复和验证 API 统一返回的错误
+///
+/// 与只描述某一步骤的 [`ShareError`]、[`ReconstructError`] 不同，
+/// 该类型覆盖了会导致 panic 的全部参数错误，供需要不 panic 保证的调用者使用。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretSharingError {
+    /// 门限值为 0、份额总数为 0，或门限值大于份额总数
+    InvalidThreshold,
+    /// 存在 x 坐标相同的份额
+    DuplicateShareIndex,
+    /// 份额的 x 坐标为 0，此时份额的值就是秘密本身
+    ZeroShareIndex,
+    /// 提供的份额少于恢复所需的数量
+    InsufficientShares,
+    /// 需要求逆的标量为 0
+    NonInvertible,
+    /// 承诺的个数与门限值不一致
+    CommitmentLengthMismatch { expected: usize, actual: usize },
+    /// 私钥与给定的公钥不对应
+    PublicKeyMismatch,
+}
+
+impl fmt::Display for SecretSharingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretSharingError::InvalidThreshold => write!(f, "invalid threshold or number of shares"),
+            SecretSharingError::DuplicateShareIndex => write!(f, "duplicate share x-coordinate"),
+            SecretSharingError::ZeroShareIndex => write!(f, "share x-coordinate must be nonzero"),
+            SecretSharingError::InsufficientShares => write!(f, "not enough shares to reconstruct"),
+            SecretSharingError::NonInvertible => write!(f, "scalar is not invertible"),
+            SecretSharingError::CommitmentLengthMismatch { expected, actual } => {
+                write!(f, "expected {} commitments, got {}", expected, actual)
+            }
+            SecretSharingError::PublicKeyMismatch => write!(f, "private key does not match the public key"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SecretSharingError {}
+
+impl From<ShareError> for SecretSharingError {
+    fn from(error: ShareError) -> Self {
+        match error {
+            ShareError::ZeroShares
+            | ShareError::ZeroThreshold
+            | ShareError::ThresholdExceedsShares
+            | ShareError::ThresholdTooLarge => {
+                SecretSharingError::InvalidThreshold
+            }
+            ShareError::ZeroShareIndex => SecretSharingError::ZeroShareIndex,
+            ShareError::DuplicateShareIndex => SecretSharingError::DuplicateShareIndex,
+            ShareError::InsufficientShares => SecretSharingError::InsufficientShares,
+        }
+    }
+}
\ No newline at end of file
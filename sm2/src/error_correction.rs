@@ -0,0 +1,106 @@
+//! 不依赖承诺的纠错恢复
+//!
+//! 份额是 Reed-Solomon 码字：n 个份额是 t-1 次多项式 P 在 n 个点上的值。
+//! Berlekamp-Welch 算法引入错误定位多项式 E(x)（首一、e 次，根为出错份额的 x 坐标）
+//! 和 Q(x) = P(x) * E(x)（次数不超过 t + e - 1），对每个份额有 Q(x_i) = y_i * E(x_i)。
+//! 这是关于 Q 和 E 的系数的 n 个线性方程、t + 2e 个未知数，
+//! 因此要纠正最多 e 个错误份额，需要 n >= t + 2e 个份额，解出后 P = Q / E。
+
+use alloc::vec;
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::PrimeField;
+use crate::error::ReconstructError;
+use crate::linear::solve;
+
+/// 使用 Berlekamp-Welch 算法在最多 `max_errors` 个份额被篡改的情况下恢复秘密
+///
+/// 需要至少 `t + 2 * max_errors` 个份额。不需要 VSS 承诺，但冗余度要求比 Feldman 过滤更高。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表，其中最多 `max_errors` 个可能是错误的
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `max_errors` - 允许纠正的最大错误份额数
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；份额数量不足时返回 `InsufficientShares`，
+///   x 坐标重复时返回 `DuplicateShareIndex`，错误份额多于 `max_errors` 时返回 `TooManyErrors`
+pub fn reconstruct_with_error_correction<F: PrimeField>(
+    shares: &[(F, F)],
+    t: usize,
+    max_errors: usize,
+) -> Result<F, ReconstructError> {
+    let n = shares.len();
+    if t == 0 || n < t + 2 * max_errors {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    for (i, (x, _)) in shares.iter().enumerate() {
+        if shares[..i].iter().any(|(x_j, _)| x_j == x) {
+            return Err(ReconstructError::DuplicateShareIndex);
+        }
+    }
+
+    let e = max_errors;
+    let q_len = t + e;
+    // 未知数依次为 Q 的 t + e 个系数和 E 的前 e 个系数（E 是首一的）
+    // 第 i 个方程：Σ q_j x_i^j - y_i Σ e_j x_i^j = y_i x_i^e
+    let rows: Vec<Vec<F>> = shares
+        .iter()
+        .map(|&(x, y)| {
+            let powers = powers_of(x, q_len);
+            let mut row = powers.clone();
+            row.extend(powers[..e].iter().map(|&p| -(y * p)));
+            row.push(y * powers[e]);
+            row
+        })
+        .collect();
+    let (solution, _) = solve(rows, q_len + e).ok_or(ReconstructError::TooManyErrors)?;
+
+    let q = &solution[..q_len];
+    let mut locator = solution[q_len..].to_vec();
+    locator.push(F::ONE);
+    let p = divide_exact(q, &locator).ok_or(ReconstructError::TooManyErrors)?;
+
+    // P 必须与至少 n - e 个份额一致
+    let agreeing = shares.iter().filter(|&&(x, y)| evaluate(&p, x) == y).count();
+    if agreeing < n - e {
+        return Err(ReconstructError::TooManyErrors);
+    }
+    Ok(p[0])
+}
+
+// 计算 1, x, x^2, ..., x^(len-1)
+fn powers_of<F: PrimeField>(x: F, len: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(len);
+    let mut power = F::ONE;
+    for _ in 0..len {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
+
+// 使用霍纳法则计算系数从低到高排列的多项式在 x 处的值
+fn evaluate<F: PrimeField>(coefficients: &[F], x: F) -> F {
+    coefficients.iter().rev().fold(F::ZERO, |acc, c| acc * x + c)
+}
+
+// 多项式长除法 numerator / divisor（divisor 为首一多项式），余数不为 0 时返回 None
+fn divide_exact<F: PrimeField>(numerator: &[F], divisor: &[F]) -> Option<Vec<F>> {
+    let mut remainder = numerator.to_vec();
+    let d = divisor.len() - 1;
+    let mut quotient = vec![F::ZERO; remainder.len() - d];
+    for k in (0..quotient.len()).rev() {
+        let coeff = remainder[k + d];
+        quotient[k] = coeff;
+        for (j, &c) in divisor.iter().enumerate() {
+            remainder[k + j] -= coeff * c;
+        }
+    }
+    if remainder.iter().all(|c| bool::from(c.is_zero())) {
+        Some(quotient)
+    } else {
+        None
+    }
+}
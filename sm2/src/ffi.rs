@@ -0,0 +1,119 @@
+//! C 语言接口
+//!
+//! 启用 `capi` 特性后导出以下函数，对应的头文件为 `include/shamir_secret_sharing.h`：
+//!
+//! - [`ss_generate_shares`]：分享一个 32 字节大端编码的秘密，输出 n 条 64 字节的份额记录 (x || y)；
+//! - [`ss_reconstruct`]：从份额记录恢复秘密。
+//!
+//! 所有函数都返回整数错误码而不是 panic，调用者需要检查返回值是否为 [`SS_OK`]。
+//!
+//! Cargo.toml 只声明默认的 rlib，以免依赖本库的 no_std crate 也要为动态库或静态库提供
+//! panic_handler 和全局分配器。动态库用 `cargo rustc --release --features capi --crate-type cdylib` 构建，
+//! 静态库用 `cargo rustc --release --features capi --crate-type staticlib` 构建。头文件是手工维护的，
+//! 修改这里的函数签名或错误码时需要同步修改头文件，`tests/ffi_test.rs` 会检查两者是否一致。
+
+use alloc::vec::Vec;
+use core::ffi::c_int;
+use core::slice;
+use rand::rngs::OsRng;
+use crate::error::SecretSharingError;
+use crate::secret_sharing::{generate_shares, scalar_from_bytes, try_reconstruct_secret, validate_threshold};
+
+/// 成功
+pub const SS_OK: c_int = 0;
+/// 参数中有空指针
+pub const SS_ERR_NULL_POINTER: c_int = -1;
+/// 门限值为 0、份额数量为 0 或门限值大于份额数量
+pub const SS_ERR_INVALID_THRESHOLD: c_int = -2;
+/// 输出缓冲区小于 64 * n 字节
+pub const SS_ERR_BUFFER_TOO_SMALL: c_int = -3;
+/// 输入不是规范的 32 字节大端标量编码
+pub const SS_ERR_NON_CANONICAL: c_int = -4;
+/// 份额的 x 坐标重复
+pub const SS_ERR_DUPLICATE_SHARE: c_int = -5;
+/// 份额为空或 x 坐标为 0
+pub const SS_ERR_INVALID_SHARES: c_int = -6;
+
+// 每条份额记录的长度：32 字节 x 加 32 字节 y
+const RECORD_LEN: usize = 64;
+
+/// 分享一个秘密，将 n 条份额记录写入 `out_buf`
+///
+/// 第 i 条记录位于 `out_buf[64 * i .. 64 * (i + 1)]`，依次为 32 字节大端 x 坐标和 32 字节大端 y 坐标。
+///
+/// # Safety
+///
+/// `secret_bytes` 必须指向 32 个可读字节，`out_buf` 必须指向 `out_len` 个可写字节。
+///
+/// # Returns
+///
+/// * `c_int` - 成功时返回 `SS_OK`，否则返回负的错误码，此时 `out_buf` 不会被写入
+#[no_mangle]
+pub unsafe extern "C" fn ss_generate_shares(
+    secret_bytes: *const u8,
+    n: usize,
+    t: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> c_int {
+    if secret_bytes.is_null() || out_buf.is_null() {
+        return SS_ERR_NULL_POINTER;
+    }
+    let secret = match scalar_from_bytes(slice::from_raw_parts(secret_bytes, 32)) {
+        Some(secret) => secret,
+        None => return SS_ERR_NON_CANONICAL,
+    };
+    if validate_threshold(n, t).is_err() {
+        return SS_ERR_INVALID_THRESHOLD;
+    }
+    if n.checked_mul(RECORD_LEN).is_none_or(|needed| needed > out_len) {
+        return SS_ERR_BUFFER_TOO_SMALL;
+    }
+
+    let shares = generate_shares(secret, n, t, &mut OsRng);
+    let out = slice::from_raw_parts_mut(out_buf, n * RECORD_LEN);
+    for (record, (x, y)) in out.chunks_exact_mut(RECORD_LEN).zip(&shares) {
+        record[..32].copy_from_slice(&x.to_bytes());
+        record[32..].copy_from_slice(&y.to_bytes());
+    }
+    SS_OK
+}
+
+/// 从 `count` 条份额记录恢复秘密，将 32 字节大端编码写入 `out_secret`
+///
+/// 使用全部 `count` 条记录进行插值，调用者应只传入 t 条（或更多条一致的）记录。
+///
+/// # Safety
+///
+/// `shares_bytes` 必须指向 `64 * count` 个可读字节，`out_secret` 必须指向 32 个可写字节。
+///
+/// # Returns
+///
+/// * `c_int` - 成功时返回 `SS_OK`，否则返回负的错误码，此时 `out_secret` 不会被写入
+#[no_mangle]
+pub unsafe extern "C" fn ss_reconstruct(shares_bytes: *const u8, count: usize, out_secret: *mut u8) -> c_int {
+    if shares_bytes.is_null() || out_secret.is_null() {
+        return SS_ERR_NULL_POINTER;
+    }
+    let len = match count.checked_mul(RECORD_LEN) {
+        Some(len) => len,
+        None => return SS_ERR_INVALID_SHARES,
+    };
+
+    let mut shares = Vec::with_capacity(count);
+    for record in slice::from_raw_parts(shares_bytes, len).chunks_exact(RECORD_LEN) {
+        match scalar_from_bytes(&record[..32]).zip(scalar_from_bytes(&record[32..])) {
+            Some(share) => shares.push(share),
+            None => return SS_ERR_NON_CANONICAL,
+        }
+    }
+
+    match try_reconstruct_secret(&shares) {
+        Ok(secret) => {
+            slice::from_raw_parts_mut(out_secret, 32).copy_from_slice(&secret.to_bytes());
+            SS_OK
+        }
+        Err(SecretSharingError::DuplicateShareIndex) => SS_ERR_DUPLICATE_SHARE,
+        Err(_) => SS_ERR_INVALID_SHARES,
+    }
+}
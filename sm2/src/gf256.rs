@@ -0,0 +1,220 @@
+//! GF(2^8) 上的逐字节秘密共享
+//!
+//! 与 HashiCorp Vault 的 `shamir` 包等常见工具兼容：秘密的每个字节在 AES 所用的有限域
+//! GF(2^8)（不可约多项式 x^8 + x^4 + x^3 + x + 1，即 0x11b）上独立进行 Shamir 分享，
+//! 所有字节共用同一个 x 坐标。
+//!
+//! 份额的格式与 Vault 相同：每个字节的 y 值依次排列，最后附加 1 字节的 x 坐标，
+//! 因此份额长度为秘密长度加 1。x 坐标为 1..=n，最多生成 255 个份额。
+//!
+//! 域运算不使用查表，乘法按位进行且不含与数据相关的分支。
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use rand::{CryptoRng, RngCore};
+use crate::error::ShareError;
+use crate::secret_sharing::validate_threshold;
+
+// x^8 + x^4 + x^3 + x + 1 去掉最高位后的低 8 位
+const REDUCTION: u8 = 0x1b;
+
+/// 逐字节分享和恢复中可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gf256Error {
+    /// 秘密为空
+    EmptySecret,
+    /// 份额数量超过 255，x 坐标无法用 1 字节表示
+    TooManyShares,
+    /// 没有提供任何份额
+    InsufficientShares,
+    /// 份额长度不足 2 字节
+    MalformedShare,
+    /// 各份额的长度不一致
+    LengthMismatch,
+    /// 份额的 x 坐标为 0
+    ZeroShareIndex,
+    /// 份额的 x 坐标重复
+    DuplicateShareIndex,
+    /// 门限值或份额数量不合法
+    Invalid(ShareError),
+}
+
+impl fmt::Display for Gf256Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gf256Error::EmptySecret => write!(f, "secret must not be empty"),
+            Gf256Error::TooManyShares => write!(f, "at most 255 shares are supported"),
+            Gf256Error::InsufficientShares => write!(f, "at least one share is required"),
+            Gf256Error::MalformedShare => write!(f, "share must be at least 2 bytes long"),
+            Gf256Error::LengthMismatch => write!(f, "shares have different lengths"),
+            Gf256Error::ZeroShareIndex => write!(f, "share x-coordinate must be nonzero"),
+            Gf256Error::DuplicateShareIndex => write!(f, "duplicate share x-coordinate"),
+            Gf256Error::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Gf256Error {}
+
+impl From<ShareError> for Gf256Error {
+    fn from(e: ShareError) -> Self {
+        Gf256Error::Invalid(e)
+    }
+}
+
+/// GF(2^8) 上的乘法
+///
+/// # Arguments
+///
+/// * `a` - 乘数
+/// * `b` - 乘数
+///
+/// # Returns
+///
+/// * `u8` - 模 0x11b 约化后的乘积
+pub fn mul(a: u8, b: u8) -> u8 {
+    let mut a = a;
+    let mut b = b;
+    let mut product = 0u8;
+    for _ in 0..8 {
+        // b 的最低位为 1 时加上 a，用掩码代替分支
+        product ^= a & (b & 1).wrapping_neg();
+        // a 乘以 x，最高位溢出时减去不可约多项式
+        let carry = (a >> 7).wrapping_neg();
+        a = (a << 1) ^ (REDUCTION & carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// GF(2^8) 上的乘法逆元
+///
+/// # Arguments
+///
+/// * `a` - 要求逆的元素
+///
+/// # Returns
+///
+/// * `Option<u8>` - a 的逆元；a 为 0 时返回 `None`
+pub fn inv(a: u8) -> Option<u8> {
+    if a == 0 {
+        return None;
+    }
+    // 乘法群的阶为 255，a^254 = a^-1
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exp = 254u8;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    Some(result)
+}
+
+/// 将字节串秘密分成 n 个份额，至少需要 t 个份额才能恢复
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数，不超过 255
+/// * `t` - 恢复秘密所需的最少份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<Vec<u8>>, Gf256Error>` - n 个份额，每个为秘密长度加 1 字节，最后 1 字节是 x 坐标；
+///   秘密为空、n 超过 255 或门限值不合法时返回错误
+pub fn split_gf256<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<Vec<u8>>, Gf256Error> {
+    if secret.is_empty() {
+        return Err(Gf256Error::EmptySecret);
+    }
+    if n > 255 {
+        return Err(Gf256Error::TooManyShares);
+    }
+    validate_threshold(n, t)?;
+
+    let mut shares: Vec<Vec<u8>> = (1..=n).map(|_| Vec::with_capacity(secret.len() + 1)).collect();
+    let mut coefficients = vec![0u8; t - 1];
+    for &byte in secret {
+        // 每个字节使用独立的 t-1 次随机多项式，常数项为该字节
+        rng.fill_bytes(&mut coefficients);
+        for (i, share) in shares.iter_mut().enumerate() {
+            let x = i as u8 + 1;
+            // Horner 法则求值
+            let y = coefficients.iter().rev().fold(0u8, |acc, &c| mul(acc, x) ^ c);
+            share.push(mul(y, x) ^ byte);
+        }
+    }
+    for (i, share) in shares.iter_mut().enumerate() {
+        share.push(i as u8 + 1);
+    }
+    coefficients.fill(0);
+    Ok(shares)
+}
+
+/// 从至少 t 个份额恢复字节串秘密
+///
+/// # Arguments
+///
+/// * `shares` - `split_gf256` 或兼容工具生成的份额，数量必须不少于门限值
+///
+/// # Returns
+///
+/// * `Result<Vec<u8>, Gf256Error>` - 恢复出的秘密；份额为空、长度不一致、x 坐标为 0 或重复时返回错误。
+///   份额不足门限值时无法察觉，会得到错误的结果
+pub fn combine_gf256<S: AsRef<[u8]>>(shares: &[S]) -> Result<Vec<u8>, Gf256Error> {
+    let first = shares.first().ok_or(Gf256Error::InsufficientShares)?.as_ref();
+    let len = first.len();
+    if len < 2 {
+        return Err(Gf256Error::MalformedShare);
+    }
+
+    let mut xs: Vec<u8> = Vec::with_capacity(shares.len());
+    for share in shares {
+        let share = share.as_ref();
+        if share.len() != len {
+            return Err(Gf256Error::LengthMismatch);
+        }
+        let x = share[len - 1];
+        if x == 0 {
+            return Err(Gf256Error::ZeroShareIndex);
+        }
+        if xs.contains(&x) {
+            return Err(Gf256Error::DuplicateShareIndex);
+        }
+        xs.push(x);
+    }
+
+    // x = 0 处的拉格朗日系数只依赖 x 坐标，对所有字节只计算一次；
+    // 特征为 2 的域中减法就是异或：λ_i = Π x_j / (x_j + x_i)
+    let lambdas: Vec<u8> = xs
+        .iter()
+        .map(|&x_i| {
+            let (num, den) = xs
+                .iter()
+                .filter(|&&x_j| x_j != x_i)
+                .fold((1u8, 1u8), |(num, den), &x_j| (mul(num, x_j), mul(den, x_j ^ x_i)));
+            // x 坐标互不相同，分母非零
+            mul(num, inv(den).unwrap())
+        })
+        .collect();
+
+    Ok((0..len - 1)
+        .map(|k| {
+            shares
+                .iter()
+                .zip(&lambdas)
+                .fold(0u8, |acc, (share, &lambda)| acc ^ mul(share.as_ref()[k], lambda))
+        })
+        .collect())
+}
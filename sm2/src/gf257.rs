@@ -0,0 +1,220 @@
+//! 素数域 GF(257)
+//!
+//! 本库的多项式和 Shamir 分享函数对实现了 `ff::PrimeField` 的任意域都是泛型的，SM2 标量域只是其中之一。
+//! [`Gf257`] 是一个很小的素数域，每个元素可以容纳一个字节，适合教学示例和不需要椭圆曲线的逐字节分享：
+//! `generate_shares::<Gf257, _>` 和 `reconstruct_secret` 无需任何修改即可使用。
+//!
+//! 泛型代码使用的域抽象就是 `ff::PrimeField`（经由 [`CoefficientField`](crate::polynomial::CoefficientField)），
+//! 而不是本库另外定义的 trait：各曲线的标量域已经实现了它，无需适配。GF(2^8) 的特征为 2，不是素数域，
+//! 无法实现 `PrimeField`（模数、`from_repr` 和 2-adicity 等都以素数阶为前提），
+//! 因此与 Vault 兼容的逐字节 GF(2^8) 分享由 [`gf256`](crate::gf256) 模块单独提供。
+//!
+//! 域中只有 257 个元素，x 坐标为 1..=256，因此最多生成 256 个份额：n 更大时 x = 257 会回绕为 0，
+//! `try_generate_shares` 返回 `ZeroShareIndex`，`generate_shares` 则会 panic。
+//! Shamir 分享的保密性是信息论意义上的，与域的大小无关；但本实现没有针对侧信道做常数时间处理，
+//! 只适合示例和测试。
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use sm2::elliptic_curve::ff::{helpers, Field, PrimeField};
+use sm2::elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption};
+use rand::RngCore;
+
+const MODULUS: u16 = 257;
+
+/// GF(257) 中的元素，内部保存 0..257 之间的规范值
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Gf257(u16);
+
+impl Gf257 {
+    /// 由整数创建元素，取模 257
+    pub const fn new(value: u16) -> Self {
+        Gf257(value % MODULUS)
+    }
+
+    /// 返回 0..257 之间的规范值
+    pub const fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+// 全零是合法的元素，清零时写入默认值即可
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for Gf257 {}
+
+impl fmt::Debug for Gf257 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Gf257({})", self.0)
+    }
+}
+
+impl From<u64> for Gf257 {
+    fn from(value: u64) -> Self {
+        Gf257((value % MODULUS as u64) as u16)
+    }
+}
+
+impl ConstantTimeEq for Gf257 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for Gf257 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Gf257(u16::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Neg for Gf257 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Gf257::new(MODULUS - self.0)
+    }
+}
+
+impl Add for Gf257 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Gf257::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Gf257 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Gf257::new(self.0 + MODULUS - rhs.0)
+    }
+}
+
+impl Mul for Gf257 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Gf257(((self.0 as u32 * rhs.0 as u32) % MODULUS as u32) as u16)
+    }
+}
+
+// 引用版本的运算符和复合赋值运算符都转发到按值的实现
+macro_rules! forward_ops {
+    ($($op:ident, $method:ident, $assign:ident, $assign_method:ident);*) => {
+        $(
+            impl<'a> $op<&'a Gf257> for Gf257 {
+                type Output = Gf257;
+
+                fn $method(self, rhs: &'a Gf257) -> Gf257 {
+                    $op::$method(self, *rhs)
+                }
+            }
+
+            impl $assign for Gf257 {
+                fn $assign_method(&mut self, rhs: Gf257) {
+                    *self = $op::$method(*self, rhs);
+                }
+            }
+
+            impl<'a> $assign<&'a Gf257> for Gf257 {
+                fn $assign_method(&mut self, rhs: &'a Gf257) {
+                    *self = $op::$method(*self, *rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_ops!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+impl Sum for Gf257 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gf257::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Gf257> for Gf257 {
+    fn sum<I: Iterator<Item = &'a Gf257>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Gf257 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gf257::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Gf257> for Gf257 {
+    fn product<I: Iterator<Item = &'a Gf257>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Field for Gf257 {
+    const ZERO: Self = Gf257(0);
+    const ONE: Self = Gf257(1);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        // 拒绝采样，保证均匀分布
+        loop {
+            let candidate = (rng.next_u32() & 0x1ff) as u16;
+            if candidate < MODULUS {
+                return Gf257(candidate);
+            }
+        }
+    }
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        // 费马小定理：a^(p-2) = a^-1
+        CtOption::new(self.pow([MODULUS as u64 - 2]), !self.is_zero())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // p - 1 = 2^8 * 1，(t - 1) / 2 = 0
+        helpers::sqrt_tonelli_shanks(self, [0u64])
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for Gf257 {
+    /// 2 字节大端编码
+    type Repr = [u8; 2];
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let value = u16::from_be_bytes(repr);
+        CtOption::new(Gf257(value % MODULUS), value.ct_lt(&MODULUS))
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.0.to_be_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.0 & 1) as u8)
+    }
+
+    const MODULUS: &'static str = "0x0101";
+    const NUM_BITS: u32 = 9;
+    const CAPACITY: u32 = 8;
+    const TWO_INV: Self = Gf257(129);
+    // 3 是模 257 的原根
+    const MULTIPLICATIVE_GENERATOR: Self = Gf257(3);
+    // p - 1 = 2^8
+    const S: u32 = 8;
+    const ROOT_OF_UNITY: Self = Gf257(3);
+    const ROOT_OF_UNITY_INV: Self = Gf257(86);
+    const DELTA: Self = Gf257(1);
+}
@@ -0,0 +1,78 @@
+//! 基于 SM3 的哈希到标量和哈希到曲线
+//!
+//! 两个函数都对输入做无歧义的编码：域分隔字符串长度 (u32 大端) || 域分隔字符串 || 消息长度 (u64 大端) || 消息
+//! || 计数器 (u32 大端) || 块编号 (1 字节)，再求 SM3 摘要。不同用途应使用不同的域分隔字符串，
+//! 相同的域分隔字符串和消息总是得到相同的输出。
+//!
+//! - [`hash_to_scalar`] 使用拒绝采样：摘要不是规范标量（不小于群的阶）或为 0 时递增计数器重试，
+//!   结果在非零标量上均匀分布，没有取模带来的偏差；
+//! - [`hash_to_curve`] 使用 try-and-increment：第 0 块作为 x 坐标，第 1 块的最低位决定 y 的奇偶，
+//!   x 不在曲线上时递增计数器重试。得到的点相对于基点或其他输出的离散对数无人知晓。
+//!
+//! 重试次数取决于输入，两个函数都不是常数时间的，只应用于公开数据或对时间不敏感的场景。
+//! 本库的派生都经由这两个函数，各自使用不同的域分隔字符串：`participant_index`、份额知识证明、
+//! PVSS 的 DLEQ 证明和内积证明的挑战值使用 [`hash_to_scalar`]，`independent_generator` 和内积证明的生成元使用
+//! [`hash_to_curve`]。SM2 签名中的 Z_A 和 e 按标准计算，不经过这里。
+
+use sm2::elliptic_curve::ff::PrimeField;
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::{CompressedPoint, FieldBytes, ProjectivePoint, Scalar};
+use sm3::{Digest, Sm3};
+
+/// 将消息哈希为非零标量
+///
+/// # Arguments
+///
+/// * `domain` - 域分隔字符串
+/// * `msg` - 消息
+///
+/// # Returns
+///
+/// * `Scalar` - 非零且小于群的阶的标量
+pub fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Scalar {
+    for counter in 0u32.. {
+        let digest = expand(domain, msg, counter, 0);
+        if let Some(scalar) = Option::<Scalar>::from(Scalar::from_repr(digest)) {
+            if !bool::from(scalar.is_zero()) {
+                return scalar;
+            }
+        }
+    }
+    unreachable!("almost every digest is a nonzero canonical scalar")
+}
+
+/// 将消息哈希为曲线上的点
+///
+/// # Arguments
+///
+/// * `domain` - 域分隔字符串
+/// * `msg` - 消息
+///
+/// # Returns
+///
+/// * `ProjectivePoint` - 曲线上的点，不是单位元
+pub fn hash_to_curve(domain: &[u8], msg: &[u8]) -> ProjectivePoint {
+    for counter in 0u32.. {
+        let x = expand(domain, msg, counter, 0);
+        let parity = expand(domain, msg, counter, 1)[31] & 1;
+        let mut encoded = CompressedPoint::default();
+        encoded[0] = 0x02 | parity;
+        encoded[1..].copy_from_slice(&x);
+        if let Some(point) = Option::<ProjectivePoint>::from(ProjectivePoint::from_bytes(&encoded)) {
+            return point;
+        }
+    }
+    unreachable!("about half of all x-coordinates lie on the curve")
+}
+
+// SM3(域分隔字符串长度 || 域分隔字符串 || 消息长度 || 消息 || 计数器 || 块编号)
+fn expand(domain: &[u8], msg: &[u8], counter: u32, block: u8) -> FieldBytes {
+    Sm3::new()
+        .chain_update((domain.len() as u32).to_be_bytes())
+        .chain_update(domain)
+        .chain_update((msg.len() as u64).to_be_bytes())
+        .chain_update(msg)
+        .chain_update(counter.to_be_bytes())
+        .chain_update([block])
+        .finalize()
+}
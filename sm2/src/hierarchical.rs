@@ -0,0 +1,137 @@
+//! Tassa 分层门限秘密共享
+//!
+//! 参与者分为若干层，第 0 层权力最高。每一层 i 有一个累计门限值 k_i（严格递增），
+//! 一组参与者能够恢复秘密当且仅当对每一层 i，来自第 0 层到第 i 层的参与者至少有 k_i 个。
+//! 例如策略 `[(2, 1), (4, 3)]` 表示 2 位董事和 4 位经理，恢复秘密需要 3 人且其中至少 1 位董事。
+//!
+//! 分发者生成 k - 1 次多项式 P（k 为最后一层的门限值），P(0) 为秘密。
+//! 第 i 层参与者得到 P 的 k_{i-1} 阶导数在自己 x 坐标处的值（k_{-1} = 0），
+//! 层级越低，得到的导数阶数越高，包含的信息越少。
+//! 恢复时求解 Birkhoff 插值（合流 Vandermonde）方程组得到 P 的全部系数。
+//! x 坐标按层级顺序单调分配（1, 2, ...），在大素数域上授权集合对应的方程组以压倒性的概率满秩。
+
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::PrimeField;
+use sm2::Scalar;
+use rand::{CryptoRng, RngCore};
+use crate::error::ReconstructError;
+use crate::linear::solve;
+use crate::polynomial::{CoefficientField, Polynomial};
+use crate::secret_sharing::degree_or_panic;
+
+/// 分层秘密共享中的一个份额
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HierarchicalShare<F = Scalar> {
+    /// 参与者所在的层级
+    pub level: usize,
+    /// 份额对应的导数阶数
+    pub derivative: usize,
+    /// 参与者的 x 坐标
+    pub x: F,
+    /// P 的 `derivative` 阶导数在 x 处的值
+    pub value: F,
+}
+
+/// 按分层策略生成份额
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `levels` - 每一层的 (参与者人数, 累计门限值)，门限值必须严格递增
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Vec<HierarchicalShare<F>>` - 按层级顺序排列的所有份额
+///
+/// # Panics
+///
+/// 如果 `levels` 为空、门限值为 0 或不是严格递增的，或最后一层的门限值超过 `MAX_THRESHOLD` 则 panic
+pub fn generate_hierarchical_shares<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    levels: &[(usize, usize)],
+    rng: &mut R,
+) -> Vec<HierarchicalShare<F>> {
+    assert!(!levels.is_empty(), "at least one level is required");
+    assert!(levels[0].1 > 0, "thresholds must be positive");
+    assert!(
+        levels.windows(2).all(|w| w[0].1 < w[1].1),
+        "thresholds must be strictly increasing"
+    );
+
+    let k = levels[levels.len() - 1].1;
+    let poly = Polynomial::new(secret, degree_or_panic(k), rng);
+
+    let mut shares = Vec::new();
+    let mut derivative_poly = Polynomial::from_coefficients(poly.coefficients().to_vec());
+    let mut order = 0;
+    let mut next_x = 1u64;
+    for (level, &(count, threshold)) in levels.iter().enumerate() {
+        // 第 level 层使用前一层门限值阶的导数
+        let previous = if level == 0 { 0 } else { levels[level - 1].1 };
+        while order < previous {
+            derivative_poly = derivative_poly.derivative();
+            order += 1;
+        }
+        debug_assert!(previous < threshold);
+
+        for _ in 0..count {
+            let x = F::from(next_x);
+            next_x += 1;
+            shares.push(HierarchicalShare {
+                level,
+                derivative: order,
+                x,
+                value: derivative_poly.evaluate(x),
+            });
+        }
+    }
+    shares
+}
+
+/// 使用 Birkhoff 插值从分层份额恢复秘密
+///
+/// # Arguments
+///
+/// * `shares` - 参与恢复的份额
+/// * `t` - 最后一层的门限值，即多项式的系数个数
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；份额集合不满足分层策略（方程组不满秩）时返回
+///   `InsufficientShares`，份额相互矛盾时返回 `InconsistentShares`
+pub fn reconstruct_hierarchical_secret<F: PrimeField>(
+    shares: &[HierarchicalShare<F>],
+    t: usize,
+) -> Result<F, ReconstructError> {
+    if t == 0 || shares.len() < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+
+    // 第 r 阶导数在 x 处的值：Σ_{j>=r} a_j * j!/(j-r)! * x^(j-r)
+    let rows: Vec<Vec<F>> = shares
+        .iter()
+        .map(|share| {
+            let mut row = Vec::with_capacity(t + 1);
+            for j in 0..t {
+                if j < share.derivative {
+                    row.push(F::ZERO);
+                    continue;
+                }
+                let mut coefficient = F::ONE;
+                for m in (j - share.derivative + 1)..=j {
+                    coefficient *= F::from(m as u64);
+                }
+                row.push(coefficient * share.x.pow_vartime([(j - share.derivative) as u64]));
+            }
+            row.push(share.value);
+            row
+        })
+        .collect();
+
+    let (coefficients, rank) = solve(rows, t).ok_or(ReconstructError::InconsistentShares)?;
+    if rank < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    Ok(coefficients[0])
+}
@@ -0,0 +1,257 @@
+//! 多项式承诺与单点打开证明（内积论证）
+//!
+//! KZG 承诺需要双线性配对，而 SM2 曲线不是配对友好曲线，因此这里采用 Bulletproofs / Halo 风格的内积论证：
+//!
+//! - 承诺：C = Σ a_i * G_i + r * H，其中 a_i 是多项式的 t 个系数，r 是随机盲化值，
+//!   G_i、H 以及打开时使用的 Q 都由 [`hash_to_curve`] 派生，相互之间的离散对数无人知晓。
+//!   承诺是一个点，在 r 随机时完全隐藏系数。
+//! - 打开：证明 p(x) = v。证明者先承诺一个满足 s(x) = 0 的随机多项式 s，把 p + ξ * s 作为要打开的向量，
+//!   再对向量 a 和 b = (1, x, ..., x^(t-1)) 做 log2(m) 轮折半的内积论证（m 为不小于 t 的 2 的幂，
+//!   多出的位置 b_i = 0）。每轮的 L、R 都带有随机盲化，最后公开折叠后的单个系数和盲化值。
+//!   s 的掩蔽使公开的值不泄露 p 的其他信息，持有者得到证明后并不比只得到 p(x) 知道得更多。
+//! - 证明包含 2 * log2(m) + 1 个点和 2 个标量，验证需要一次长度为 m 的多标量乘法。
+//!
+//! 所有挑战值都由 SM3 对此前的全部消息做 Fiat-Shamir 变换得到，绑定门限值 t、x、v 和承诺。
+//! 安全性只依赖离散对数假设，不需要可信设置。
+
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::{ProjectivePoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use crate::hash::{hash_to_curve, hash_to_scalar};
+use crate::msm::multi_scalar_mul;
+use crate::polynomial::Polynomial;
+
+// 生成元的域分隔字符串
+const GENERATOR_DOMAIN: &[u8] = b"shamir_secret_sharing/ipa/generators";
+// Fiat-Shamir 挑战值的域分隔字符串
+const CHALLENGE_DOMAIN: &[u8] = b"shamir_secret_sharing/ipa/challenge";
+
+/// 多项式在某点处取值的打开证明
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningProof {
+    /// 掩蔽多项式 s 的承诺
+    pub masking_commitment: ProjectivePoint,
+    /// 每轮的左侧交叉项承诺
+    pub l: Vec<ProjectivePoint>,
+    /// 每轮的右侧交叉项承诺
+    pub r: Vec<ProjectivePoint>,
+    /// 折叠后的系数
+    pub a: Scalar,
+    /// 折叠后的盲化值
+    pub blinding: Scalar,
+}
+
+impl Polynomial<Scalar> {
+    /// 生成多项式的内积论证承诺（SM2 不支持配对，无法使用 KZG）
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - 随机数生成器，用于选择盲化值
+    ///
+    /// # Returns
+    ///
+    /// * `(ProjectivePoint, Scalar)` - 承诺，以及打开时需要的盲化值；盲化值应与多项式一样保密
+    pub fn commit_ipa<R: RngCore + CryptoRng>(&self, rng: &mut R) -> (ProjectivePoint, Scalar) {
+        let blinding = Scalar::random(&mut *rng);
+        (commit_vector(self.coefficients(), blinding), blinding)
+    }
+
+    /// 在 x 处打开承诺，证明多项式在 x 处的值
+    ///
+    /// # Arguments
+    ///
+    /// * `blinding` - `commit_ipa` 返回的盲化值
+    /// * `x` - 打开的点，例如份额的 x 坐标
+    /// * `rng` - 随机数生成器
+    ///
+    /// # Returns
+    ///
+    /// * `(Scalar, OpeningProof)` - 多项式在 x 处的值，以及可用 [`verify_open`] 验证的证明
+    pub fn open<R: RngCore + CryptoRng>(&self, blinding: Scalar, x: Scalar, rng: &mut R) -> (Scalar, OpeningProof) {
+        let t = self.coefficients().len();
+        let m = t.next_power_of_two();
+        let value = self.evaluate(x);
+        let commitment = commit_vector(self.coefficients(), blinding);
+
+        // 随机多项式 s，调整常数项使 s(x) = 0
+        let mut masking: Vec<Scalar> = (0..t).map(|_| Scalar::random(&mut *rng)).collect();
+        let masking_at_x = masking.iter().rev().fold(Scalar::ZERO, |acc, &c| acc * x + c);
+        masking[0] -= masking_at_x;
+        let masking_blinding = Scalar::random(&mut *rng);
+        let masking_commitment = commit_vector(&masking, masking_blinding);
+
+        let mut transcript = Transcript::new(t, x, value, commitment, masking_commitment);
+        let xi = transcript.challenge();
+        let z = transcript.challenge();
+        let u_point = generator(b"Q") * z;
+        let h = generator(b"H");
+
+        // a = p + ξ * s，b = (1, x, ..., x^(t-1), 0, ...)，两者都补齐到 m
+        let mut a: Vec<Scalar> = self
+            .coefficients()
+            .iter()
+            .zip(&masking)
+            .map(|(&p_i, &s_i)| p_i + xi * s_i)
+            .collect();
+        a.resize(m, Scalar::ZERO);
+        let mut b = powers(x, t, m);
+        let mut g = generators(m);
+        let mut blinding = blinding + xi * masking_blinding;
+
+        let (mut l, mut r) = (Vec::new(), Vec::new());
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+
+            // 系数保密，使用常数时间的逐项标量乘法而不是多标量乘法
+            let l_blinding = Scalar::random(&mut *rng);
+            let r_blinding = Scalar::random(&mut *rng);
+            let l_point = inner_product_points(a_lo, g_hi) + u_point * inner_product(a_lo, b_hi) + h * l_blinding;
+            let r_point = inner_product_points(a_hi, g_lo) + u_point * inner_product(a_hi, b_lo) + h * r_blinding;
+            let u = transcript.round_challenge(l_point, r_point);
+            let u_inv = u.invert().unwrap();
+
+            a = a_lo.iter().zip(a_hi).map(|(&lo, &hi)| lo * u + hi * u_inv).collect();
+            b = b_lo.iter().zip(b_hi).map(|(&lo, &hi)| lo * u_inv + hi * u).collect();
+            g = g_lo.iter().zip(g_hi).map(|(&lo, &hi)| lo * u_inv + hi * u).collect();
+            blinding += u.square() * l_blinding + u_inv.square() * r_blinding;
+            l.push(l_point);
+            r.push(r_point);
+        }
+
+        (
+            value,
+            OpeningProof {
+                masking_commitment,
+                l,
+                r,
+                a: a[0],
+                blinding,
+            },
+        )
+    }
+}
+
+/// 验证多项式承诺在 x 处的打开证明
+///
+/// 打开的值是承诺中前 t 个系数构成的多项式在 x 处的值，因此承诺对应的多项式次数不超过 t - 1。
+///
+/// # Arguments
+///
+/// * `commitment` - `commit_ipa` 返回的承诺
+/// * `t` - 门限值，即多项式的系数个数
+/// * `x` - 打开的点
+/// * `value` - 声称的多项式在 x 处的值
+/// * `proof` - 打开证明
+///
+/// # Returns
+///
+/// * `bool` - 证明有效且多项式在 x 处的值确实为 `value` 时返回 true
+pub fn verify_open(commitment: ProjectivePoint, t: usize, x: Scalar, value: Scalar, proof: &OpeningProof) -> bool {
+    if t == 0 || proof.l.len() != proof.r.len() {
+        return false;
+    }
+    let m = t.next_power_of_two();
+    if 1usize.checked_shl(proof.l.len() as u32) != Some(m) {
+        return false;
+    }
+
+    let mut transcript = Transcript::new(t, x, value, commitment, proof.masking_commitment);
+    let xi = transcript.challenge();
+    let z = transcript.challenge();
+    let u_point = generator(b"Q") * z;
+    let h = generator(b"H");
+
+    // P = C + ξ * S + v * U，每轮加上 u^2 * L + u^-2 * R
+    let mut p = commitment + proof.masking_commitment * xi + u_point * value;
+    let mut challenges = Vec::with_capacity(proof.l.len());
+    for (&l_point, &r_point) in proof.l.iter().zip(&proof.r) {
+        let u = transcript.round_challenge(l_point, r_point);
+        let u_inv = u.invert().unwrap();
+        p += l_point * u.square() + r_point * u_inv.square();
+        challenges.push((u, u_inv));
+    }
+
+    // 折叠系数 s_i：第 j 轮对应 i 的从高到低第 j 比特，比特为 0 时乘 u_j^-1，为 1 时乘 u_j
+    let mut s = alloc::vec![Scalar::ONE];
+    for &(u, u_inv) in challenges.iter().rev() {
+        let lo: Vec<Scalar> = s.iter().map(|&s_i| s_i * u_inv).collect();
+        let hi: Vec<Scalar> = s.iter().map(|&s_i| s_i * u).collect();
+        s = lo.into_iter().chain(hi).collect();
+    }
+    let g_final = multi_scalar_mul(&generators(m), &s);
+    let b_final = inner_product(&s, &powers(x, t, m));
+
+    p == g_final * proof.a + u_point * (proof.a * b_final) + h * proof.blinding
+}
+
+// Σ v_i * G_i + r * H
+fn commit_vector(vector: &[Scalar], blinding: Scalar) -> ProjectivePoint {
+    inner_product_points(vector, &generators(vector.len())) + generator(b"H") * blinding
+}
+
+// G_0, ..., G_{m-1}
+fn generators(m: usize) -> Vec<ProjectivePoint> {
+    (0..m as u32).map(|i| hash_to_curve(GENERATOR_DOMAIN, &i.to_be_bytes())).collect()
+}
+
+// 具名的辅助生成元 H 或 Q
+fn generator(name: &[u8]) -> ProjectivePoint {
+    hash_to_curve(GENERATOR_DOMAIN, name)
+}
+
+// (1, x, ..., x^(t-1))，补 0 到长度 m
+fn powers(x: Scalar, t: usize, m: usize) -> Vec<Scalar> {
+    let mut powers = Vec::with_capacity(m);
+    let mut power = Scalar::ONE;
+    for _ in 0..t {
+        powers.push(power);
+        power *= x;
+    }
+    powers.resize(m, Scalar::ZERO);
+    powers
+}
+
+fn inner_product(a: &[Scalar], b: &[Scalar]) -> Scalar {
+    a.iter().zip(b).fold(Scalar::ZERO, |acc, (&a_i, &b_i)| acc + a_i * b_i)
+}
+
+fn inner_product_points(scalars: &[Scalar], points: &[ProjectivePoint]) -> ProjectivePoint {
+    scalars
+        .iter()
+        .zip(points)
+        .fold(ProjectivePoint::IDENTITY, |acc, (&s, &point)| acc + point * s)
+}
+
+// Fiat-Shamir 记录：每个挑战值由此前的全部消息派生，并被追加到记录中
+struct Transcript {
+    bytes: Vec<u8>,
+}
+
+impl Transcript {
+    fn new(t: usize, x: Scalar, value: Scalar, commitment: ProjectivePoint, masking: ProjectivePoint) -> Self {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(t as u64).to_be_bytes());
+        bytes.extend_from_slice(&x.to_bytes());
+        bytes.extend_from_slice(&value.to_bytes());
+        bytes.extend_from_slice(&commitment.to_bytes());
+        bytes.extend_from_slice(&masking.to_bytes());
+        Transcript { bytes }
+    }
+
+    fn challenge(&mut self) -> Scalar {
+        let challenge = hash_to_scalar(CHALLENGE_DOMAIN, &self.bytes);
+        self.bytes.extend_from_slice(&challenge.to_bytes());
+        challenge
+    }
+
+    fn round_challenge(&mut self, l: ProjectivePoint, r: ProjectivePoint) -> Scalar {
+        self.bytes.extend_from_slice(&l.to_bytes());
+        self.bytes.extend_from_slice(&r.to_bytes());
+        self.challenge()
+    }
+}
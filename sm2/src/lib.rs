@@ -1,2 +1,52 @@
+//! 基于 SM2 的秘密共享方案
+//!
+//! 本库是 `no_std` 的，只依赖 `alloc`。默认启用的 `std` 特性提供依赖系统时钟的
+//! [`attestation`] 模块，以及各错误类型的 `std::error::Error` 实现。
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod accumulator;
+#[cfg(feature = "std")]
+pub mod attestation;
+pub mod builder;
+pub mod byte_sharing;
+#[cfg(feature = "serde")]
+pub mod codec;
+pub mod dkg;
+pub mod dual_threshold;
+pub mod error;
+pub mod error_correction;
+#[cfg(feature = "capi")]
+pub mod ffi;
+pub mod gf256;
+pub mod gf257;
+pub mod hash;
+pub mod hierarchical;
+pub mod ipa;
+mod linear;
+#[cfg(feature = "mnemonic")]
+pub mod mnemonic;
+mod msm;
+pub mod packed;
+pub mod participant;
 pub mod polynomial;
-pub mod secret_sharing;
\ No newline at end of file
+pub mod portable;
+pub mod protocol;
+pub mod pvss;
+pub mod resharing;
+pub mod seal;
+pub mod secret;
+pub mod secret_sharing;
+pub mod session;
+pub mod share;
+pub mod share_proof;
+pub mod slip39;
+pub mod test_vectors;
+pub mod threshold_sign;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod weighted;
\ No newline at end of file
@@ -0,0 +1,49 @@
+//! 有限域上的线性代数
+
+use alloc::vec;
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::PrimeField;
+
+/// 高斯消元求解增广矩阵表示的线性方程组
+///
+/// 每一行为 `unknowns` 个系数加上右侧的常数。自由变量取 0。
+///
+/// # Returns
+///
+/// * `Option<(Vec<F>, usize)>` - 方程组的一个解以及系数矩阵的秩；方程组无解时返回 None
+pub(crate) fn solve<F: PrimeField>(mut rows: Vec<Vec<F>>, unknowns: usize) -> Option<(Vec<F>, usize)> {
+    let mut pivots = Vec::new();
+    let mut rank = 0;
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..rows.len()).find(|&r| !bool::from(rows[r][col].is_zero())) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inverse = rows[rank][col].invert().unwrap();
+        for value in rows[rank].iter_mut() {
+            *value *= inverse;
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && !bool::from(row[col].is_zero()) {
+                let factor = row[col];
+                for (value, &p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                    *value -= p * factor;
+                }
+            }
+        }
+        pivots.push(col);
+        rank += 1;
+    }
+
+    // 剩余的行左侧全为 0，右侧也必须为 0
+    if rows[rank..].iter().any(|row| !bool::from(row[unknowns].is_zero())) {
+        return None;
+    }
+
+    let mut solution = vec![F::ZERO; unknowns];
+    for (row, &col) in pivots.iter().enumerate() {
+        solution[col] = rows[row][unknowns];
+    }
+    Some((solution, rank))
+}
@@ -0,0 +1,133 @@
+//! 以 BIP39 助记词表示份额，便于抄写在纸上备份
+//!
+//! 份额 (x, y) 被编码为 48 个英文单词：前 24 个单词是 x 坐标的 32 字节大端编码对应的标准 BIP39 助记词，
+//! 后 24 个单词是 y 坐标对应的助记词。每一半各自带有 BIP39 的 8 比特校验和：
+//! 写成词表以外的单词总会被发现，换成词表中另一个单词时约有 255/256 的概率被发现。
+//! 两半也都可以用现有的 BIP39 工具单独检查。
+//!
+//! 启用 `zeroize` 特性时，解码过程中本模块产生的助记词副本和熵会在使用后清零；
+//! `bip39` 内部保存的副本不在此列。
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use bip39::{Language, Mnemonic};
+use sm2::Scalar;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+use crate::secret_sharing::scalar_from_bytes;
+
+/// 一个份额对应的单词数
+pub const SHARE_MNEMONIC_WORDS: usize = 48;
+
+// 每个坐标对应的单词数
+const HALF_WORDS: usize = SHARE_MNEMONIC_WORDS / 2;
+
+/// 解析助记词时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MnemonicError {
+    /// 单词个数不是 48
+    WordCount { actual: usize },
+    /// 第 `position` 个单词（从 0 开始）不在 BIP39 英文词表中
+    UnknownWord { position: usize },
+    /// 校验和不匹配，说明有单词抄错或顺序错误
+    InvalidChecksum,
+    /// 解码得到的坐标不是规范的标量
+    NonCanonicalScalar,
+}
+
+impl fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MnemonicError::WordCount { actual } => {
+                write!(f, "expected {} words, got {}", SHARE_MNEMONIC_WORDS, actual)
+            }
+            MnemonicError::UnknownWord { position } => write!(f, "word {} is not in the BIP39 word list", position),
+            MnemonicError::InvalidChecksum => write!(f, "mnemonic checksum mismatch"),
+            MnemonicError::NonCanonicalScalar => write!(f, "decoded share coordinate is not a canonical scalar"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MnemonicError {}
+
+/// 将份额编码为 48 个以空格分隔的小写英文单词
+///
+/// # Arguments
+///
+/// * `share` - 份额 (x, y)
+///
+/// # Returns
+///
+/// * `String` - 助记词，前 24 个单词编码 x，后 24 个单词编码 y
+pub fn share_to_mnemonic(share: &(Scalar, Scalar)) -> String {
+    let mut words = String::new();
+    for coordinate in [share.0, share.1] {
+        // 32 字节熵总是合法的 BIP39 输入
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &coordinate.to_bytes())
+            .expect("32-byte entropy is valid for BIP39");
+        for word in mnemonic.words() {
+            if !words.is_empty() {
+                words.push(' ');
+            }
+            words.push_str(word);
+        }
+    }
+    words
+}
+
+/// 解析 `share_to_mnemonic` 生成的助记词并校验两半的校验和
+///
+/// 单词之间可以用任意空白分隔，大小写不敏感。
+///
+/// # Arguments
+///
+/// * `phrase` - 助记词
+///
+/// # Returns
+///
+/// * `Result<(Scalar, Scalar), MnemonicError>` - 份额 (x, y)
+pub fn share_from_mnemonic(phrase: &str) -> Result<(Scalar, Scalar), MnemonicError> {
+    let lowered = phrase.to_lowercase();
+    let result = share_from_words(&lowered);
+    #[cfg(feature = "zeroize")]
+    {
+        let mut lowered = lowered;
+        lowered.zeroize();
+    }
+    result
+}
+
+// 按空白切分已转为小写的助记词并解析两半
+fn share_from_words(phrase: &str) -> Result<(Scalar, Scalar), MnemonicError> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    if words.len() != SHARE_MNEMONIC_WORDS {
+        return Err(MnemonicError::WordCount { actual: words.len() });
+    }
+    let x = coordinate_from_words(&words[..HALF_WORDS], 0)?;
+    let y = coordinate_from_words(&words[HALF_WORDS..], HALF_WORDS)?;
+    Ok((x, y))
+}
+
+// 解析 24 个单词，`offset` 用于在错误中报告单词在整个助记词中的位置
+fn coordinate_from_words(words: &[&str], offset: usize) -> Result<Scalar, MnemonicError> {
+    let joined = words.join(" ");
+    let parsed = Mnemonic::parse_in_normalized(Language::English, &joined);
+    #[cfg(feature = "zeroize")]
+    {
+        let mut joined = joined;
+        joined.zeroize();
+    }
+    let mnemonic = parsed.map_err(|e| match e {
+        bip39::Error::UnknownWord(i) => MnemonicError::UnknownWord { position: offset + i },
+        _ => MnemonicError::InvalidChecksum,
+    })?;
+    // 熵数组是 Copy 的，必须原地清零，移动到新绑定只会清除副本
+    #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+    let (mut entropy, len) = mnemonic.to_entropy_array();
+    let coordinate = scalar_from_bytes(&entropy[..len]).ok_or(MnemonicError::NonCanonicalScalar);
+    #[cfg(feature = "zeroize")]
+    entropy.zeroize();
+    coordinate
+}
@@ -0,0 +1,81 @@
+//! 多标量乘法 (multi-scalar multiplication)
+//!
+//! `elliptic_curve` 没有为 SM2 提供通用的多标量乘法，这里实现 Pippenger 桶算法：
+//! 将每个标量按 c 比特分窗，每个窗口内先把点按窗口值放入桶中累加，
+//! 再用一次后缀和求出 Σ j * bucket_j。n 个点、b 比特标量时约需 (b / c) * (n + 2^(c+1)) 次点加法，
+//! 而逐个标量乘法需要 n * b 次倍点。
+
+use alloc::vec;
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::{Field, PrimeField};
+use sm2::elliptic_curve::group::Group;
+
+/// 计算 Σ points[i] * scalars[i]
+///
+/// 结果与逐项标量乘法后求和完全相同。只用于公开数据（例如验证承诺），不保证常数时间。
+///
+/// # Panics
+///
+/// 如果 `points` 与 `scalars` 的长度不同则 panic
+pub(crate) fn multi_scalar_mul<G: Group>(points: &[G], scalars: &[G::Scalar]) -> G {
+    assert_eq!(points.len(), scalars.len(), "points and scalars must have the same length");
+    if points.is_empty() {
+        return G::identity();
+    }
+
+    // 标量的规范编码可能是大端也可能是小端，由 1 的编码判断，统一转换为小端
+    let one = G::Scalar::ONE.to_repr();
+    let big_endian = AsRef::<[u8]>::as_ref(&one).last() == Some(&1);
+    let digits: Vec<Vec<u8>> = scalars
+        .iter()
+        .map(|s| {
+            let mut bytes = AsRef::<[u8]>::as_ref(&s.to_repr()).to_vec();
+            if big_endian {
+                bytes.reverse();
+            }
+            bytes
+        })
+        .collect();
+
+    // 窗口宽度约为 ln(n) + 2
+    let c = (points.len().ilog2() as usize * 69 / 100 + 2).min(16);
+    let windows = (G::Scalar::NUM_BITS as usize).div_ceil(c);
+
+    let mut acc = G::identity();
+    let mut buckets = vec![G::identity(); (1 << c) - 1];
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        buckets.iter_mut().for_each(|b| *b = G::identity());
+        for (point, bytes) in points.iter().zip(&digits) {
+            let d = window(bytes, w * c, c);
+            if d != 0 {
+                buckets[d - 1] += point;
+            }
+        }
+
+        // 后缀和：running 为 bucket_j 到 bucket_max 之和，累加后得到 Σ j * bucket_j
+        let mut running = G::identity();
+        let mut sum = G::identity();
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            sum += running;
+        }
+        acc += sum;
+    }
+    acc
+}
+
+// 从小端字节串中取出从 start 比特开始、宽度为 width 的窗口值
+fn window(bytes: &[u8], start: usize, width: usize) -> usize {
+    let mut value = 0;
+    for k in 0..width {
+        let bit = start + k;
+        if bit / 8 < bytes.len() && (bytes[bit / 8] >> (bit % 8)) & 1 == 1 {
+            value |= 1 << k;
+        }
+    }
+    value
+}
@@ -0,0 +1,84 @@
+//! 打包秘密共享
+//!
+//! 将 k 个秘密放在同一个多项式的 k 个保留点 x = -1, -2, ..., -k 上，份额仍在 x = 1..=n 处求值，
+//! 每个参与者只持有一个份额就同时分享了全部 k 个秘密。
+//!
+//! 多项式另外经过 x = -(k+1), ..., -(k+t-1) 处的 t-1 个随机值，次数为 t + k - 2：
+//!
+//! - 任意不超过 t-1 个份额不泄露任何秘密的任何信息；
+//! - 恢复需要 t + k - 1 个份额，因此 n 必须不小于 t + k - 1。
+//!
+//! 保留点与份额的 x 坐标必须互不相同，因此域的阶必须大于 n + t + k - 1。SM2 标量域总是满足，
+//! 但在 [`Gf257`](crate::gf257::Gf257) 这样的小域上 n 不能太大。
+//!
+//! 与分别分享 k 个秘密相比，份额大小缩小为 1/k，代价是恢复门限与隐私门限之间相差 k - 1。
+
+use alloc::vec::Vec;
+use rand::{CryptoRng, RngCore};
+use crate::error::{ReconstructError, ShareError};
+use crate::polynomial::{CoefficientField, Polynomial};
+use crate::secret_sharing::{fits_in_field, polynomial_degree, validate_threshold};
+
+/// 将 k 个秘密打包进一个多项式并生成 n 个份额
+///
+/// # Arguments
+///
+/// * `secrets` - 要分享的 k 个秘密，第 j 个（从 0 开始）位于 x = -(j+1)
+/// * `n` - 份额的总数
+/// * `t` - 隐私门限：任意 t-1 个份额不泄露秘密
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(F, F)>, ShareError>` - x 坐标为 1 到 n 的份额；n 或 t 为 0，t 超过 `MAX_THRESHOLD`，
+///   或 n 小于 t + k - 1 时返回错误；域的阶不大于 n + t + k - 1，保留点会与份额的 x 坐标重合时
+///   返回 `DuplicateShareIndex`
+///
+/// # Panics
+///
+/// 如果 `secrets` 为空则 panic
+pub fn generate_packed_shares<F: CoefficientField, R: RngCore + CryptoRng>(
+    secrets: &[F],
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(F, F)>, ShareError> {
+    assert!(!secrets.is_empty(), "at least one secret is required");
+    let k = secrets.len();
+    let degree = polynomial_degree(t)?;
+    validate_threshold(n, t + k - 1)?;
+    // x = 1..=n 与保留点 x = -1..=-(t+k-1) 互不相同，当且仅当 2..=n+t+k-1 中没有整数在域中为 0
+    if !fits_in_field::<F>(n + t + k - 1) {
+        return Err(ShareError::DuplicateShareIndex);
+    }
+
+    // k 个秘密点和 t-1 个随机点唯一确定一个 t+k-2 次多项式
+    let points: Vec<(F, F)> = secrets
+        .iter()
+        .copied()
+        .chain((0..degree).map(|_| F::random(&mut *rng)))
+        .enumerate()
+        .map(|(j, value)| (-F::from(j as u64 + 1), value))
+        .collect();
+    let poly = Polynomial::lagrange_interpolate(&points).expect("reserved points fit in the field");
+
+    let xs: Vec<F> = (1..=n).map(|i| F::from(i as u64)).collect();
+    let ys = poly.evaluate_many(&xs);
+    Ok(xs.into_iter().zip(ys).collect())
+}
+
+/// 从至少 t + k - 1 个份额恢复全部 k 个秘密
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表，数量必须不少于 t + k - 1
+/// * `k` - 打包的秘密个数
+///
+/// # Returns
+///
+/// * `Result<Vec<F>, ReconstructError>` - 按打包顺序排列的 k 个秘密；份额为空时返回 `InsufficientShares`，
+///   x 坐标重复时返回 `DuplicateShareIndex`。份额不足 t + k - 1 个时无法察觉，会得到错误的结果
+pub fn reconstruct_packed<F: CoefficientField>(shares: &[(F, F)], k: usize) -> Result<Vec<F>, ReconstructError> {
+    let poly = Polynomial::lagrange_interpolate(shares)?;
+    Ok((1..=k).map(|j| poly.evaluate(-F::from(j as u64))).collect())
+}
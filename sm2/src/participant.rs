@@ -0,0 +1,102 @@
+//! 按参与者身份分发的份额
+//!
+//! 实际部署中参与者以名称或身份标识区分，而不是数字编号。每个参与者的 x 坐标由其身份标识经
+//! [`hash_to_scalar`] 确定性地派生，份额因此与身份绑定：恢复时会重新计算 x 坐标，
+//! 身份与份额不符的份额会被拒绝。
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use sm2::Scalar;
+use rand::{CryptoRng, RngCore};
+use crate::error::{ReconstructError, ShareError};
+use crate::hash::hash_to_scalar;
+use crate::secret::fmt_index;
+use crate::secret_sharing::{generate_shares_at, interpolate_at};
+
+// 身份标识哈希到 x 坐标时使用的域分隔标签
+const PARTICIPANT_INDEX_DOMAIN: &[u8] = b"shamir_secret_sharing/participant_index";
+
+/// 属于某个参与者的份额
+///
+/// `Debug` 只输出身份标识和 x 坐标。
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParticipantShare {
+    /// 参与者的身份标识
+    pub identity: String,
+    /// 份额 (x, y)，x 由身份标识派生
+    pub share: (Scalar, Scalar),
+}
+
+impl fmt::Debug for ParticipantShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ParticipantShare {{ identity: {:?}, index: ", self.identity)?;
+        fmt_index(&self.share.0, f)?;
+        write!(f, ", value: .. }}")
+    }
+}
+
+/// 由身份标识派生参与者的 x 坐标：以 `shamir_secret_sharing/participant_index` 为域分隔字符串的 [`hash_to_scalar`]
+///
+/// # Arguments
+///
+/// * `identity` - 参与者的身份标识
+///
+/// # Returns
+///
+/// * `Scalar` - 非零的 x 坐标，相同的身份标识总是得到相同的值
+pub fn participant_index(identity: &str) -> Scalar {
+    hash_to_scalar(PARTICIPANT_INDEX_DOMAIN, identity.as_bytes())
+}
+
+/// 为每个具名参与者生成一个份额，至少需要 t 个份额才能恢复秘密
+///
+/// # Arguments
+///
+/// * `identities` - 参与者的身份标识
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `secret` - 要分享的秘密
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<ParticipantShare>, ShareError>` - 与 `identities` 一一对应的份额；
+///   门限值不合法时返回错误，身份标识重复时返回 `DuplicateShareIndex`，派生出的 x 坐标为 0 时返回 `ZeroShareIndex`
+pub fn generate_shares_for<R: RngCore + CryptoRng>(
+    identities: &[String],
+    t: usize,
+    secret: Scalar,
+    rng: &mut R,
+) -> Result<Vec<ParticipantShare>, ShareError> {
+    let xs: Vec<Scalar> = identities.iter().map(|identity| participant_index(identity)).collect();
+    let shares = generate_shares_at(secret, &xs, t, rng)?;
+    Ok(identities
+        .iter()
+        .zip(shares)
+        .map(|(identity, share)| ParticipantShare {
+            identity: identity.clone(),
+            share,
+        })
+        .collect())
+}
+
+/// 从具名参与者的份额恢复秘密
+///
+/// # Arguments
+///
+/// * `shares` - 至少 t 个参与者的份额
+///
+/// # Returns
+///
+/// * `Result<Scalar, ReconstructError>` - 恢复的秘密；份额为空时返回 `InsufficientShares`，
+///   份额的 x 坐标与身份标识派生出的不符时返回 `InconsistentShares`，同一参与者的份额重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_participant_shares(shares: &[ParticipantShare]) -> Result<Scalar, ReconstructError> {
+    if shares.is_empty() {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    if shares.iter().any(|share| share.share.0 != participant_index(&share.identity)) {
+        return Err(ReconstructError::InconsistentShares);
+    }
+    let points: Vec<(Scalar, Scalar)> = shares.iter().map(|share| share.share).collect();
+    interpolate_at(&points, Scalar::ZERO)
+}
@@ -1,28 +1,71 @@
+use alloc::vec::Vec;
+use alloc::vec;
+use core::fmt;
 use sm2::Scalar;
-use sm2::elliptic_curve::ff::Field;
-use sm2::ProjectivePoint;
-use rand::Rng;
+use sm2::elliptic_curve::ff::PrimeField;
+use sm2::elliptic_curve::group::Group;
+use sm2::elliptic_curve::point::BatchNormalize;
+use sm2::elliptic_curve::subtle::{ConditionallySelectable, ConstantTimeEq};
+use sm2::{AffinePoint, ProjectivePoint};
+use rand::{CryptoRng, RngCore};
+use crate::error::ReconstructError;
+use crate::secret::{fmt_field, Revealed};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// 多项式系数所在的域
+///
+/// 对所有实现了 `PrimeField` 的域自动实现。启用 `zeroize` 特性时还要求域元素实现 `Zeroize`，
+/// 多项式释放时通过它清除系数。
+#[cfg(feature = "zeroize")]
+pub trait CoefficientField: PrimeField + Zeroize {}
+
+#[cfg(feature = "zeroize")]
+impl<F: PrimeField + Zeroize> CoefficientField for F {}
+
+/// 多项式系数所在的域
+///
+/// 对所有实现了 `PrimeField` 的域自动实现。启用 `zeroize` 特性时还要求域元素实现 `Zeroize`，
+/// 多项式释放时通过它清除系数。
+#[cfg(not(feature = "zeroize"))]
+pub trait CoefficientField: PrimeField {}
+
+#[cfg(not(feature = "zeroize"))]
+impl<F: PrimeField> CoefficientField for F {}
 
 /// 表示有限域上的多项式
-pub struct Polynomial {
+///
+/// 系数域 F 默认为 SM2 的标量域，也可以是任意实现了 `PrimeField` 的域，
+/// 例如其他椭圆曲线的标量域。
+///
+/// 启用 `zeroize` 特性后，多项式在释放时会清除其系数（常数项通常就是秘密）。
+/// `Debug` 输出只包含次数，需要输出系数时使用 [`Polynomial::reveal`]。
+pub struct Polynomial<F: CoefficientField = Scalar> {
     // 多项式的系数，按照次数从低到高排列
-    coefficients: Vec<Scalar>,
+    coefficients: Vec<F>,
 }
 
-impl Polynomial {
+impl<F: CoefficientField> Polynomial<F> {
     /// 创建一个随机多项式，最高次项为'degree'，常数项为'secret'
     ///
     /// # Arguments
     ///
     /// * `secret` - 多项式的常数项
     /// * `degree` - 多项式的最高次数
-    /// * `rng` - 随机数生成器
-    pub fn new<R: Rng>(secret: Scalar, degree: usize, rng: &mut R) -> Self {
+    /// * `rng` - 密码学安全的随机数生成器
+    pub fn new<R: RngCore + CryptoRng>(secret: F, degree: usize, rng: &mut R) -> Self {
+        Self::new_with_rng_unchecked(secret, degree, rng)
+    }
+
+    /// 与 `new` 相同，但接受任意随机数生成器，仅用于确定性测试
+    ///
+    /// 多项式的系数必须保密，在生产环境中使用非密码学安全的随机数生成器会泄露秘密
+    pub fn new_with_rng_unchecked<R: RngCore>(secret: F, degree: usize, rng: &mut R) -> Self {
         // 初始化系数向量，第一个元素为常数项
         let mut coefficients = vec![secret];
         // 生成 degree 个随机系数
         for _ in 0..degree {
-            coefficients.push(Scalar::random(&mut *rng));
+            coefficients.push(F::random(&mut *rng));
         }
         // 返回构造的多项式
         Polynomial { coefficients }
@@ -37,13 +80,77 @@ impl Polynomial {
     /// # Returns
     ///
     /// * 多项式在 x 处的值
-    pub fn evaluate(&self, x: Scalar) -> Scalar {
+    pub fn evaluate(&self, x: F) -> F {
         // 使用霍纳法则 (Horner's method) 从高次项到低次项计算多项式的值
-        self.coefficients.iter().rev().fold(Scalar::ZERO, |acc, coeff| {
+        self.coefficients.iter().rev().fold(F::ZERO, |acc, coeff| {
             acc * x + coeff
         })
     }
 
+    /// 计算多项式的形式导数
+    ///
+    /// # Returns
+    ///
+    /// * `Polynomial<F>` - 导数多项式，次数比原多项式低 1；常数多项式的导数没有系数
+    pub fn derivative(&self) -> Polynomial<F> {
+        Polynomial {
+            coefficients: self
+                .coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(j, &c)| c * F::from(j as u64))
+                .collect(),
+        }
+    }
+
+    /// 计算多项式在多个点处的值，结果与逐点调用 `evaluate` 完全相同
+    ///
+    /// 如果 x 坐标构成等差数列（例如 `generate_shares` 使用的 1, 2, ..., n）且点数多于系数个数，
+    /// 先用霍纳法则计算前 deg + 1 个值，再用前向差分表递推其余的值，每个点只需 deg 次加法而不需要乘法，
+    /// 总开销从 n * deg 次乘法和加法降为 deg^2 次乘法加 n * deg 次加法。
+    /// 实际耗时与平台有关，用 `cargo bench --bench evaluate_many` 测量。
+    /// 其他情况逐点使用霍纳法则。这里没有使用子乘积树 (subproduct tree) 算法：
+    /// 没有快速多项式乘法时它并不比霍纳法则更快。
+    ///
+    /// # Arguments
+    ///
+    /// * `xs` - 自变量的值
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<F>` - 与 `xs` 一一对应的多项式的值
+    pub fn evaluate_many(&self, xs: &[F]) -> Vec<F> {
+        let len = self.coefficients.len();
+        let arithmetic = xs.len() > len
+            && len > 0
+            && xs.windows(2).all(|w| w[1] - w[0] == xs[1] - xs[0]);
+        if !arithmetic {
+            return xs.iter().map(|&x| self.evaluate(x)).collect();
+        }
+
+        // delta[j] 为 j 阶前向差分 Δ^j p(x_0)，deg 阶差分为常数
+        let mut delta: Vec<F> = xs[..len].iter().map(|&x| self.evaluate(x)).collect();
+        for j in 1..len {
+            for i in (j..len).rev() {
+                let previous = delta[i - 1];
+                delta[i] -= previous;
+            }
+        }
+
+        let mut values = Vec::with_capacity(xs.len());
+        values.push(delta[0]);
+        for _ in 1..xs.len() {
+            // Δ^j p(x_{k+1}) = Δ^j p(x_k) + Δ^{j+1} p(x_k)
+            for j in 0..len - 1 {
+                let next = delta[j + 1];
+                delta[j] += next;
+            }
+            values.push(delta[0]);
+        }
+        values
+    }
+
     /// 生成多项式系数的 Feldman 承诺
     ///
     /// # Arguments
@@ -52,8 +159,8 @@ impl Polynomial {
     ///
     /// # Returns
     ///
-    /// * `Vec<ProjectivePoint>` - 包含承诺的列表
-    pub fn feldman_commit(&self, g: ProjectivePoint) -> Vec<ProjectivePoint> {
+    /// * `Vec<G>` - 包含承诺的列表
+    pub fn feldman_commit<G: Group<Scalar = F>>(&self, g: G) -> Vec<G> {
         let mut commitments = Vec::new();
 
         for coeff in &self.coefficients {
@@ -73,18 +180,18 @@ impl Polynomial {
     /// * `rng` - 随机数生成器
     ///
     /// # Returns
-    /// * `(Vec<ProjectivePoint>, Polynomial)` - 包含承诺列表和 blinding 因子的多项式
-    pub fn pedersen_commit<R: Rng>(
+    /// * `(Vec<G>, Polynomial<F>)` - 包含承诺列表和 blinding 因子的多项式
+    pub fn pedersen_commit<G: Group<Scalar = F>, R: RngCore + CryptoRng>(
         &self,
-        g: ProjectivePoint,
-        h: ProjectivePoint,
+        g: G,
+        h: G,
         rng: &mut R,
-    ) -> (Vec<ProjectivePoint>, Polynomial) {
+    ) -> (Vec<G>, Polynomial<F>) {
         let mut commitments = Vec::new();
         let mut blinding_factors = Vec::new();
 
         for coeff in &self.coefficients {
-            let r = Scalar::random(&mut *rng);
+            let r = F::random(&mut *rng);
             blinding_factors.push(r);
 
             // 计算 g^coeff 和 h^r
@@ -101,7 +208,263 @@ impl Polynomial {
     }
 
     /// 返回多项式的系数
-    pub fn coefficients(&self) -> &Vec<Scalar> {
+    pub fn coefficients(&self) -> &Vec<F> {
         &self.coefficients
     }
+
+    /// 返回多项式的实际次数，忽略末尾为 0 的系数；零多项式的次数记为 0
+    pub fn degree(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|c| !bool::from(c.is_zero()))
+            .unwrap_or(0)
+    }
+
+    /// 返回可以输出全部系数的包装，仅在确实需要完整输出时使用
+    pub fn reveal(&self) -> Revealed<'_, Self> {
+        Revealed(self)
+    }
+
+    /// 由系数创建多项式
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - 多项式的系数，按照次数从低到高排列
+    pub fn from_coefficients(coefficients: Vec<F>) -> Self {
+        Polynomial { coefficients }
+    }
+
+    /// 计算两个多项式的和，较短的系数向量用 0 补齐
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - 另一个多项式
+    ///
+    /// # Returns
+    ///
+    /// * `Polynomial` - 逐系数相加得到的多项式
+    pub fn add(&self, other: &Polynomial<F>) -> Polynomial<F> {
+        let (longer, shorter) = if self.coefficients.len() >= other.coefficients.len() {
+            (&self.coefficients, &other.coefficients)
+        } else {
+            (&other.coefficients, &self.coefficients)
+        };
+        let mut coefficients = longer.clone();
+        for (c, s) in coefficients.iter_mut().zip(shorter) {
+            *c += s;
+        }
+        Polynomial { coefficients }
+    }
+
+    /// 计算多项式与标量的乘积
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - 标量
+    ///
+    /// # Returns
+    ///
+    /// * `Polynomial` - 每个系数都乘以 s 的多项式
+    pub fn mul_scalar(&self, s: F) -> Polynomial<F> {
+        Polynomial {
+            coefficients: self.coefficients.iter().map(|&c| c * s).collect(),
+        }
+    }
+
+    /// 计算两个多项式的乘积（朴素卷积），结果的次数为两者次数之和
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - 另一个多项式
+    ///
+    /// # Returns
+    ///
+    /// * `Polynomial` - 乘积多项式；任一操作数没有系数时返回没有系数的零多项式
+    pub fn mul(&self, other: &Polynomial<F>) -> Polynomial<F> {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Polynomial { coefficients: Vec::new() };
+        }
+
+        let mut coefficients = vec![F::ZERO; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += *a * b;
+            }
+        }
+        Polynomial { coefficients }
+    }
+
+    /// 使用拉格朗日插值由点集恢复完整的多项式
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - 多项式上的点 (x, y)，x 坐标互不相同
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Polynomial, ReconstructError>` - 次数小于点数的插值多项式；
+    ///   点集为空时返回 `InsufficientShares`，x 坐标重复时返回 `DuplicateShareIndex`
+    pub fn lagrange_interpolate(points: &[(F, F)]) -> Result<Self, ReconstructError> {
+        if points.is_empty() {
+            return Err(ReconstructError::InsufficientShares);
+        }
+
+        // 计算 M(x) = Π (x - x_i) 的系数
+        let mut master = vec![F::ONE];
+        for &(x_i, _) in points {
+            master.insert(0, F::ZERO);
+            for k in 0..master.len() - 1 {
+                let next = master[k + 1];
+                master[k] -= next * x_i;
+            }
+        }
+
+        let mut coefficients = vec![F::ZERO; points.len()];
+        for (i, &(x_i, y_i)) in points.iter().enumerate() {
+            // 综合除法计算 M(x) / (x - x_i)，得到第 i 个基多项式的分子
+            let mut basis = vec![F::ZERO; points.len()];
+            let mut carry = F::ZERO;
+            for k in (0..points.len()).rev() {
+                carry = master[k + 1] + carry * x_i;
+                basis[k] = carry;
+            }
+
+            // 分母为 Π_{j≠i} (x_i - x_j)，x 坐标重复时为 0
+            let mut denominator = F::ONE;
+            for (j, &(x_j, _)) in points.iter().enumerate() {
+                if i != j {
+                    denominator *= x_i - x_j;
+                }
+            }
+            let inverse = Option::<F>::from(denominator.invert())
+                .ok_or(ReconstructError::DuplicateShareIndex)?;
+
+            let weight = y_i * inverse;
+            for (c, b) in coefficients.iter_mut().zip(&basis) {
+                *c += *b * weight;
+            }
+        }
+
+        Ok(Polynomial { coefficients })
+    }
+}
+
+// 只输出次数，不输出系数
+impl<F: CoefficientField> fmt::Debug for Polynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Polynomial").field("degree", &self.degree()).finish_non_exhaustive()
+    }
+}
+
+impl<F: CoefficientField> fmt::Debug for Revealed<'_, Polynomial<F>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polynomial {{ coefficients: [")?;
+        for (i, c) in self.0.coefficients.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            fmt_field(c, f)?;
+        }
+        write!(f, "] }}")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: CoefficientField> Zeroize for Polynomial<F> {
+    fn zeroize(&mut self) {
+        // Vec::zeroize 清零全部元素后清空向量
+        self.coefficients.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: CoefficientField> Drop for Polynomial<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+// 固定基标量乘法的窗口宽度（比特）
+const WINDOW_BITS: usize = 4;
+// 每个窗口中的预计算点个数
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+// 256 比特标量的窗口个数
+const WINDOWS: usize = 256 / WINDOW_BITS;
+
+/// 针对固定生成元 g 预计算窗口表的 Feldman 承诺计算器
+///
+/// sm2 库没有提供固定基标量乘法的预计算表，这里自行构建：
+/// `table[i][j] = j * 16^i * g`。此后每次计算 `g * k` 只需要 64 次点加法而不需要倍点，
+/// 预计算表只需构建一次即可在多个多项式之间复用。
+/// 构建预计算表需要 64 * 16 次点加法；此后每个系数的承诺是 64 次混合加法，
+/// 而 `feldman_commit` 的 `g * coeff` 每次约需 256 次倍点加上数十次点加法。
+/// 实际耗时与平台有关，用 `cargo bench --bench feldman_commit` 测量。
+pub struct FeldmanCommitter {
+    // 预计算的窗口表，以仿射坐标存储以便使用混合加法
+    table: Vec<[AffinePoint; WINDOW_SIZE]>,
+}
+
+impl FeldmanCommitter {
+    /// 为生成元 g 构建预计算表
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - 公共生成元
+    pub fn new(g: ProjectivePoint) -> Self {
+        let mut table = Vec::with_capacity(WINDOWS);
+        let mut base = g;
+        for _ in 0..WINDOWS {
+            // 当前窗口的 0 * base, 1 * base, ..., 15 * base
+            let mut window = [ProjectivePoint::IDENTITY; WINDOW_SIZE];
+            for j in 1..WINDOW_SIZE {
+                window[j] = window[j - 1] + base;
+            }
+            // 下一个窗口的基点为 16 * base
+            base = window[WINDOW_SIZE - 1] + base;
+            // 每个窗口一次批量求逆转换为仿射坐标
+            table.push(ProjectivePoint::batch_normalize(&window));
+        }
+        FeldmanCommitter { table }
+    }
+
+    /// 使用预计算表计算 g * k
+    ///
+    /// 查表使用常数时间选择，不会通过访存模式泄露标量
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - 标量
+    ///
+    /// # Returns
+    ///
+    /// * `ProjectivePoint` - g * k
+    pub fn mul(&self, k: &Scalar) -> ProjectivePoint {
+        let bytes = k.to_bytes();
+        let mut acc = ProjectivePoint::IDENTITY;
+        for (i, window) in self.table.iter().enumerate() {
+            // 从最低位开始取第 i 个 4 比特窗口
+            let byte = bytes[bytes.len() - 1 - i / 2];
+            let nibble = if i % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+
+            let mut selected = AffinePoint::IDENTITY;
+            for (j, point) in window.iter().enumerate().skip(1) {
+                selected.conditional_assign(point, (j as u8).ct_eq(&nibble));
+            }
+            acc += selected;
+        }
+        acc
+    }
+
+    /// 生成多项式系数的 Feldman 承诺，结果与 `Polynomial::feldman_commit` 完全相同
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - 要承诺的多项式
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<ProjectivePoint>` - 包含承诺的列表
+    pub fn commit(&self, poly: &Polynomial) -> Vec<ProjectivePoint> {
+        poly.coefficients.iter().map(|coeff| self.mul(coeff)).collect()
+    }
 }
@@ -0,0 +1,205 @@
+use alloc::vec::Vec;
+use alloc::vec;
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::{ProjectivePoint, Scalar};
+use crate::error::ReconstructError;
+use crate::secret_sharing::{interpolate_at, scalar_from_bytes};
+
+// 编码格式版本号
+const VERSION: u8 = 1;
+// 压缩点编码的长度
+const POINT_LEN: usize = 33;
+
+/// 份额所属的秘密共享方案及其公共参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// Shamir 秘密共享
+    Plain,
+    /// Feldman 可验证秘密共享及其生成元 g
+    Feldman { g: ProjectivePoint },
+    /// Pedersen 可验证秘密共享及其生成元 g 和 h
+    Pedersen { g: ProjectivePoint, h: ProjectivePoint },
+}
+
+/// 自描述的份额，携带恢复秘密所需的全部参数，恢复时不需要任何外部配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortableShare {
+    /// 秘密共享方案
+    pub scheme: Scheme,
+    /// 恢复秘密所需的最小份额数
+    pub threshold: usize,
+    /// 分发标识，同一次分发产生的份额具有相同的标识
+    pub deal_id: [u8; 16],
+    /// 份额 (x, y)
+    pub share: (Scalar, Scalar),
+}
+
+/// 处理自描述份额时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortableError {
+    /// 份额来自不同的分发，或使用了不同的方案、参数或门限值
+    InconsistentShares,
+    /// 字节编码格式错误
+    Malformed,
+    /// 恢复秘密失败
+    Reconstruct(ReconstructError),
+}
+
+impl core::fmt::Display for PortableError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PortableError::InconsistentShares => write!(f, "shares belong to different deals or parameters"),
+            PortableError::Malformed => write!(f, "malformed portable share encoding"),
+            PortableError::Reconstruct(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PortableError {}
+
+impl From<ReconstructError> for PortableError {
+    fn from(e: ReconstructError) -> Self {
+        PortableError::Reconstruct(e)
+    }
+}
+
+impl PortableShare {
+    /// 为同一次分发产生的所有份额附加方案参数
+    ///
+    /// # Arguments
+    ///
+    /// * `shares` - 份额列表
+    /// * `scheme` - 秘密共享方案
+    /// * `threshold` - 恢复秘密所需的最小份额数
+    /// * `deal_id` - 分发标识
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<PortableShare>` - 自描述份额列表
+    pub fn wrap_all(
+        shares: &[(Scalar, Scalar)],
+        scheme: Scheme,
+        threshold: usize,
+        deal_id: [u8; 16],
+    ) -> Vec<PortableShare> {
+        shares
+            .iter()
+            .map(|&share| PortableShare {
+                scheme,
+                threshold,
+                deal_id,
+                share,
+            })
+            .collect()
+    }
+
+    /// 编码为字节串：版本 || 方案标签 || 生成元 || 门限值 || 分发标识 || x || y
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![VERSION];
+        match self.scheme {
+            Scheme::Plain => bytes.push(0),
+            Scheme::Feldman { g } => {
+                bytes.push(1);
+                bytes.extend_from_slice(&g.to_bytes());
+            }
+            Scheme::Pedersen { g, h } => {
+                bytes.push(2);
+                bytes.extend_from_slice(&g.to_bytes());
+                bytes.extend_from_slice(&h.to_bytes());
+            }
+        }
+        bytes.extend_from_slice(&(self.threshold as u32).to_be_bytes());
+        bytes.extend_from_slice(&self.deal_id);
+        bytes.extend_from_slice(&self.share.0.to_bytes());
+        bytes.extend_from_slice(&self.share.1.to_bytes());
+        bytes
+    }
+
+    /// 从字节串解码，拒绝未知版本、截断的输入、无效的点和不规范的标量
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PortableError> {
+        let mut reader = Reader(bytes);
+        if reader.take(1)?[0] != VERSION {
+            return Err(PortableError::Malformed);
+        }
+        let scheme = match reader.take(1)?[0] {
+            0 => Scheme::Plain,
+            1 => Scheme::Feldman { g: reader.point()? },
+            2 => Scheme::Pedersen {
+                g: reader.point()?,
+                h: reader.point()?,
+            },
+            _ => return Err(PortableError::Malformed),
+        };
+        let mut threshold = [0u8; 4];
+        threshold.copy_from_slice(reader.take(4)?);
+        let mut deal_id = [0u8; 16];
+        deal_id.copy_from_slice(reader.take(16)?);
+        let x = reader.scalar()?;
+        let y = reader.scalar()?;
+        if !reader.0.is_empty() {
+            return Err(PortableError::Malformed);
+        }
+
+        Ok(PortableShare {
+            scheme,
+            threshold: u32::from_be_bytes(threshold) as usize,
+            deal_id,
+            share: (x, y),
+        })
+    }
+}
+
+/// 直接从自描述份额恢复秘密，方案参数和门限值均从份额本身读取
+///
+/// # Arguments
+///
+/// * `shares` - 自描述份额列表
+///
+/// # Returns
+///
+/// * `Result<Scalar, PortableError>` - 恢复的秘密；份额参数不一致或数量不足门限值时返回错误
+pub fn reconstruct_portable(shares: &[PortableShare]) -> Result<Scalar, PortableError> {
+    let first = shares
+        .first()
+        .ok_or(PortableError::Reconstruct(ReconstructError::InsufficientShares))?;
+
+    // 所有份额必须来自同一次分发并使用相同的方案参数
+    if shares.iter().any(|s| {
+        s.deal_id != first.deal_id || s.scheme != first.scheme || s.threshold != first.threshold
+    }) {
+        return Err(PortableError::InconsistentShares);
+    }
+
+    let t = first.threshold;
+    if t == 0 || shares.len() < t {
+        return Err(ReconstructError::InsufficientShares.into());
+    }
+
+    let points: Vec<(Scalar, Scalar)> = shares[..t].iter().map(|s| s.share).collect();
+    Ok(interpolate_at(&points, Scalar::ZERO)?)
+}
+
+// 按顺序读取字节串的辅助结构
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], PortableError> {
+        if self.0.len() < len {
+            return Err(PortableError::Malformed);
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn point(&mut self) -> Result<ProjectivePoint, PortableError> {
+        let mut repr = <ProjectivePoint as GroupEncoding>::Repr::default();
+        repr.copy_from_slice(self.take(POINT_LEN)?);
+        Option::from(ProjectivePoint::from_bytes(&repr)).ok_or(PortableError::Malformed)
+    }
+
+    fn scalar(&mut self) -> Result<Scalar, PortableError> {
+        scalar_from_bytes(self.take(32)?).ok_or(PortableError::Malformed)
+    }
+}
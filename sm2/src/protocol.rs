@@ -0,0 +1,320 @@
+//! 以显式消息驱动的 Feldman VSS 协议
+//!
+//! 协议拆分为消息类型和两个状态机，传输层只负责投递消息：
+//!
+//! 1. 承诺：[`VssDealer::deal`] 产生 [`DealerMessage`]。传输层广播其中的承诺，并把
+//!    [`DealerMessage::to_participant`] 得到的、只含一个份额的消息通过安全信道发给对应的参与者。
+//! 2. 验证：参与者用 [`VssParticipant::receive`] 以承诺验证自己的份额，验证失败时得到一条应广播的 [`ComplaintMessage`]。
+//! 3. 投诉：分发者对每条投诉调用 [`VssDealer::respond`]，公开被投诉的份额 [`DealerResponse`]。
+//! 4. 裁决：所有参与者用 [`VssParticipant::resolve`] 检查公开的份额；份额与承诺一致时投诉者采用它，
+//!    否则分发者作弊，应被取消资格。
+//!
+//! 被投诉的份额在第 3 步公开，因此公开的份额超过 t-1 个时秘密不再安全。
+//! 验证、份额生成均复用 `secret_sharing` 中的 Feldman 函数。
+
+use alloc::vec::Vec;
+use core::fmt;
+use sm2::{ProjectivePoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use crate::error::ShareError;
+use crate::secret::fmt_index;
+use crate::secret_sharing::{
+    generate_shares_with_feldman_vss, validate_threshold, verify_commitment_degree, verify_share_with_feldman_vss,
+};
+
+/// 处理协议消息时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolError {
+    /// 消息与当前状态不符，例如尚未收到分发消息就收到了投诉的裁决
+    UnexpectedMessage,
+    /// 分发者广播的承诺个数与门限值不符或次数不足
+    InvalidCommitments,
+    /// 参与者编号为 0 或超出 n
+    UnknownParticipant { index: usize },
+    /// 分发者为回应编号为 index 的参与者的投诉而公开的份额与承诺不一致，分发者应被取消资格
+    DealerMisbehaved { index: usize },
+    /// 门限值或份额数量不合法
+    Invalid(ShareError),
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtocolError::UnexpectedMessage => write!(f, "message is not expected in the current state"),
+            ProtocolError::InvalidCommitments => write!(f, "dealer published invalid commitments"),
+            ProtocolError::UnknownParticipant { index } => write!(f, "unknown participant {}", index),
+            ProtocolError::DealerMisbehaved { index } => {
+                write!(f, "dealer revealed an invalid share for participant {}", index)
+            }
+            ProtocolError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProtocolError {}
+
+impl From<ShareError> for ProtocolError {
+    fn from(e: ShareError) -> Self {
+        ProtocolError::Invalid(e)
+    }
+}
+
+/// 分发者在承诺轮发出的消息
+///
+/// `Debug` 输出只包含份额的 x 坐标。
+#[derive(Clone, PartialEq, Eq)]
+pub struct DealerMessage {
+    /// Feldman 承诺，向所有参与者广播
+    pub commitments: Vec<ProjectivePoint>,
+    /// 份额 (x, y)，每个份额只能通过安全信道发给 x 对应的参与者
+    pub shares: Vec<(Scalar, Scalar)>,
+}
+
+impl fmt::Debug for DealerMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DealerMessage {{ commitments: {:?}, shares: [", self.commitments)?;
+        for (i, (x, _)) in self.shares.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "(")?;
+            fmt_index(x, f)?;
+            write!(f, ", ..)")?;
+        }
+        write!(f, "] }}")
+    }
+}
+
+impl DealerMessage {
+    /// 取出发给编号为 `index` 的参与者的消息：全部承诺和该参与者自己的份额
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 参与者编号
+    ///
+    /// # Returns
+    ///
+    /// * `Option<DealerMessage>` - 只含一个份额的消息；消息中没有 x 坐标为 `index` 的份额时返回 None
+    pub fn to_participant(&self, index: usize) -> Option<DealerMessage> {
+        let x = Scalar::from(index as u64);
+        let share = self.shares.iter().find(|(share_x, _)| *share_x == x)?;
+        Some(DealerMessage {
+            commitments: self.commitments.clone(),
+            shares: alloc::vec![*share],
+        })
+    }
+}
+
+/// 参与者对收到的份额的投诉，向所有参与者和分发者广播
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplaintMessage {
+    /// 投诉者的编号
+    pub index: usize,
+}
+
+/// 分发者对投诉的回应：公开被投诉的份额，供所有参与者检查
+///
+/// `Debug` 输出只包含份额的 x 坐标。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DealerResponse {
+    /// 投诉者的份额 (x, y)
+    pub revealed_share: (Scalar, Scalar),
+}
+
+impl fmt::Debug for DealerResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DealerResponse {{ revealed_share: (")?;
+        fmt_index(&self.revealed_share.0, f)?;
+        write!(f, ", ..) }}")
+    }
+}
+
+/// VSS 协议中的分发者
+pub struct VssDealer {
+    n: usize,
+    shares: Vec<(Scalar, Scalar)>,
+    commitments: Vec<ProjectivePoint>,
+    // 已收到投诉的参与者编号
+    complaints: Vec<usize>,
+}
+
+impl VssDealer {
+    /// 为 n 个参与者生成份额和 Feldman 承诺，参与者编号为 1 到 n
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - 要分享的秘密
+    /// * `n` - 参与者总数
+    /// * `t` - 恢复秘密所需的最小份额数
+    /// * `rng` - 随机数生成器
+    ///
+    /// # Returns
+    ///
+    /// * `Result<VssDealer, ProtocolError>` - 分发者；门限值不合法时返回 `Invalid`
+    pub fn new<R: RngCore + CryptoRng>(secret: Scalar, n: usize, t: usize, rng: &mut R) -> Result<Self, ProtocolError> {
+        validate_threshold(n, t)?;
+        let (shares, commitments) = generate_shares_with_feldman_vss(secret, n, t, ProjectivePoint::GENERATOR, rng);
+        Ok(VssDealer {
+            n,
+            shares,
+            commitments,
+            complaints: Vec::new(),
+        })
+    }
+
+    /// 承诺轮：产生包含承诺和全部份额的消息
+    pub fn deal(&self) -> DealerMessage {
+        DealerMessage {
+            commitments: self.commitments.clone(),
+            shares: self.shares.clone(),
+        }
+    }
+
+    /// 投诉轮：公开投诉者的份额
+    ///
+    /// # Arguments
+    ///
+    /// * `complaint` - 参与者的投诉
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DealerResponse, ProtocolError>` - 应广播的回应；投诉者编号为 0 或超出 n 时返回 `UnknownParticipant`
+    pub fn respond(&mut self, complaint: &ComplaintMessage) -> Result<DealerResponse, ProtocolError> {
+        let index = complaint.index;
+        if index == 0 || index > self.n {
+            return Err(ProtocolError::UnknownParticipant { index });
+        }
+        if !self.complaints.contains(&index) {
+            self.complaints.push(index);
+        }
+        Ok(DealerResponse {
+            revealed_share: self.shares[index - 1],
+        })
+    }
+
+    /// 已收到投诉的参与者编号，按收到的顺序排列
+    pub fn complaints(&self) -> &[usize] {
+        &self.complaints
+    }
+}
+
+// 参与者所处的协议阶段
+enum ParticipantState {
+    // 等待分发消息
+    AwaitingDeal,
+    // 份额验证失败，已投诉，等待分发者公开份额
+    Complained { commitments: Vec<ProjectivePoint> },
+    // 持有与承诺一致的份额
+    Holding {
+        commitments: Vec<ProjectivePoint>,
+        share: (Scalar, Scalar),
+    },
+}
+
+/// VSS 协议中的参与者
+pub struct VssParticipant {
+    // 参与者编号，也是份额的 x 坐标
+    index: usize,
+    t: usize,
+    state: ParticipantState,
+}
+
+impl VssParticipant {
+    /// 创建编号为 `index` 的参与者
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 参与者编号，从 1 开始
+    /// * `t` - 约定的门限值
+    ///
+    /// # Panics
+    ///
+    /// 如果 `index` 为 0 则 panic
+    pub fn new(index: usize, t: usize) -> Self {
+        assert!(index >= 1, "participant index must be nonzero");
+        VssParticipant {
+            index,
+            t,
+            state: ParticipantState::AwaitingDeal,
+        }
+    }
+
+    /// 参与者编号
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// 验证轮：用承诺验证分发消息中属于自己的份额
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - 分发者发来的消息
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<ComplaintMessage>, ProtocolError>` - 份额有效时返回 None；消息中没有自己的份额，
+    ///   或份额与承诺不一致时返回应广播的投诉。已经处理过分发消息时返回 `UnexpectedMessage`，
+    ///   承诺与门限值不符时返回 `InvalidCommitments`
+    pub fn receive(&mut self, message: &DealerMessage) -> Result<Option<ComplaintMessage>, ProtocolError> {
+        if !matches!(self.state, ParticipantState::AwaitingDeal) {
+            return Err(ProtocolError::UnexpectedMessage);
+        }
+        verify_commitment_degree(&message.commitments, self.t).map_err(|_| ProtocolError::InvalidCommitments)?;
+
+        let commitments = message.commitments.clone();
+        let x = Scalar::from(self.index as u64);
+        match message.shares.iter().find(|(share_x, _)| *share_x == x) {
+            Some(&share) if verify_share_with_feldman_vss(share, &commitments, ProjectivePoint::GENERATOR) => {
+                self.state = ParticipantState::Holding { commitments, share };
+                Ok(None)
+            }
+            _ => {
+                self.state = ParticipantState::Complained { commitments };
+                Ok(Some(ComplaintMessage { index: self.index }))
+            }
+        }
+    }
+
+    /// 裁决轮：检查分发者为回应投诉而公开的份额
+    ///
+    /// 每个参与者都应检查所有回应，不只是针对自己投诉的回应。
+    ///
+    /// # Arguments
+    ///
+    /// * `complaint` - 被回应的投诉
+    /// * `response` - 分发者的回应
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ProtocolError>` - 公开的份额与承诺一致时返回 `Ok(())`，投诉者是自己时采用该份额；
+    ///   份额的 x 坐标与投诉者不符或与承诺不一致时返回 `DealerMisbehaved`，
+    ///   尚未收到分发消息时返回 `UnexpectedMessage`
+    pub fn resolve(&mut self, complaint: &ComplaintMessage, response: &DealerResponse) -> Result<(), ProtocolError> {
+        let commitments = match &self.state {
+            ParticipantState::AwaitingDeal => return Err(ProtocolError::UnexpectedMessage),
+            ParticipantState::Complained { commitments } | ParticipantState::Holding { commitments, .. } => commitments,
+        };
+        let share = response.revealed_share;
+        if share.0 != Scalar::from(complaint.index as u64)
+            || !verify_share_with_feldman_vss(share, commitments, ProjectivePoint::GENERATOR)
+        {
+            return Err(ProtocolError::DealerMisbehaved { index: complaint.index });
+        }
+        if complaint.index == self.index {
+            if let ParticipantState::Complained { commitments } = &mut self.state {
+                let commitments = core::mem::take(commitments);
+                self.state = ParticipantState::Holding { commitments, share };
+            }
+        }
+        Ok(())
+    }
+
+    /// 持有的有效份额；尚未收到分发消息或投诉尚未解决时返回 None
+    pub fn share(&self) -> Option<(Scalar, Scalar)> {
+        match self.state {
+            ParticipantState::Holding { share, .. } => Some(share),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! 公开可验证秘密共享 (PVSS)
+//!
+//! 基于 Schoenmakers 方案在 SM2 曲线上实现：
+//!
+//! 1. 分发者生成 t-1 次多项式 p(x)，p(0) 为秘密，公布 Feldman 承诺 C_j = g * a_j；
+//! 2. 对第 i 个接收者（x_i = i），分发者公布加密份额 Y_i = pk_i * s_i，其中 s_i = p(x_i)，
+//!    pk_i = G * sk_i 为接收者的 SM2 公钥（G 为 SM2 基点）；
+//! 3. 分发者同时给出 DLEQ 证明，证明 log_g(X_i) == log_{pk_i}(Y_i)，其中 X_i = Σ C_j * x_i^j；
+//! 4. 任何人都可以只凭公开信息验证所有证明；
+//! 5. 接收者用私钥解密得到 S_i = Y_i * sk_i^{-1} = G * s_i，
+//!    t 个解密份额在指数上插值即可恢复 G * secret。
+//!
+//! 恢复得到的是群元素 G * secret（例如作为随机数信标的输出），而不是标量秘密本身。
+//! 承诺使用的生成元 g 必须与 SM2 基点 G 相互独立（没有人知道二者之间的离散对数），
+//! 否则 C_0 = g * secret 会直接泄露 G * secret。[`generate_pvss`] 使用由固定字符串
+//! 哈希得到的生成元 [`pvss_generator`]，调用者无需自行选择 g。
+
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::{ProjectivePoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use crate::error::{ReconstructError, SecretSharingError};
+use crate::hash::hash_to_scalar;
+use crate::polynomial::Polynomial;
+use crate::secret_sharing::{evaluate_commitments, independent_generator, reconstruct_point_secret, degree_or_panic};
+
+/// 离散对数相等 (DLEQ) 的非交互式证明，使用 SM3 进行 Fiat-Shamir 变换
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DleqProof {
+    /// 挑战值 c
+    pub challenge: Scalar,
+    /// 响应值 r = w - c * s
+    pub response: Scalar,
+}
+
+impl DleqProof {
+    /// 证明 X = g1 * s 且 Y = g2 * s
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - 证明者知道的离散对数
+    /// * `g1` - 第一个底
+    /// * `g2` - 第二个底
+    /// * `rng` - 随机数生成器
+    pub fn prove<R: RngCore + CryptoRng>(s: Scalar, g1: ProjectivePoint, g2: ProjectivePoint, rng: &mut R) -> Self {
+        let w = Scalar::random(&mut *rng);
+        let challenge = dleq_challenge(g1, g1 * s, g2, g2 * s, g1 * w, g2 * w);
+        DleqProof {
+            challenge,
+            response: w - challenge * s,
+        }
+    }
+
+    /// 验证 log_{g1}(X) == log_{g2}(Y)
+    ///
+    /// # Arguments
+    ///
+    /// * `g1` - 第一个底
+    /// * `x` - X = g1 * s
+    /// * `g2` - 第二个底
+    /// * `y` - Y = g2 * s
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - 如果证明有效，则返回 true；否则返回 false
+    pub fn verify(&self, g1: ProjectivePoint, x: ProjectivePoint, g2: ProjectivePoint, y: ProjectivePoint) -> bool {
+        // 重新计算承诺值 A1 = g1 * r + X * c，A2 = g2 * r + Y * c
+        let a1 = g1 * self.response + x * self.challenge;
+        let a2 = g2 * self.response + y * self.challenge;
+        self.challenge == dleq_challenge(g1, x, g2, y, a1, a2)
+    }
+}
+
+/// 分发者公布的 PVSS 记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PvssTranscript {
+    /// 多项式系数的 Feldman 承诺
+    pub commitments: Vec<ProjectivePoint>,
+    /// 加密份额 (x_i, Y_i)，与接收者公钥一一对应
+    pub encrypted_shares: Vec<(Scalar, ProjectivePoint)>,
+    /// 每个加密份额的 DLEQ 证明
+    pub proofs: Vec<DleqProof>,
+}
+
+/// 分发一个公开可验证的秘密
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `recipient_pubkeys` - 接收者的 SM2 公钥，第 i 个接收者的 x 坐标为 i + 1
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `g` - 承诺使用的生成元，必须与 SM2 基点相互独立
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `PvssTranscript` - 可以公开发布的分发记录
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD` 则 panic
+pub fn deal_pvss<R: RngCore + CryptoRng>(
+    secret: Scalar,
+    recipient_pubkeys: &[ProjectivePoint],
+    t: usize,
+    g: ProjectivePoint,
+    rng: &mut R,
+) -> PvssTranscript {
+    let poly = Polynomial::new(secret, degree_or_panic(t), rng);
+    let commitments = poly.feldman_commit(g);
+
+    let mut encrypted_shares = Vec::with_capacity(recipient_pubkeys.len());
+    let mut proofs = Vec::with_capacity(recipient_pubkeys.len());
+    for (i, &pubkey) in recipient_pubkeys.iter().enumerate() {
+        let x = Scalar::from(i as u64 + 1);
+        let s = poly.evaluate(x);
+        // 将份额加密为 Y_i = pk_i * s_i，并证明其与承诺一致
+        encrypted_shares.push((x, pubkey * s));
+        proofs.push(DleqProof::prove(s, g, pubkey, rng));
+    }
+
+    PvssTranscript {
+        commitments,
+        encrypted_shares,
+        proofs,
+    }
+}
+
+/// 使用默认生成元 [`pvss_generator`] 分发一个公开可验证的秘密
+///
+/// 验证时同样使用 `pvss_generator()` 作为 `verify_pvss` 的生成元。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `participant_pubkeys` - 接收者的 SM2 公钥，第 i 个接收者的 x 坐标为 i + 1
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `PvssTranscript` - 可以公开发布的分发记录
+pub fn generate_pvss<R: RngCore + CryptoRng>(
+    secret: Scalar,
+    participant_pubkeys: &[ProjectivePoint],
+    t: usize,
+    rng: &mut R,
+) -> PvssTranscript {
+    deal_pvss(secret, participant_pubkeys, t, pvss_generator(), rng)
+}
+
+// 默认 PVSS 生成元的域分隔字符串
+const PVSS_GENERATOR_DOMAIN: &[u8] = b"shamir_secret_sharing/pvss/generator";
+
+/// PVSS 承诺默认使用的生成元
+///
+/// 即 `independent_generator(b"shamir_secret_sharing/pvss/generator")`，
+/// 没有人知道该点相对于 SM2 基点的离散对数。
+pub fn pvss_generator() -> ProjectivePoint {
+    independent_generator(PVSS_GENERATOR_DOMAIN)
+}
+
+/// 公开验证分发记录，不需要任何接收者的私钥
+///
+/// # Arguments
+///
+/// * `transcript` - 分发记录
+/// * `recipient_pubkeys` - 接收者的 SM2 公钥
+/// * `g` - 承诺使用的生成元
+///
+/// # Returns
+///
+/// * `bool` - 如果所有加密份额都与承诺一致，则返回 true；否则返回 false
+pub fn verify_pvss(transcript: &PvssTranscript, recipient_pubkeys: &[ProjectivePoint], g: ProjectivePoint) -> bool {
+    if transcript.commitments.is_empty()
+        || transcript.encrypted_shares.len() != recipient_pubkeys.len()
+        || transcript.proofs.len() != recipient_pubkeys.len()
+    {
+        return false;
+    }
+
+    transcript
+        .encrypted_shares
+        .iter()
+        .zip(&transcript.proofs)
+        .zip(recipient_pubkeys)
+        .enumerate()
+        .all(|(i, ((&(x, y), proof), &pubkey))| {
+            // x 坐标必须是约定的 i + 1
+            if x != Scalar::from(i as u64 + 1) {
+                return false;
+            }
+            // 由承诺计算 X_i = g * s_i
+            let commitment_at_x = evaluate_commitments(&transcript.commitments, x);
+            proof.verify(g, commitment_at_x, pubkey, y)
+        })
+}
+
+/// 接收者使用私钥解密自己的份额
+///
+/// # Arguments
+///
+/// * `encrypted_share` - 加密份额 (x_i, Y_i)
+/// * `secret_key` - 接收者的 SM2 私钥
+///
+/// # Returns
+///
+/// * `Result<(Scalar, ProjectivePoint), SecretSharingError>` - 解密后的份额 (x_i, G * s_i)；私钥为 0 时返回 `NonInvertible`
+pub fn decrypt_share(
+    encrypted_share: &(Scalar, ProjectivePoint),
+    secret_key: Scalar,
+) -> Result<(Scalar, ProjectivePoint), SecretSharingError> {
+    let (x, y) = *encrypted_share;
+    Option::<Scalar>::from(secret_key.invert())
+        .map(|inverse| (x, y * inverse))
+        .ok_or(SecretSharingError::NonInvertible)
+}
+
+/// 使用 t 个解密后的份额在指数上进行拉格朗日插值，恢复 G * secret
+///
+/// # Arguments
+///
+/// * `decrypted_shares` - 解密后的份额 (x_i, G * s_i)
+///
+/// # Returns
+///
+/// * `Result<ProjectivePoint, SecretSharingError>` - G * secret；份额为空时返回 `InsufficientShares`，
+///   x 坐标重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_pvss_secret(decrypted_shares: &[(Scalar, ProjectivePoint)]) -> Result<ProjectivePoint, SecretSharingError> {
+    reconstruct_point_secret(decrypted_shares).map_err(|error| match error {
+        ReconstructError::DuplicateShareIndex => SecretSharingError::DuplicateShareIndex,
+        _ => SecretSharingError::InsufficientShares,
+    })
+}
+
+// DLEQ 证明的 Fiat-Shamir 挑战值的域分隔字符串
+const DLEQ_CHALLENGE_DOMAIN: &[u8] = b"shamir_secret_sharing/pvss/dleq";
+
+// Fiat-Shamir 挑战值 c = hash_to_scalar(DLEQ_CHALLENGE_DOMAIN, g1 || X || g2 || Y || A1 || A2)
+fn dleq_challenge(
+    g1: ProjectivePoint,
+    x: ProjectivePoint,
+    g2: ProjectivePoint,
+    y: ProjectivePoint,
+    a1: ProjectivePoint,
+    a2: ProjectivePoint,
+) -> Scalar {
+    let mut bytes = Vec::with_capacity(6 * 33);
+    for point in [g1, x, g2, y, a1, a2] {
+        bytes.extend_from_slice(&point.to_bytes());
+    }
+    hash_to_scalar(DLEQ_CHALLENGE_DOMAIN, &bytes)
+}
@@ -0,0 +1,301 @@
+//! 不恢复秘密的份额重分发协议
+//!
+//! 协议流程：
+//!
+//! 1. 选出至少 t 个旧份额持有者参与，记其 x 坐标集合为 `old_xs`，新参与者的 x 坐标为 `new_xs`。
+//! 2. 每个旧持有者 i 计算自己在 `old_xs` 上、x = 0 处的拉格朗日系数 λ_i，
+//!    以 λ_i * y_i 为常数项生成 `new_t - 1` 次随机多项式，并调用 [`proactive_reshare`]
+//!    为每个新参与者生成一个子份额，通过安全信道发送给对应的新参与者。
+//! 3. 每个新参与者按照 `old_xs` 的顺序收集来自每个旧持有者的子份额，
+//!    调用 [`combine_reshare`] 将它们相加得到自己的新份额。
+//!
+//! 由于 Σ λ_i * y_i 等于秘密，新份额位于一个常数项为秘密的 `new_t - 1` 次多项式上，
+//! 而整个过程中没有任何一方得到秘密本身。协议假设参与的旧持有者都是诚实的。
+//!
+//! [`redistribute`] 在一处模拟上述完整流程，便于测试以及所有旧持有者位于同一进程中的场景。
+//!
+//! 只修改门限值而参与者集合不变时可以使用 [`reshare_threshold`]。
+//! 如果参与者集合和门限值都不变，只需要定期使份额失效，可以使用 [`refresh_shares`]；
+//! 撤销参与者可以使用 [`revoke_and_refresh`]，为新参与者追加份额可以使用 [`compute_new_share`]。
+
+use alloc::vec::Vec;
+use sm2::Scalar;
+use rand::{CryptoRng, RngCore};
+use crate::error::{SecretSharingError, ShareError};
+use crate::polynomial::Polynomial;
+use crate::secret_sharing::{degree_or_panic, interpolate_at, lagrange_at_zero, polynomial_degree, validate_threshold};
+
+/// 旧份额持有者为每个新参与者生成子份额
+///
+/// # Arguments
+///
+/// * `my_share` - 旧持有者自己的份额 (x, y)
+/// * `old_xs` - 参与重分发的所有旧持有者的 x 坐标，必须包含 `my_share` 的 x 坐标
+/// * `new_xs` - 新参与者的 x 坐标
+/// * `new_t` - 新的门限值
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(Scalar, Scalar)>, ShareError>` - 发送给每个新参与者的子份额，与 `new_xs` 一一对应；
+///   `old_xs` 或 `new_xs` 中有 0 时返回 `ZeroShareIndex`（x = 0 处的子份额就是 λ_i * y_i 本身），
+///   有重复时返回 `DuplicateShareIndex`；`new_xs` 为空、`new_t` 为 0、大于 `new_xs` 的个数
+///   或超过 `MAX_THRESHOLD` 时返回相应的错误
+///
+/// # Panics
+///
+/// 如果 `old_xs` 不包含 `my_share` 的 x 坐标则 panic
+pub fn proactive_reshare<R: RngCore + CryptoRng>(
+    my_share: (Scalar, Scalar),
+    old_xs: &[Scalar],
+    new_xs: &[Scalar],
+    new_t: usize,
+    rng: &mut R,
+) -> Result<Vec<(Scalar, Scalar)>, ShareError> {
+    let (x_i, y_i) = my_share;
+    assert!(old_xs.contains(&x_i), "my_share must belong to old_xs");
+    // old_xs 重复时拉格朗日系数是错误的，合并后的新份额不再位于常数项为秘密的多项式上
+    validate_xs(old_xs)?;
+    validate_xs(new_xs)?;
+    let degree = polynomial_degree(new_t)?;
+    validate_threshold(new_xs.len(), new_t)?;
+
+    // 以 λ_i * y_i 为常数项，生成 new_t - 1 次随机多项式
+    let poly = Polynomial::new(lagrange_at_zero(x_i, old_xs) * y_i, degree, rng);
+
+    Ok(new_xs.iter().map(|&x| (x, poly.evaluate(x))).collect())
+}
+
+/// 新参与者合并收到的子份额，得到自己的新份额
+///
+/// # Arguments
+///
+/// * `received_subshares` - 收到的子份额，第 i 个来自 `old_xs[i]` 对应的旧持有者
+/// * `old_xs` - 参与重分发的所有旧持有者的 x 坐标
+///
+/// # Returns
+///
+/// * `(Scalar, Scalar)` - 新份额 (x, y)
+///
+/// # Panics
+///
+/// 如果 `old_xs` 为空或有重复，子份额数量与 `old_xs` 不一致，或子份额的 x 坐标不相同则 panic
+pub fn combine_reshare(received_subshares: &[(Scalar, Scalar)], old_xs: &[Scalar]) -> (Scalar, Scalar) {
+    assert!(!old_xs.is_empty(), "at least one old shareholder is required");
+    assert!(
+        old_xs.iter().enumerate().all(|(i, x)| !old_xs[..i].contains(x)),
+        "old_xs must be distinct"
+    );
+    assert_eq!(
+        received_subshares.len(),
+        old_xs.len(),
+        "expected exactly one subshare from each old shareholder"
+    );
+    let x = received_subshares[0].0;
+    assert!(
+        received_subshares.iter().all(|&(sx, _)| sx == x),
+        "subshares must all be addressed to the same participant"
+    );
+
+    // 子份额已经按拉格朗日系数加权，直接相加即可
+    let y = received_subshares.iter().fold(Scalar::ZERO, |acc, &(_, y)| acc + y);
+    (x, y)
+}
+
+/// 将 (t_old, n) 方案下的份额重分发为 (t_new, n') 方案下的份额
+///
+/// 取前 `t_old` 个旧份额的持有者参与重分发：每个旧持有者用一个新的随机多项式
+/// 分享自己加权后的份额，每个新参与者对收到的子份额进行插值（加权求和）得到新份额。
+/// 假设至少有 `t_old` 个诚实的旧持有者参与；恶意持有者提交的错误子份额无法被发现。
+///
+/// # Arguments
+///
+/// * `old_shares` - 旧份额，至少 `t_old` 个
+/// * `t_old` - 旧门限值
+/// * `new_xs` - 新参与者的 x 坐标
+/// * `t_new` - 新门限值
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(Scalar, Scalar)>, ShareError>` - 新份额，与 `new_xs` 一一对应；
+///   `t_old` 为 0 时返回 `ZeroThreshold`，旧份额少于 `t_old` 个时返回 `InsufficientShares`；
+///   `new_xs` 为空、`t_new` 为 0、大于 `new_xs` 的个数或超过 `MAX_THRESHOLD` 时返回相应的错误；
+///   参与的旧份额或 `new_xs` 中有 0 时返回 `ZeroShareIndex`（x = 0 处的新份额就是秘密本身），
+///   有重复时返回 `DuplicateShareIndex`
+pub fn redistribute<R: RngCore + CryptoRng>(
+    old_shares: &[(Scalar, Scalar)],
+    t_old: usize,
+    new_xs: &[Scalar],
+    t_new: usize,
+    rng: &mut R,
+) -> Result<Vec<(Scalar, Scalar)>, ShareError> {
+    if t_old == 0 {
+        return Err(ShareError::ZeroThreshold);
+    }
+    if old_shares.len() < t_old {
+        return Err(ShareError::InsufficientShares);
+    }
+    // t_new 大于新参与者人数时，新份额永远无法恢复秘密
+    validate_threshold(new_xs.len(), t_new)?;
+    let participants = &old_shares[..t_old];
+    let old_xs: Vec<Scalar> = participants.iter().map(|&(x, _)| x).collect();
+
+    // 每个旧持有者为所有新参与者生成子份额
+    let outgoing: Vec<Vec<(Scalar, Scalar)>> = participants
+        .iter()
+        .map(|&share| proactive_reshare(share, &old_xs, new_xs, t_new, rng))
+        .collect::<Result<_, _>>()?;
+
+    // 每个新参与者按 old_xs 的顺序合并收到的子份额
+    Ok((0..new_xs.len())
+        .map(|j| {
+            let received: Vec<(Scalar, Scalar)> = outgoing.iter().map(|subs| subs[j]).collect();
+            combine_reshare(&received, &old_xs)
+        })
+        .collect())
+}
+
+/// 只修改门限值：参与者集合和 x 坐标保持不变，把 (old_t, n) 方案下的份额变为 (new_t, n) 方案下的份额
+///
+/// 即以全部份额的 x 坐标作为 `new_xs` 调用 [`redistribute`]：前 `old_t` 个持有者通过子份额交换
+/// 在不恢复秘密的前提下把秘密重新分享到 `new_t - 1` 次多项式上。协议假设至少有 `old_t` 个诚实的持有者参与，
+/// 恶意持有者提交的错误子份额无法被发现，会使所有新份额恢复出错误的秘密。
+/// 提高门限值后，旧份额必须销毁：任意 `old_t` 个旧份额仍能恢复秘密。
+///
+/// # Arguments
+///
+/// * `shares` - 当前的全部份额，至少 `old_t` 个
+/// * `old_t` - 当前门限值
+/// * `new_t` - 新门限值
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Vec<(Scalar, Scalar)>` - 新份额，与 `shares` 一一对应且 x 坐标相同
+///
+/// # Panics
+///
+/// 如果 `old_t` 为 0，份额少于 `old_t` 个，`new_t` 为 0 或大于份额个数，或份额的 x 坐标为 0 或有重复则 panic
+pub fn reshare_threshold<R: RngCore + CryptoRng>(
+    shares: &[(Scalar, Scalar)],
+    old_t: usize,
+    new_t: usize,
+    rng: &mut R,
+) -> Vec<(Scalar, Scalar)> {
+    let xs: Vec<Scalar> = shares.iter().map(|&(x, _)| x).collect();
+    validate_threshold(xs.len(), new_t).expect("new_t must be between 1 and the number of shares");
+    redistribute(shares, old_t, &xs, new_t, rng)
+        .expect("at least old_t shares with nonzero, distinct x-coordinates are required")
+}
+
+/// 主动刷新份额：在不改变秘密的前提下重新随机化所有份额
+///
+/// 生成一个常数项为 0 的 t-1 次随机多项式，在每个份额的 x 处求值并加到原有的 y 上。
+/// 刷新后的份额恢复出相同的秘密，但与刷新前的份额相互独立，
+/// 攻击者在不同时期窃取的新旧份额无法组合使用。
+///
+/// # Arguments
+///
+/// * `shares` - 当前的全部份额
+/// * `t` - 门限值
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Vec<(Scalar, Scalar)>` - 刷新后的份额，与 `shares` 一一对应
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD` 则 panic
+pub fn refresh_shares<R: RngCore + CryptoRng>(shares: &[(Scalar, Scalar)], t: usize, rng: &mut R) -> Vec<(Scalar, Scalar)> {
+    // 常数项为 0 的随机多项式，不改变 x = 0 处的值
+    let delta = Polynomial::new(Scalar::ZERO, degree_or_panic(t), rng);
+    shares.iter().map(|&(x, y)| (x, y + delta.evaluate(x))).collect()
+}
+
+/// 撤销一个参与者后为其余参与者重新随机化份额，使被撤销者保留的旧份额失效
+///
+/// 将剩余份额中的前 t 个在相同门限下重分发到剩余参与者自己的 x 坐标上（见 [`redistribute`]）。
+/// 新份额位于一个常数项仍为秘密、其余系数全新随机的多项式上，旧份额与新份额不在同一多项式上，
+/// 因此被撤销的份额无论与多少个新份额组合都得不到秘密。
+///
+/// 至少需要 t 个诚实的剩余参与者合作，且所有剩余参与者都必须丢弃旧份额；
+/// 被撤销者如果与仍持有旧份额的参与者合谋，撤销就没有意义。
+///
+/// # Arguments
+///
+/// * `remaining_shares` - 撤销后剩余参与者的当前份额，至少 t 个
+/// * `t` - 门限值，撤销前后保持不变
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(Scalar, Scalar)>, SecretSharingError>` - 新份额，与 `remaining_shares` 一一对应且 x 坐标不变；
+///   t 为 0 时返回 `InvalidThreshold`，剩余份额少于 t 个时返回 `InsufficientShares`，
+///   x 坐标为 0 或重复时返回相应的错误
+pub fn revoke_and_refresh<R: RngCore + CryptoRng>(
+    remaining_shares: &[(Scalar, Scalar)],
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(Scalar, Scalar)>, SecretSharingError> {
+    if t == 0 {
+        return Err(SecretSharingError::InvalidThreshold);
+    }
+    if remaining_shares.len() < t {
+        return Err(SecretSharingError::InsufficientShares);
+    }
+    let xs: Vec<Scalar> = remaining_shares.iter().map(|&(x, _)| x).collect();
+    Ok(redistribute(remaining_shares, t, &xs, t, rng)?)
+}
+
+/// 为 x 坐标为 `x_new` 的新参与者计算份额，不改变已有份额，也不需要先恢复秘密
+///
+/// 取前 t 个已有份额在 `x_new` 处插值，新份额与已有份额位于同一个多项式上。
+/// 分布式执行时，每个旧持有者只提交 λ_i(x_new) * y_i，由新参与者求和；
+/// 本函数在一处完成同样的计算，调用者必须确保这些份额不会同时出现在不可信的环境中。
+///
+/// # Arguments
+///
+/// * `existing_shares` - 已有份额，至少 t 个
+/// * `x_new` - 新参与者的 x 坐标
+/// * `t` - 门限值
+///
+/// # Returns
+///
+/// * `Result<(Scalar, Scalar), SecretSharingError>` - 新份额 (x_new, y)；t 为 0 时返回 `InvalidThreshold`，
+///   份额少于 t 个时返回 `InsufficientShares`，`x_new` 为 0 时返回 `ZeroShareIndex`，
+///   `x_new` 与已有份额的 x 坐标相同或已有份额的 x 坐标重复时返回 `DuplicateShareIndex`
+pub fn compute_new_share(
+    existing_shares: &[(Scalar, Scalar)],
+    x_new: Scalar,
+    t: usize,
+) -> Result<(Scalar, Scalar), SecretSharingError> {
+    if t == 0 {
+        return Err(SecretSharingError::InvalidThreshold);
+    }
+    if existing_shares.len() < t {
+        return Err(SecretSharingError::InsufficientShares);
+    }
+    if bool::from(x_new.is_zero()) {
+        return Err(SecretSharingError::ZeroShareIndex);
+    }
+    if existing_shares.iter().any(|&(x, _)| x == x_new) {
+        return Err(SecretSharingError::DuplicateShareIndex);
+    }
+    let y = interpolate_at(&existing_shares[..t], x_new).map_err(|_| SecretSharingError::DuplicateShareIndex)?;
+    Ok((x_new, y))
+}
+
+// 份额的 x 坐标不能为 0，也不能重复
+fn validate_xs(xs: &[Scalar]) -> Result<(), ShareError> {
+    for (i, x) in xs.iter().enumerate() {
+        if bool::from(x.is_zero()) {
+            return Err(ShareError::ZeroShareIndex);
+        }
+        if xs[..i].contains(x) {
+            return Err(ShareError::DuplicateShareIndex);
+        }
+    }
+    Ok(())
+}
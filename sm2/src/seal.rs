@@ -0,0 +1,196 @@
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::sec1::ToEncodedPoint;
+use sm2::elliptic_curve::subtle::ConstantTimeEq;
+use sm2::elliptic_curve::group::Group;
+use sm2::{ProjectivePoint, Scalar};
+use sm3::{Digest, Sm3};
+use rand::{CryptoRng, RngCore};
+use crate::secret_sharing::scalar_from_bytes;
+
+/// 密封份额时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SealError {
+    /// 份额数量与接收者公钥数量不一致
+    LengthMismatch,
+    /// 接收者公钥或密文中的临时公钥为无穷远点
+    InvalidPoint,
+    /// 密文完整性校验失败（密文被篡改或使用了错误的私钥）
+    InvalidCiphertext,
+    /// 解密得到的份额不是规范编码的标量
+    NonCanonicalScalar,
+}
+
+impl core::fmt::Display for SealError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SealError::LengthMismatch => write!(f, "number of shares and recipients differ"),
+            SealError::InvalidPoint => write!(f, "point at infinity is not a valid key"),
+            SealError::InvalidCiphertext => write!(f, "ciphertext integrity check failed"),
+            SealError::NonCanonicalScalar => write!(f, "decrypted share is not a canonical scalar"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SealError {}
+
+/// 使用 SM2 公钥加密算法 (GB/T 32918.4) 加密到某个接收者的份额
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedShare {
+    /// C1：临时公钥 k * G
+    pub ephemeral: ProjectivePoint,
+    /// C2：份额 x || y 与密钥流异或后的密文
+    pub ciphertext: [u8; 64],
+    /// C3：SM3(x2 || M || y2) 完整性校验值
+    pub tag: [u8; 32],
+}
+
+/// 将每个份额加密给对应的接收者
+///
+/// # Arguments
+///
+/// * `shares` - 要分发的份额列表
+/// * `recipient_pubkeys` - 接收者的 SM2 公钥，第 i 个公钥对应第 i 个份额
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<SealedShare>, SealError>` - 与份额一一对应的密封份额
+pub fn seal_shares<R: RngCore + CryptoRng>(
+    shares: &[(Scalar, Scalar)],
+    recipient_pubkeys: &[ProjectivePoint],
+    rng: &mut R,
+) -> Result<Vec<SealedShare>, SealError> {
+    if shares.len() != recipient_pubkeys.len() {
+        return Err(SealError::LengthMismatch);
+    }
+
+    shares
+        .iter()
+        .zip(recipient_pubkeys)
+        .map(|(&(x, y), &pubkey)| seal_share((x, y), pubkey, rng))
+        .collect()
+}
+
+/// 将单个份额加密给接收者
+///
+/// # Arguments
+///
+/// * `share` - 要加密的份额 (x, y)
+/// * `recipient_pubkey` - 接收者的 SM2 公钥
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<SealedShare, SealError>` - 密封后的份额
+pub fn seal_share<R: RngCore + CryptoRng>(
+    share: (Scalar, Scalar),
+    recipient_pubkey: ProjectivePoint,
+    rng: &mut R,
+) -> Result<SealedShare, SealError> {
+    if bool::from(recipient_pubkey.is_identity()) {
+        return Err(SealError::InvalidPoint);
+    }
+
+    let (x, y) = share;
+    let mut plaintext = [0u8; 64];
+    plaintext[..32].copy_from_slice(&x.to_bytes());
+    plaintext[32..].copy_from_slice(&y.to_bytes());
+
+    loop {
+        // 选取非零的临时私钥 k
+        let k = Scalar::random(&mut *rng);
+        if bool::from(k.is_zero()) {
+            continue;
+        }
+
+        let ephemeral = ProjectivePoint::GENERATOR * k;
+        let (x2, y2) = coordinates(&(recipient_pubkey * k)).ok_or(SealError::InvalidPoint)?;
+        let keystream = kdf(&x2, &y2);
+        // 密钥流全为零时需要重新选取 k
+        if keystream.iter().all(|&b| b == 0) {
+            continue;
+        }
+
+        let mut ciphertext = [0u8; 64];
+        for (c, (m, k)) in ciphertext.iter_mut().zip(plaintext.iter().zip(&keystream)) {
+            *c = m ^ k;
+        }
+        let tag = tag(&x2, &plaintext, &y2);
+
+        return Ok(SealedShare {
+            ephemeral,
+            ciphertext,
+            tag,
+        });
+    }
+}
+
+/// 使用接收者的私钥打开密封的份额
+///
+/// # Arguments
+///
+/// * `sealed` - 密封的份额
+/// * `my_secret_key` - 接收者的 SM2 私钥
+///
+/// # Returns
+///
+/// * `Result<(Scalar, Scalar), SealError>` - 解密得到的份额 (x, y)
+pub fn open_share(sealed: &SealedShare, my_secret_key: Scalar) -> Result<(Scalar, Scalar), SealError> {
+    if bool::from(sealed.ephemeral.is_identity()) {
+        return Err(SealError::InvalidPoint);
+    }
+
+    let (x2, y2) = coordinates(&(sealed.ephemeral * my_secret_key)).ok_or(SealError::InvalidPoint)?;
+    let keystream = kdf(&x2, &y2);
+
+    let mut plaintext = [0u8; 64];
+    for (m, (c, k)) in plaintext.iter_mut().zip(sealed.ciphertext.iter().zip(&keystream)) {
+        *m = c ^ k;
+    }
+
+    // 以常数时间比较完整性校验值
+    if !bool::from(tag(&x2, &plaintext, &y2).ct_eq(&sealed.tag)) {
+        return Err(SealError::InvalidCiphertext);
+    }
+
+    match (scalar_from_bytes(&plaintext[..32]), scalar_from_bytes(&plaintext[32..])) {
+        (Some(x), Some(y)) => Ok((x, y)),
+        _ => Err(SealError::NonCanonicalScalar),
+    }
+}
+
+// 返回点的仿射坐标 (x2, y2)，无穷远点没有仿射坐标
+fn coordinates(point: &ProjectivePoint) -> Option<([u8; 32], [u8; 32])> {
+    let encoded = point.to_affine().to_encoded_point(false);
+    let mut x2 = [0u8; 32];
+    let mut y2 = [0u8; 32];
+    x2.copy_from_slice(encoded.x()?);
+    y2.copy_from_slice(encoded.y()?);
+    Some((x2, y2))
+}
+
+// SM2 密钥派生函数 KDF(x2 || y2, 512)
+fn kdf(x2: &[u8; 32], y2: &[u8; 32]) -> [u8; 64] {
+    let mut keystream = [0u8; 64];
+    for (counter, block) in keystream.chunks_mut(32).enumerate() {
+        let digest = Sm3::new()
+            .chain_update(x2)
+            .chain_update(y2)
+            .chain_update((counter as u32 + 1).to_be_bytes())
+            .finalize();
+        block.copy_from_slice(&digest);
+    }
+    keystream
+}
+
+// C3 = SM3(x2 || M || y2)
+fn tag(x2: &[u8; 32], plaintext: &[u8; 64], y2: &[u8; 32]) -> [u8; 32] {
+    Sm3::new()
+        .chain_update(x2)
+        .chain_update(plaintext)
+        .chain_update(y2)
+        .finalize()
+        .into()
+}
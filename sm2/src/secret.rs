@@ -0,0 +1,84 @@
+//! 持有秘密值的包装类型
+//!
+//! 启用 `zeroize` 特性后，[`SecretScalar`] 和 [`Polynomial`](crate::polynomial::Polynomial)
+//! 会在释放时清除内存中的秘密。这只是尽力而为：Rust 的移动和复制语义可能在栈上
+//! 或寄存器中留下无法追踪的副本，清除操作只覆盖值最终所在的位置。
+//!
+//! 持有秘密的类型（[`SecretScalar`]、[`Polynomial`](crate::polynomial::Polynomial)、
+//! [`Share`](crate::share::Share)）的 `Debug` 和 `Display` 输出都不包含秘密，可以安全地写入日志；
+//! 确实需要完整输出时，调用各自的 `reveal()` 显式取得 [`Revealed`] 包装。
+
+use core::fmt;
+use sm2::elliptic_curve::ff::PrimeField;
+use sm2::Scalar;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// 恢复得到的秘密，启用 `zeroize` 特性时在释放时清零
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretScalar(Scalar);
+
+impl SecretScalar {
+    /// 包装一个秘密标量
+    pub fn new(secret: Scalar) -> Self {
+        SecretScalar(secret)
+    }
+
+    /// 返回秘密的引用
+    pub fn reveal(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+impl From<Scalar> for SecretScalar {
+    fn from(secret: Scalar) -> Self {
+        SecretScalar(secret)
+    }
+}
+
+// 不输出秘密本身，避免通过日志泄露
+impl fmt::Debug for SecretScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretScalar(..)")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for SecretScalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretScalar {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// 显式请求输出完整秘密内容时得到的包装，其 `Debug` 和 `Display` 输出包含秘密本身
+///
+/// 由 `Polynomial::reveal` 和 `Share::reveal` 返回，只应在确实需要完整输出时使用。
+pub struct Revealed<'a, T>(pub(crate) &'a T);
+
+// 以 0x 开头的十六进制输出域元素的规范大端编码
+pub(crate) fn fmt_field<F: PrimeField>(value: &F, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "0x")?;
+    for byte in value.to_repr().as_ref() {
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}
+
+// 份额的 x 坐标通常是小整数，能放进 u64 时以十进制输出
+pub(crate) fn fmt_index(index: &Scalar, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let bytes = index.to_bytes();
+    if bytes[..24].iter().all(|&b| b == 0) {
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&bytes[24..]);
+        write!(f, "{}", u64::from_be_bytes(low))
+    } else {
+        fmt_field(index, f)
+    }
+}
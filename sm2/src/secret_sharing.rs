@@ -1,94 +1,1407 @@
-use sm2::elliptic_curve::ff::Field;
-use sm2::{ProjectivePoint, Scalar};
-use rand::Rng;
-use crate::polynomial::Polynomial;
+use alloc::vec;
+use alloc::vec::Vec;
+use alloc::collections::BTreeSet;
+use core::marker::PhantomData;
+use sm2::elliptic_curve::ff::{Field, PrimeField};
+use sm2::elliptic_curve::group::{Group, GroupEncoding};
+use sm2::elliptic_curve::subtle::{Choice, ConstantTimeEq};
+use sm2::{AffinePoint, FieldBytes, ProjectivePoint, Scalar};
+use sm3::{Digest, Sm3};
+use rand::seq::SliceRandom;
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+use crate::error::{CommitmentError, DecodeError, IndexMismatch, ReconstructError, SecretSharingError, ShareError};
+use crate::hash::hash_to_curve;
+use crate::msm::multi_scalar_mul;
+use crate::polynomial::{CoefficientField, FeldmanCommitter, Polynomial};
 
 /// 生成 n 个份额，至少需要 t 个份额才能恢复秘密
-pub fn generate_shares<R: Rng>(secret: Scalar, n: usize, t: usize, rng: &mut R) -> Vec<(Scalar, Scalar)> {
-    // 创建一个 t-1 次的随机多项式，其常数项为秘密值
-    let poly = Polynomial::new(secret, t - 1, rng);
-    // 生成 n 个份额，每个份额是一个 (x, y) 对
-    (1..=n).map(|i| {
-        // x 坐标为 1 到 n 的整数
-        let x = Scalar::from(i as u64);
-        // y 坐标为多项式在 x 处的值
-        let y = poly.evaluate(x);
-        // 返回 (x, y) 对
-        (x, y)
-    }).collect()
-}
-
-/// 使用拉格朗日插值恢复秘密
-pub fn reconstruct_secret(shares: &[(Scalar, Scalar)]) -> Scalar {
-    // 初始化秘密为 0
-    let mut secret = Scalar::ZERO;
-    // 遍历每个份额
-    for (i, &(x_i, y_i)) in shares.iter().enumerate() {
+///
+/// 多项式的次数为 t - 1，两个极端情况不需要特殊处理：
+///
+/// - t = 1 时多项式是常数 f(x) = secret，每个份额的 y 都等于秘密本身，任意一个份额即可恢复，
+///   相当于把秘密复制 n 份；
+/// - t = n 时必须集齐全部 n 个份额，任意 n - 1 个份额不泄露秘密的任何信息。
+///
+/// t 为 0 或大于 n 时没有意义，需要检查参数时请使用 `try_generate_shares`。
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD`，或 n 不小于域的阶（x 坐标会回绕为 0）则 panic
+pub fn generate_shares<F: CoefficientField, R: RngCore + CryptoRng>(secret: F, n: usize, t: usize, rng: &mut R) -> Vec<(F, F)> {
+    generate_shares_with_rng_unchecked(secret, n, t, rng)
+}
+
+/// 与 `generate_shares` 相同，但接受任意随机数生成器，仅用于确定性测试
+///
+/// 多项式的系数必须保密，在生产环境中使用非密码学安全的随机数生成器会泄露秘密
+pub fn generate_shares_with_rng_unchecked<F: CoefficientField, R: RngCore>(
+    secret: F,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Vec<(F, F)> {
+    assert!(fits_in_field::<F>(n), "n must be smaller than the field order");
+    // x 坐标为 1 到 n 的整数
+    let xs: Vec<F> = (1..=n).map(|i| F::from(i as u64)).collect();
+    shares_at(secret, &xs, t, rng)
+}
+
+/// 生成 n 个份额，同时返回生成份额的多项式，供 DKG、零知识证明等需要多项式本身的协议使用
+///
+/// 多项式的常数项就是秘密，其余系数一旦泄露，结合 t-1 个份额也能恢复秘密，
+/// 因此返回的多项式与秘密同等敏感：只应在分发者本地使用，用完后尽快释放，不要记录日志或序列化。
+/// 启用 `zeroize` 特性时多项式在释放时会清除系数。份额与使用相同状态的随机数生成器调用
+/// `generate_shares` 得到的份额完全相同。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `(Vec<(F, F)>, Polynomial<F>)` - x 坐标为 1 到 n 的份额，以及 t-1 次的多项式
+pub fn generate_shares_with_polynomial<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> (Vec<(F, F)>, Polynomial<F>) {
+    let xs: Vec<F> = (1..=n).map(|i| F::from(i as u64)).collect();
+    shares_and_polynomial_at(secret, &xs, t, rng)
+}
+
+/// 生成 N 个份额并以定长数组返回，份额个数在编译期确定，适合在嵌入式设备上把份额保存在栈上
+///
+/// 输出数组不占用堆内存，但多项式的 t 个系数仍在堆上分配。x 坐标为 1 到 N 的整数，
+/// 与 `generate_shares(secret, N, t, rng)` 使用相同状态的随机数生成器时得到相同的份额。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `[(F, F); N]` - N 个份额
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 N 则 panic
+pub fn generate_shares_const<F: CoefficientField, R: RngCore + CryptoRng, const N: usize>(
+    secret: F,
+    t: usize,
+    rng: &mut R,
+) -> [(F, F); N] {
+    validate_share_count::<F>(N, t).expect("threshold must be between 1 and N, and N below the field order");
+    let poly = Polynomial::new(secret, degree_or_panic(t), rng);
+    core::array::from_fn(|i| {
+        let x = F::from(i as u64 + 1);
+        (x, poly.evaluate(x))
+    })
+}
+
+/// 惰性地生成 n 个份额，每次迭代只计算一个份额，适合把大量份额直接写入文件或网络而不在内存中缓存
+///
+/// 多项式在调用时创建一次并由迭代器持有，x 坐标和份额的值与 `generate_shares` 相同：
+/// 使用相同状态的随机数生成器时，两者得到完全相同的份额。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器，只在创建多项式时使用
+///
+/// # Returns
+///
+/// * `impl Iterator<Item = (F, F)>` - 依次产生 x = 1 到 n 的份额
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD`，或 n 不小于域的阶则 panic
+pub fn generate_shares_iter<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> impl Iterator<Item = (F, F)> {
+    assert!(fits_in_field::<F>(n), "n must be smaller than the field order");
+    let poly = Polynomial::new(secret, degree_or_panic(t), rng);
+    (1..=n).map(move |i| {
+        let x = F::from(i as u64);
+        (x, poly.evaluate(x))
+    })
+}
+
+/// 由 32 字节种子确定性地生成 n 个份额，相同的秘密和种子总是得到相同的份额
+///
+/// 内部使用以种子初始化的 ChaCha20 随机数生成器，适用于确定性的密钥派生方案、
+/// 备份恢复流程和测试向量。种子必须与秘密一样保密，泄露种子等同于泄露多项式。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `seed` - ChaCha20 的种子
+///
+/// # Returns
+///
+/// * `Vec<(F, F)>` - 包含份额的列表
+pub fn generate_shares_deterministic<F: CoefficientField>(secret: F, n: usize, t: usize, seed: [u8; 32]) -> Vec<(F, F)> {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    generate_shares(secret, n, t, &mut rng)
+}
+
+/// 在调用者指定的 x 坐标上生成份额，至少需要 t 个份额才能恢复秘密
+///
+/// 适用于参与者拥有外部分配的、不连续的编号的场景。
+/// x 坐标不能为 0（否则份额的值就是秘密本身），也不能重复。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `xs` - 每个份额的 x 坐标
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(F, F)>, ShareError>` - 与 `xs` 一一对应的份额列表
+pub fn generate_shares_at<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    xs: &[F],
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(F, F)>, ShareError> {
+    validate_threshold(xs.len(), t)?;
+    for (i, x) in xs.iter().enumerate() {
+        if bool::from(x.is_zero()) {
+            return Err(ShareError::ZeroShareIndex);
+        }
+        if xs[..i].contains(x) {
+            return Err(ShareError::DuplicateShareIndex);
+        }
+    }
+    Ok(shares_at(secret, xs, t, rng))
+}
+
+/// 与 `generate_shares_at` 相同，但以整数编号作为 x 坐标，省去调用者的 `F::from` 转换
+///
+/// 编号 0 会被拒绝，因为 x = 0 处的份额就是秘密本身；编号也不能重复。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `indices` - 每个份额的编号
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(u32, F)>, ShareError>` - 与 `indices` 一一对应的 (编号, y) 份额列表
+pub fn generate_shares_indexed<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    indices: &[u32],
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(u32, F)>, ShareError> {
+    let xs: Vec<F> = indices.iter().map(|&i| F::from(u64::from(i))).collect();
+    let shares = generate_shares_at(secret, &xs, t, rng)?;
+    Ok(indices.iter().zip(shares).map(|(&i, (_, y))| (i, y)).collect())
+}
+
+// 启用 rayon 特性时，每个线程负责的连续 x 坐标个数
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 1024;
+
+// 创建一个 t-1 次的随机多项式，其常数项为秘密值，并在每个 x 处求值
+fn shares_at<F: CoefficientField, R: RngCore>(secret: F, xs: &[F], t: usize, rng: &mut R) -> Vec<(F, F)> {
+    shares_and_polynomial_at(secret, xs, t, rng).0
+}
+
+// 与 shares_at 相同，同时返回生成份额的多项式
+fn shares_and_polynomial_at<F: CoefficientField, R: RngCore>(
+    secret: F,
+    xs: &[F],
+    t: usize,
+    rng: &mut R,
+) -> (Vec<(F, F)>, Polynomial<F>) {
+    let poly = Polynomial::new_with_rng_unchecked(secret, degree_or_panic(t), rng);
+    // 分块并行求值，等差数列的每一块仍是等差数列，块内继续使用差分表；结果顺序与 xs 一致
+    #[cfg(feature = "rayon")]
+    let ys: Vec<F> = xs
+        .par_chunks(PAR_CHUNK)
+        .flat_map_iter(|chunk| poly.evaluate_many(chunk))
+        .collect();
+    #[cfg(not(feature = "rayon"))]
+    let ys = poly.evaluate_many(xs);
+    // 每个份额是一个 (x, y) 对，y 坐标为多项式在 x 处的值
+    (xs.iter().copied().zip(ys).collect(), poly)
+}
+
+/// 与 `generate_shares` 相同，但会先检查参数，对不可能满足的门限值返回错误而不是 panic
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(F, F)>, ShareError>` - 份额列表；n 为 0、t 为 0、t 大于 n 或 t 超过 `MAX_THRESHOLD` 时返回错误，
+///   n 不小于域的阶（x 坐标会回绕为 0）时返回 `ZeroShareIndex`
+pub fn try_generate_shares<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(F, F)>, ShareError> {
+    validate_share_count::<F>(n, t)?;
+    Ok(generate_shares(secret, n, t, rng))
+}
+
+/// 门限值的上限
+///
+/// 多项式有 t 个系数，插值和承诺验证的开销随 t 平方增长。超过此上限的门限值没有实际用途，
+/// 通常是调用者的参数计算出错（例如减法下溢），生成份额的函数会在分配任何内存之前拒绝。
+pub const MAX_THRESHOLD: usize = 1 << 16;
+
+/// 检查份额总数和门限值是否合法
+pub(crate) fn validate_threshold(n: usize, t: usize) -> Result<(), ShareError> {
+    if n == 0 {
+        return Err(ShareError::ZeroShares);
+    }
+    polynomial_degree(t)?;
+    if t > n {
+        return Err(ShareError::ThresholdExceedsShares);
+    }
+    Ok(())
+}
+
+/// 在 `validate_threshold` 的基础上检查 x = 1..=n 在域 F 中互不相同且都不为 0
+///
+/// 域的阶 p 不大于 n 时 x = p 会回绕为 0，此时返回 `ZeroShareIndex`
+pub(crate) fn validate_share_count<F: PrimeField>(n: usize, t: usize) -> Result<(), ShareError> {
+    validate_threshold(n, t)?;
+    if !fits_in_field::<F>(n) {
+        return Err(ShareError::ZeroShareIndex);
+    }
+    Ok(())
+}
+
+// x = 1..=total 在域中互不相同且都不为 0，当且仅当域的阶 p 大于 total，
+// 即 2..=total 中没有整数在域中为 0。p 不小于 2^(NUM_BITS-1)，大域上无需逐个检查
+pub(crate) fn fits_in_field<F: PrimeField>(total: usize) -> bool {
+    if F::NUM_BITS > 64 || (total as u64) >> (F::NUM_BITS - 1) == 0 {
+        return true;
+    }
+    (2..=total as u64).all(|s| !bool::from(F::from(s).is_zero()))
+}
+
+/// 由门限值计算多项式的次数 t - 1
+///
+/// t 为 0 时返回 `ZeroThreshold`，超过 `MAX_THRESHOLD` 时返回 `ThresholdTooLarge`
+pub(crate) fn polynomial_degree(t: usize) -> Result<usize, ShareError> {
+    if t > MAX_THRESHOLD {
+        return Err(ShareError::ThresholdTooLarge);
+    }
+    t.checked_sub(1).ok_or(ShareError::ZeroThreshold)
+}
+
+// 供不返回 Result 的生成函数使用：t 不合法时在分配多项式之前 panic，而不是在 release 模式下下溢
+pub(crate) fn degree_or_panic(t: usize) -> usize {
+    polynomial_degree(t).expect("threshold must be between 1 and MAX_THRESHOLD")
+}
+
+/// 生成 n 个份额并随机打乱其顺序，使公开发布的顺序不再暴露参与者与 x 坐标的对应关系
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `(Vec<(F, F)>, Vec<usize>)` - 打乱后的份额，以及分发者需要私下保存的置换：
+///   打乱后的第 k 个份额是原顺序中的第 `permutation[k]` 个份额
+pub fn generate_shares_shuffled<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> (Vec<(F, F)>, Vec<usize>) {
+    let shares = generate_shares(secret, n, t, rng);
+
+    let mut permutation: Vec<usize> = (0..n).collect();
+    permutation.shuffle(rng);
+
+    let shuffled = permutation.iter().map(|&i| shares[i]).collect();
+    (shuffled, permutation)
+}
+
+/// 生成 n 个份额，每个份额的 x 坐标是不放回地从全体非零域元素中随机抽取的，而不是 1 到 n
+///
+/// 与 `generate_shares_shuffled` 只打乱顺序不同，x 坐标本身不再暴露参与者的次序或总人数。
+/// x 坐标必须非零（x = 0 处的值就是秘密）且互不相同（否则无法插值），抽到 0 或已抽到的值时会重新抽取，
+/// 已抽到的值按编码记录在有序集合中。x 坐标无需保密，但必须与 y 值一起保存，恢复时照常使用 `reconstruct_secret`。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器，同时用于抽取 x 坐标和多项式的系数
+///
+/// # Returns
+///
+/// * `Result<Vec<(F, F)>, ShareError>` - n 个份额，x 坐标互不相同且非零；n 为 0、t 为 0、t 大于 n
+///   或 t 超过 `MAX_THRESHOLD` 时返回错误，非零域元素不足 n 个时返回 `ZeroShareIndex`
+pub fn generate_shares_random_indices<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(F, F)>, ShareError> {
+    let degree = polynomial_degree(t)?;
+    validate_share_count::<F>(n, t)?;
+    let mut seen: BTreeSet<Vec<u8>> = BTreeSet::new();
+    let mut xs: Vec<F> = Vec::with_capacity(n);
+    while xs.len() < n {
+        let x = F::random(&mut *rng);
+        if !bool::from(x.is_zero()) && seen.insert(x.to_repr().as_ref().to_vec()) {
+            xs.push(x);
+        }
+    }
+    let poly = Polynomial::new(secret, degree, rng);
+    Ok(xs.into_iter().map(|x| (x, poly.evaluate(x))).collect())
+}
+
+/// 使用拉格朗日插值恢复秘密，即 `interpolate_at(shares, F::ZERO)`
+///
+/// # Panics
+///
+/// 如果份额的 x 坐标有重复则 panic
+pub fn reconstruct_secret<F: CoefficientField>(shares: &[(F, F)]) -> F {
+    interpolate_at(shares, F::ZERO).expect("share x-coordinates must be distinct")
+}
+
+/// 恢复秘密，同时返回每个份额的加权贡献 y_i * λ_i，供审计和排查错误份额使用
+///
+/// 贡献之和就是秘密。恢复结果不对时，可以比较不同份额子集下同一份额的贡献来定位出错的份额。
+/// 贡献值与秘密同等敏感，不要记录到日志中。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+///
+/// # Returns
+///
+/// * `(F, Vec<F>)` - 恢复的秘密，以及与 `shares` 一一对应的贡献
+///
+/// # Panics
+///
+/// 如果份额的 x 坐标有重复则 panic
+pub fn reconstruct_secret_with_contributions<F: CoefficientField>(shares: &[(F, F)]) -> (F, Vec<F>) {
+    let xs: Vec<F> = shares.iter().map(|&(x, _)| x).collect();
+    let coefficients = lagrange_coefficients(&xs, F::ZERO).expect("share x-coordinates must be distinct");
+    let contributions: Vec<F> = shares
+        .iter()
+        .zip(coefficients)
+        .map(|(&(_, y_i), coefficient)| y_i * coefficient)
+        .collect();
+    let secret = contributions.iter().fold(F::ZERO, |acc, &contribution| acc + contribution);
+    (secret, contributions)
+}
+
+/// 利用 x = 0 的结构恢复秘密，结果与 `reconstruct_secret` 完全相同
+///
+/// 在 x = 0 处，第 i 个拉格朗日分子 Π_{j≠i} (0 - x_j) 等于 (-1)^(t-1) * P / x_i，其中 P = Π x_j。
+/// 因此只需计算一次 P，再把 x_i 并入分母一起批量求逆，省去了每一项分子的 t-1 次乘法，
+/// 分子部分从 t(t-1) 次乘法降为 t 次，总乘法次数约减半，但复杂度仍为 O(t^2)。
+/// 实际耗时与平台有关，用 `cargo bench --bench reconstruct_fast` 测量。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；存在 x 坐标为 0 的份额时返回 `ZeroShareIndex`，
+///   x 坐标重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_secret_fast<F: CoefficientField>(shares: &[(F, F)]) -> Result<F, ReconstructError> {
+    if shares.iter().any(|(x, _)| bool::from(x.is_zero())) {
+        return Err(ReconstructError::ZeroShareIndex);
+    }
+    // (-1)^(t-1) * Π x_j，所有分子的公共部分
+    let product = shares.iter().fold(F::ONE, |acc, &(x_j, _)| acc * x_j);
+    let common = if shares.len().is_multiple_of(2) { -product } else { product };
+
+    // 第 i 项的分母为 x_i * Π_{j≠i} (x_i - x_j)
+    let denominators: Vec<F> = shares
+        .iter()
+        .enumerate()
+        .map(|(i, &(x_i, _))| {
+            shares
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(x_i, |acc, (_, &(x_j, _))| acc * (x_i - x_j))
+        })
+        .collect();
+    let inverses = batch_invert(&denominators).ok_or(ReconstructError::DuplicateShareIndex)?;
+    let sum = shares
+        .iter()
+        .zip(inverses)
+        .fold(F::ZERO, |acc, (&(_, y_i), inverse)| acc + y_i * inverse);
+    Ok(common * sum)
+}
+
+/// 使用拉格朗日插值计算份额所在多项式在任意点 x 处的值，例如为新参与者计算份额
+///
+/// x 可以与某个已有份额的 x 坐标相同，此时返回该份额的 y 值。
+/// 所有拉格朗日分母使用 Montgomery 技巧一次批量求逆（一次求逆加 3(t-1) 次乘法），
+/// 而逐项求逆需要 t 次求逆，每次求逆的开销相当于数百次乘法。
+/// 实际耗时与平台有关，用 `cargo bench --bench batch_inversion` 测量。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `x` - 插值点
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 多项式在 x 处的值；x 坐标重复时返回 `DuplicateShareIndex`
+pub fn interpolate_at<F: CoefficientField>(shares: &[(F, F)], x: F) -> Result<F, ReconstructError> {
+    let xs: Vec<F> = shares.iter().map(|&(x_i, _)| x_i).collect();
+    let coefficients = lagrange_coefficients(&xs, x)?;
+    // 将 y_i 乘以拉格朗日系数并累加
+    Ok(shares
+        .iter()
+        .zip(coefficients)
+        .fold(F::ZERO, |acc, (&(_, y_i), coefficient)| acc + y_i * coefficient))
+}
+
+/// 计算一组 x 坐标在点 at 处的拉格朗日系数 λ_i = Π_{j≠i} (at - x_j) / (x_i - x_j)
+///
+/// 系数只依赖 x 坐标而与 y 值无关，调用者可以缓存后用于同一组 x 坐标的不同 y 向量
+/// （例如门限签名中同一组签名者签多条消息），多项式在 at 处的值即为 Σ λ_i * y_i。
+///
+/// # Arguments
+///
+/// * `xs` - 份额的 x 坐标
+/// * `at` - 插值点，恢复秘密时为 0
+///
+/// # Returns
+///
+/// * `Result<Vec<F>, ReconstructError>` - 与 `xs` 一一对应的系数；x 坐标重复时返回 `DuplicateShareIndex`
+pub fn lagrange_coefficients<F: CoefficientField>(xs: &[F], at: F) -> Result<Vec<F>, ReconstructError> {
+    let mut numerators = Vec::with_capacity(xs.len());
+    let mut denominators = Vec::with_capacity(xs.len());
+    // 遍历每个 x 坐标
+    for (i, &x_i) in xs.iter().enumerate() {
         // 初始化分子和分母为 1
-        let mut numerator = Scalar::ONE;
-        let mut denominator = Scalar::ONE;
-        // 遍历其他份额，计算拉格朗日插值多项式的系数
+        let mut numerator = F::ONE;
+        let mut denominator = F::ONE;
+        // 遍历其他 x 坐标，计算拉格朗日基多项式在 at 处的值
+        for (j, &x_j) in xs.iter().enumerate() {
+            // 如果是同一个份额，则跳过
+            if i != j {
+                // 分子乘以 (at - x_j)
+                numerator *= at - x_j;
+                // 分母乘以 (x_i - x_j)
+                denominator *= x_i - x_j;
+            }
+        }
+        numerators.push(numerator);
+        denominators.push(denominator);
+    }
+
+    // 所有分母一次批量求逆；x 坐标重复时分母为 0，无法求逆
+    let inverses = batch_invert(&denominators).ok_or(ReconstructError::DuplicateShareIndex)?;
+    Ok(numerators
+        .into_iter()
+        .zip(inverses)
+        .map(|(numerator, inverse)| numerator * inverse)
+        .collect())
+}
+
+/// 从按列存放的 x 坐标和 y 值恢复秘密，结果与 `reconstruct_secret` 相同
+///
+/// 调用者已经以两个数组（struct-of-arrays）保存份额时，无需先组合成 (x, y) 元组。
+///
+/// # Arguments
+///
+/// * `xs` - 份额的 x 坐标
+/// * `ys` - 份额的 y 值，与 `xs` 一一对应
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；两个数组长度不同时返回 `InconsistentShares`，
+///   数组为空时返回 `InsufficientShares`，x 坐标重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_secret_columnar<F: CoefficientField>(xs: &[F], ys: &[F]) -> Result<F, ReconstructError> {
+    if xs.len() != ys.len() {
+        return Err(ReconstructError::InconsistentShares);
+    }
+    if xs.is_empty() {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    let coefficients = lagrange_coefficients(xs, F::ZERO)?;
+    Ok(ys
+        .iter()
+        .zip(coefficients)
+        .fold(F::ZERO, |acc, (&y_i, coefficient)| acc + y_i * coefficient))
+}
+
+/// 由 `generate_shares_indexed` 生成的 (编号, y) 份额恢复秘密
+///
+/// # Arguments
+///
+/// * `shares` - (编号, y) 份额列表
+///
+/// # Returns
+///
+/// * `Result<F, SecretSharingError>` - 恢复的秘密；份额为空时返回 `InsufficientShares`，
+///   存在编号 0 时返回 `ZeroShareIndex`，编号重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_secret_indexed<F: CoefficientField>(shares: &[(u32, F)]) -> Result<F, SecretSharingError> {
+    let points: Vec<(F, F)> = shares.iter().map(|&(i, y)| (F::from(u64::from(i)), y)).collect();
+    try_reconstruct_secret(&points)
+}
+
+/// 常数时间的拉格朗日插值恢复秘密，结果与 `reconstruct_secret` 完全相同
+///
+/// `reconstruct_secret` 在内层循环中按下标分支，这里用 `subtle` 的条件选择代替分支，
+/// 所有分母同样通过 Montgomery 技巧一次批量求逆，运行时间只取决于份额的个数，而与份额的值无关。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；x 坐标重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_secret_ct<F: CoefficientField>(shares: &[(F, F)]) -> Result<F, ReconstructError> {
+    let mut numerators = Vec::with_capacity(shares.len());
+    let mut denominators = Vec::with_capacity(shares.len());
+    for (i, &(x_i, _)) in shares.iter().enumerate() {
+        let mut numerator = F::ONE;
+        let mut denominator = F::ONE;
+        for (j, &(x_j, _)) in shares.iter().enumerate() {
+            // i == j 时乘以 1，不产生分支
+            let same = (i as u64).ct_eq(&(j as u64));
+            numerator *= F::conditional_select(&x_j, &F::ONE, same);
+            denominator *= F::conditional_select(&(x_j - x_i), &F::ONE, same);
+        }
+        numerators.push(numerator);
+        denominators.push(denominator);
+    }
+
+    let inverses = batch_invert(&denominators).ok_or(ReconstructError::DuplicateShareIndex)?;
+    Ok(shares
+        .iter()
+        .zip(numerators)
+        .zip(inverses)
+        .fold(F::ZERO, |acc, ((&(_, y_i), numerator), inverse)| acc + y_i * numerator * inverse))
+}
+
+// 使用 Montgomery 技巧批量求逆：一次求逆加 3(n-1) 次乘法。
+// 求逆本身是常数时间的，只有在某个值为 0 时整体返回 None。
+pub(crate) fn batch_invert<F: Field>(values: &[F]) -> Option<Vec<F>> {
+    // prefix[k] = values[0] * ... * values[k-1]
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut product = F::ONE;
+    for value in values {
+        prefix.push(product);
+        product *= value;
+    }
+
+    let inverse = product.invert();
+    let invertible = inverse.is_some();
+    // inverse 为 (values[0] * ... * values[k])^-1，从后往前逐个剥离
+    let mut inverse = inverse.unwrap_or(F::ZERO);
+    let mut inverses = vec![F::ZERO; values.len()];
+    for k in (0..values.len()).rev() {
+        inverses[k] = inverse * prefix[k];
+        inverse *= values[k];
+    }
+
+    if bool::from(invertible) {
+        Some(inverses)
+    } else {
+        None
+    }
+}
+
+/// 直接从网络缓冲区中的 64 字节记录恢复秘密，每条记录为 32 字节大端 x 坐标加 32 字节大端 y 坐标
+///
+/// # Arguments
+///
+/// * `records` - 份额记录列表
+/// * `t` - 恢复秘密所需的最小份额数，只使用前 t 条记录
+///
+/// # Returns
+///
+/// * `Result<Scalar, ReconstructError>` - 恢复的秘密；记录不足、编码不规范或 x 坐标重复时返回错误
+pub fn reconstruct_from_raw(records: &[[u8; 64]], t: usize) -> Result<Scalar, ReconstructError> {
+    if t == 0 || records.len() < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+
+    // 每条记录只解析一次，所有份额共用一次分配
+    let shares = records[..t]
+        .iter()
+        .map(|record| {
+            let x = scalar_from_bytes(&record[..32]);
+            let y = scalar_from_bytes(&record[32..]);
+            x.zip(y).ok_or(ReconstructError::NonCanonicalEncoding)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    interpolate_at(&shares, Scalar::ZERO)
+}
+
+/// 过滤掉已被吊销的份额后恢复秘密
+///
+/// `Scalar` 没有实现 `Hash`，因此吊销集合使用按 `Ord` 排序的 `BTreeSet`
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `revoked_xs` - 已被吊销的份额的 x 坐标
+/// * `t` - 恢复秘密所需的最小份额数
+///
+/// # Returns
+///
+/// * `Result<Scalar, ReconstructError>` - 恢复的秘密；剩余份额不足 t 个时返回 `InsufficientShares`
+pub fn reconstruct_excluding(
+    shares: &[(Scalar, Scalar)],
+    revoked_xs: &BTreeSet<Scalar>,
+    t: usize,
+) -> Result<Scalar, ReconstructError> {
+    let remaining: Vec<(Scalar, Scalar)> = shares
+        .iter()
+        .filter(|(x, _)| !revoked_xs.contains(x))
+        .take(t)
+        .copied()
+        .collect();
+
+    if t == 0 || remaining.len() < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    interpolate_at(&remaining, Scalar::ZERO)
+}
+
+/// 由份额一次性恢复多项式，并在多个目标点上求值
+///
+/// 相比逐点进行拉格朗日插值，只需插值一次，适合同时为多个新参与者签发份额
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `targets` - 需要求值的 x 坐标，不能为 0：x = 0 处的值就是秘密本身，不能作为份额签发
+///
+/// # Returns
+///
+/// * `Result<Vec<F>, ReconstructError>` - 多项式在每个目标点上的值；目标中有 0 时返回 `ZeroShareIndex`
+pub fn reconstruct_secret_at_many<F: CoefficientField>(
+    shares: &[(F, F)],
+    targets: &[F],
+) -> Result<Vec<F>, ReconstructError> {
+    if targets.iter().any(|x| bool::from(x.is_zero())) {
+        return Err(ReconstructError::ZeroShareIndex);
+    }
+    let poly = Polynomial::lagrange_interpolate(shares)?;
+    Ok(targets.iter().map(|&x| poly.evaluate(x)).collect())
+}
+
+/// 检查一组份额是否位于一个次数小于 t 的多项式上
+///
+/// 由全部份额插值出多项式并检查其实际次数。份额多于 t 个时，
+/// 即使没有承诺也能发现被篡改的份额：篡改后的点集一般只能由更高次的多项式穿过。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `t` - 声称的门限值
+///
+/// # Returns
+///
+/// * `bool` - 插值多项式的次数小于 t 时返回 true；份额为空、x 坐标重复或 t 为 0 时返回 false
+pub fn shares_consistent_with_threshold<F: CoefficientField>(shares: &[(F, F)], t: usize) -> bool {
+    if t == 0 {
+        return false;
+    }
+    match Polynomial::lagrange_interpolate(shares) {
+        Ok(poly) => poly.degree() < t,
+        Err(_) => false,
+    }
+}
+
+/// 检查声称来自同一分发者的一组份额是否都位于同一个 t-1 次多项式上
+///
+/// 由前 t 个份额插值出多项式，再检查其余每个份额的 y 是否等于多项式在其 x 处的值，
+/// 可以发现混入的或被篡改的份额。与 `shares_consistent_with_threshold` 相比只需插值 t 个点，
+/// 份额数量远大于 t 时更快。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `t` - 门限值
+///
+/// # Returns
+///
+/// * `bool` - 所有份额一致时返回 true；t 为 0、份额少于 t 个或 x 坐标重复时返回 false
+pub fn verify_share_set_consistency<F: CoefficientField>(shares: &[(F, F)], t: usize) -> bool {
+    if t == 0 || shares.len() < t {
+        return false;
+    }
+    let mut xs: Vec<F::Repr> = shares.iter().map(|(x, _)| x.to_repr()).collect();
+    xs.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+    if xs.windows(2).any(|w| w[0].as_ref() == w[1].as_ref()) {
+        return false;
+    }
+    let poly = match Polynomial::lagrange_interpolate(&shares[..t]) {
+        Ok(poly) => poly,
+        Err(_) => return false,
+    };
+    shares[t..].iter().all(|&(x, y)| poly.evaluate(x) == y)
+}
+
+/// 用多于 t 个的份额恢复秘密，并在返回前确认所有份额相互一致
+///
+/// 所有份额位于同一个 t-1 次多项式上等价于任意 t 个份额的子集都恢复出相同的秘密，
+/// 因此只需用 `verify_share_set_consistency` 检查一次，而不必枚举所有子集。
+/// 多出的份额提供冗余校验：混入任何一个被篡改的份额都会返回错误，而不是悄悄得到错误的秘密。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表，至少 t 个
+/// * `t` - 门限值
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；t 为 0 或份额少于 t 个时返回 `InsufficientShares`，
+///   存在 x 坐标为 0 的份额时返回 `ZeroShareIndex`，份额不一致或 x 坐标重复时返回 `InconsistentShares`
+pub fn reconstruct_secret_overdetermined<F: CoefficientField>(shares: &[(F, F)], t: usize) -> Result<F, ReconstructError> {
+    if t == 0 || shares.len() < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    if shares.iter().any(|(x, _)| bool::from(x.is_zero())) {
+        return Err(ReconstructError::ZeroShareIndex);
+    }
+    if !verify_share_set_consistency(shares, t) {
+        return Err(ReconstructError::InconsistentShares);
+    }
+    interpolate_at(&shares[..t], F::ZERO)
+}
+
+/// 从份额流中恰好取出 t 个不同的份额并恢复秘密，适合份额从多个来源陆续到达的场景
+///
+/// 逐个读取迭代器，跳过与已取出份额完全相同的重复份额，取够 t 个 x 坐标不同的份额后立即停止，
+/// 不会多读迭代器中剩余的份额。
+///
+/// # Arguments
+///
+/// * `shares` - 份额迭代器
+/// * `t` - 门限值
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；t 为 0 或迭代器在取够 t 个不同的份额前耗尽时返回 `InsufficientShares`，
+///   遇到 x 坐标为 0 的份额时返回 `ZeroShareIndex`，遇到 x 坐标相同但 y 不同的份额时返回 `DuplicateShareIndex`
+pub fn reconstruct_from_iter<F: CoefficientField>(
+    shares: impl Iterator<Item = (F, F)>,
+    t: usize,
+) -> Result<F, ReconstructError> {
+    if t == 0 {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    let mut collected: Vec<(F, F)> = Vec::with_capacity(t);
+    for (x, y) in shares {
+        if bool::from(x.is_zero()) {
+            return Err(ReconstructError::ZeroShareIndex);
+        }
+        match collected.iter().find(|(seen, _)| *seen == x) {
+            Some(&(_, seen_y)) if seen_y == y => continue,
+            Some(_) => return Err(ReconstructError::DuplicateShareIndex),
+            None => collected.push((x, y)),
+        }
+        if collected.len() == t {
+            return interpolate_at(&collected, F::ZERO);
+        }
+    }
+    Err(ReconstructError::InsufficientShares)
+}
+
+/// 把同一组 x 坐标上两个秘密的份额逐个相加，得到两个秘密之和的份额，用于加法式的 DKG 等场景
+///
+/// 两组份额必须一一对应：长度相同，且相同位置的份额 x 坐标相同。
+///
+/// # Arguments
+///
+/// * `a` - 第一个秘密的份额
+/// * `b` - 第二个秘密的份额，与 `a` 按位置对齐
+///
+/// # Returns
+///
+/// * `Result<Vec<(F, F)>, IndexMismatch>` - y 值为 a_i + b_i 的份额，恢复得到 secret_a + secret_b；
+///   长度不同或任一位置的 x 坐标不同时返回错误
+pub fn add_share_sets<F: CoefficientField>(a: &[(F, F)], b: &[(F, F)]) -> Result<Vec<(F, F)>, IndexMismatch> {
+    if a.len() != b.len() {
+        return Err(IndexMismatch);
+    }
+    a.iter()
+        .zip(b)
+        .map(|(&(xa, ya), &(xb, yb))| if xa == xb { Ok((xa, ya + yb)) } else { Err(IndexMismatch) })
+        .collect()
+}
+
+/// 把每个份额的 y 乘以公开常数，x 坐标不变，得到秘密乘以该常数的份额
+///
+/// 相当于把多项式整体乘以 `factor`，factor 非零时多项式的次数不变，恢复门限也不变。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `factor` - 公开常数
+///
+/// # Returns
+///
+/// * `Vec<(F, F)>` - 恢复得到 secret * factor 的份额
+pub fn scale_share_set<F: CoefficientField>(shares: &[(F, F)], factor: F) -> Vec<(F, F)> {
+    shares.iter().map(|&(x, y)| (x, y * factor)).collect()
+}
+
+/// 与 `reconstruct_secret` 相同，但由调用者给出门限值，份额不足 t 个时返回错误
+///
+/// 少于 t 个份额时拉格朗日插值会得到一个次数更低的多项式，`reconstruct_secret` 无从察觉，
+/// 只会悄悄返回一个错误的标量。本函数在插值前检查份额数量，避免这种误用。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `t` - 门限值
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；t 为 0 或份额少于 t 个时返回 `InsufficientShares`，
+///   存在 x 坐标为 0 的份额时返回 `ZeroShareIndex`，x 坐标重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_secret_checked<F: CoefficientField>(shares: &[(F, F)], t: usize) -> Result<F, ReconstructError> {
+    if t == 0 || shares.len() < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    if shares.iter().any(|(x, _)| bool::from(x.is_zero())) {
+        return Err(ReconstructError::ZeroShareIndex);
+    }
+    interpolate_at(shares, F::ZERO)
+}
+
+/// 与 `reconstruct_secret` 相同，但对不合法的份额返回错误而不是 panic
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+///
+/// # Returns
+///
+/// * `Result<F, SecretSharingError>` - 恢复的秘密；份额为空时返回 `InsufficientShares`，
+///   存在 x 坐标为 0 的份额时返回 `ZeroShareIndex`，x 坐标重复时返回 `DuplicateShareIndex`
+pub fn try_reconstruct_secret<F: CoefficientField>(shares: &[(F, F)]) -> Result<F, SecretSharingError> {
+    if shares.is_empty() {
+        return Err(SecretSharingError::InsufficientShares);
+    }
+    if shares.iter().any(|(x, _)| bool::from(x.is_zero())) {
+        return Err(SecretSharingError::ZeroShareIndex);
+    }
+    interpolate_at(shares, F::ZERO).map_err(|_| SecretSharingError::DuplicateShareIndex)
+}
+
+/// 取得份额的所有权，恢复秘密后立即清零每个份额的 y 值再释放，缩短份额在内存中停留的时间
+///
+/// 清零只是尽力而为：它只能覆盖传入的这个向量的缓冲区。标量是 `Copy` 的，
+/// 调用者在别处保留的副本、构造向量时留下的旧缓冲区，以及插值过程中寄存器和栈上的临时值都不在清零范围内，
+/// 返回的秘密本身也需要调用者自行保护（例如包装为 [`SecretScalar`](crate::secret::SecretScalar)）。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表，调用后不再可用
+///
+/// # Returns
+///
+/// * `Scalar` - 恢复的秘密
+///
+/// # Panics
+///
+/// 如果份额的 x 坐标有重复则 panic，panic 前同样会清零份额
+#[cfg(feature = "zeroize")]
+pub fn reconstruct_and_consume(shares: Vec<(Scalar, Scalar)>) -> Scalar {
+    // 用守卫在正常返回和 panic 展开时都清零
+    struct Wipe(Vec<(Scalar, Scalar)>);
+
+    impl Drop for Wipe {
+        fn drop(&mut self) {
+            for (_, y) in self.0.iter_mut() {
+                y.zeroize();
+            }
+        }
+    }
+
+    let shares = Wipe(shares);
+    interpolate_at(&shares.0, Scalar::ZERO).expect("share x-coordinates must be distinct")
+}
+
+// 计算 x_i 在 xs 上、x = 0 处的拉格朗日系数
+pub(crate) fn lagrange_at_zero(x_i: Scalar, xs: &[Scalar]) -> Scalar {
+    let mut numerator = Scalar::ONE;
+    let mut denominator = Scalar::ONE;
+    for &x_j in xs.iter().filter(|&&x_j| x_j != x_i) {
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    numerator * denominator.invert().unwrap()
+}
+
+/// 解析 32 字节大端编码的规范标量，编码不规范时返回 None
+pub(crate) fn scalar_from_bytes(bytes: &[u8]) -> Option<Scalar> {
+    let mut repr = FieldBytes::default();
+    repr.copy_from_slice(bytes);
+    Scalar::from_repr(repr).into()
+}
+
+/// 根据秘密比特以常数时间在两个份额之间进行选择，不产生分支
+///
+/// # Arguments
+///
+/// * `a` - choice 为 0 时返回的份额
+/// * `b` - choice 为 1 时返回的份额
+/// * `choice` - 秘密选择比特
+///
+/// # Returns
+///
+/// * `(F, F)` - 被选中的份额
+pub fn conditional_select_share<F: CoefficientField>(
+    a: &(F, F),
+    b: &(F, F),
+    choice: Choice,
+) -> (F, F) {
+    (
+        F::conditional_select(&a.0, &b.0, choice),
+        F::conditional_select(&a.1, &b.1, choice),
+    )
+}
+
+/// 分享一个群元素（例如公钥）而不是标量：构造系数为点的多项式，常数项为秘密点，其余系数为随机点
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密点
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<(G::Scalar, G)>, ShareError>` - 包含份额的列表，x 坐标为 1 到 n 的整数；
+///   n 为 0、t 为 0、t 大于 n 或 t 超过 `MAX_THRESHOLD` 时返回错误
+pub fn generate_point_shares<G: Group, R: RngCore + CryptoRng>(
+    secret: G,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<Vec<(G::Scalar, G)>, ShareError> {
+    let degree = polynomial_degree(t)?;
+    validate_share_count::<G::Scalar>(n, t)?;
+    let mut coefficients = vec![secret];
+    for _ in 0..degree {
+        coefficients.push(G::random(&mut *rng));
+    }
+
+    Ok((1..=n)
+        .map(|i| {
+            let x = G::Scalar::from(i as u64);
+            (x, evaluate_commitments(&coefficients, x))
+        })
+        .collect())
+}
+
+/// 使用作用在点上的拉格朗日系数恢复 `generate_point_shares` 分享的秘密点
+///
+/// # Arguments
+///
+/// * `shares` - 至少 t 个点份额
+///
+/// # Returns
+///
+/// * `Result<G, ReconstructError>` - 恢复的秘密点；份额为空时返回 `InsufficientShares`，
+///   x 坐标重复时返回 `DuplicateShareIndex`
+pub fn reconstruct_point_secret<G: Group>(shares: &[(G::Scalar, G)]) -> Result<G, ReconstructError> {
+    if shares.is_empty() {
+        return Err(ReconstructError::InsufficientShares);
+    }
+
+    let mut points = Vec::with_capacity(shares.len());
+    let mut numerators = Vec::with_capacity(shares.len());
+    let mut denominators = Vec::with_capacity(shares.len());
+    for (i, &(x_i, point)) in shares.iter().enumerate() {
+        let mut numerator = G::Scalar::ONE;
+        let mut denominator = G::Scalar::ONE;
         for (j, &(x_j, _)) in shares.iter().enumerate() {
-            // 如果是同一个份额，则跳过
             if i != j {
-                // 分子乘以 x_j
                 numerator *= x_j;
-                // 分母乘以 (x_j - x_i)
                 denominator *= x_j - x_i;
             }
         }
-        // 计算拉格朗日系数
-        let lagrange_coefficient = numerator * denominator.invert().unwrap();
-        // 将 y_i 乘以拉格朗日系数并累加到秘密中
-        secret += y_i * lagrange_coefficient;
+        points.push(point);
+        numerators.push(numerator);
+        denominators.push(denominator);
+    }
+    let inverses = batch_invert(&denominators).ok_or(ReconstructError::DuplicateShareIndex)?;
+    let coefficients: Vec<G::Scalar> = numerators.iter().zip(inverses).map(|(&n, inverse)| n * inverse).collect();
+    Ok(multi_scalar_mul(&points, &coefficients))
+}
+
+/// Shamir 分发的结果：群 G 的标量域上的份额列表
+pub type ShamirShares<G = ProjectivePoint> = Vec<(<G as Group>::Scalar, <G as Group>::Scalar)>;
+
+/// Feldman 分发的结果：份额列表和承诺列表
+pub type FeldmanShares<G = ProjectivePoint> = (Vec<(<G as Group>::Scalar, <G as Group>::Scalar)>, Vec<G>);
+
+/// 采用 Feldman 可验证秘密共享方案生成 n 个份额，至少需要 t 个份额才能恢复秘密，并返回份额和对应的承诺
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `g` - 生成元
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `FeldmanShares<G>` - 包含份额的列表和对应的 Feldman 承诺列表
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD` 则 panic
+pub fn generate_shares_with_feldman_vss<G: Group, R: RngCore + CryptoRng>(
+    secret: G::Scalar,
+    n: usize,
+    t: usize,
+    g: G,
+    rng: &mut R,
+) -> FeldmanShares<G>
+where
+    G::Scalar: CoefficientField,
+{
+    // 创建一个 t-1 次的随机多项式，其常数项为秘密值
+    let poly = Polynomial::new(secret, degree_or_panic(t), rng);
+    // 生成多项式系数的 Feldman 承诺
+    let commitments = poly.feldman_commit(g);
+
+    // 生成 n 个份额，每个份额是一个 (x, y) 对
+    let shares = (1..=n)
+        .map(|i| {
+            // x 坐标为 1 到 n 的整数
+            let x = G::Scalar::from(i as u64);
+            // y 坐标为多项式在 x 处的值
+            let y = poly.evaluate(x);
+            // 返回 (x, y) 对
+            (x, y)
+        })
+        .collect();
+
+    // 返回份额和对应的承诺
+    (shares, commitments)
+}
+
+/// 与 `generate_shares_with_feldman_vss` 相同，但会先检查参数，对不合法的门限值返回错误而不是 panic
+///
+/// # Returns
+///
+/// * `Result<FeldmanShares<G>, SecretSharingError>` - 份额和承诺；n 为 0、t 为 0 或 t 大于 n 时返回 `InvalidThreshold`
+pub fn try_generate_shares_with_feldman_vss<G: Group, R: RngCore + CryptoRng>(
+    secret: G::Scalar,
+    n: usize,
+    t: usize,
+    g: G,
+    rng: &mut R,
+) -> Result<FeldmanShares<G>, SecretSharingError>
+where
+    G::Scalar: CoefficientField,
+{
+    validate_threshold(n, t)?;
+    Ok(generate_shares_with_feldman_vss(secret, n, t, g, rng))
+}
+
+/// 分享私钥前先检查它与已知的公钥是否对应，防止操作失误分享了错误的私钥
+///
+/// 检查通过后与 `try_generate_shares_with_feldman_vss` 相同，承诺的常数项 C_0 就等于公钥。
+///
+/// # Arguments
+///
+/// * `sk` - 要分享的私钥
+/// * `pk` - 私钥对应的公钥
+/// * `n` - 份额的总数
+/// * `t` - 恢复私钥所需的最小份额数
+/// * `g` - 公钥的基点，同时用作承诺的生成元
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<FeldmanShares<G>, SecretSharingError>` - 份额和承诺；g * sk 不等于 pk 时返回 `PublicKeyMismatch`，
+///   门限值不合法时返回 `InvalidThreshold`
+pub fn split_private_key<G: Group, R: RngCore + CryptoRng>(
+    sk: G::Scalar,
+    pk: G,
+    n: usize,
+    t: usize,
+    g: G,
+    rng: &mut R,
+) -> Result<FeldmanShares<G>, SecretSharingError>
+where
+    G::Scalar: CoefficientField,
+{
+    if g * sk != pk {
+        return Err(SecretSharingError::PublicKeyMismatch);
     }
-    // 返回重建的秘密
-    secret
+    try_generate_shares_with_feldman_vss(sk, n, t, g, rng)
 }
 
-/// 采用 Feldman 可验证秘密共享方案生成 n 个份额，至少需要 t 个份额才能恢复秘密，并返回份额和对应的承诺
+/// 与 `generate_shares_with_feldman_vss` 相同，但使用预计算好的 `FeldmanCommitter` 生成承诺，
+/// 适合门限值较大或需要多次分发的场景
 ///
 /// # Arguments
 ///
 /// * `secret` - 要分享的秘密
 /// * `n` - 份额的总数
 /// * `t` - 恢复秘密所需的最小份额数
-/// * `g` - 生成元
+/// * `committer` - 为生成元 g 预计算的承诺计算器
 /// * `rng` - 随机数生成器
 ///
 /// # Returns
 ///
 /// * `(Vec<(Scalar, Scalar)>, Vec<ProjectivePoint>)` - 包含份额的列表和对应的 Feldman 承诺列表
-pub fn generate_shares_with_feldman_vss<R: Rng>(
+pub fn generate_shares_with_feldman_committer<R: RngCore + CryptoRng>(
     secret: Scalar,
     n: usize,
     t: usize,
-    g: ProjectivePoint,
+    committer: &FeldmanCommitter,
     rng: &mut R,
 ) -> (Vec<(Scalar, Scalar)>, Vec<ProjectivePoint>) {
-    // 创建一个 t-1 次的随机多项式，其常数项为秘密值
-    let poly = Polynomial::new(secret, t - 1, rng);
-    // 生成多项式系数的 Feldman 承诺
-    let commitments = poly.feldman_commit(g);
+    let poly = Polynomial::new(secret, degree_or_panic(t), rng);
+    let commitments = committer.commit(&poly);
 
-    // 生成 n 个份额，每个份额是一个 (x, y) 对
     let shares = (1..=n)
         .map(|i| {
-            // x 坐标为 1 到 n 的整数
             let x = Scalar::from(i as u64);
-            // y 坐标为多项式在 x 处的值
-            let y = poly.evaluate(x);
-            // 返回 (x, y) 对
-            (x, y)
+            (x, poly.evaluate(x))
         })
         .collect();
 
-    // 返回份额和对应的承诺
     (shares, commitments)
 }
 
+// 承诺数量达到该值时使用多标量乘法，否则使用霍纳法则
+const MSM_THRESHOLD: usize = 8;
+
+/// 计算承诺多项式在 x 处的值 C_0 + C_1 * x + C_2 * x^2 + ...
+///
+/// 承诺较少时使用霍纳法则，每个承诺需要一次完整的标量乘法；承诺较多时先计算 x 的各次幂，
+/// 再用一次多标量乘法 (Pippenger) 求和，t 个承诺约需 O(256 * t / log t) 次点加法，
+/// 承诺越多优势越明显。两种方法的结果完全相同。
+/// 实际耗时与平台有关，用 `cargo bench --bench msm` 测量。
+///
+/// # Arguments
+///
+/// * `commitments` - 承诺列表，按照次数从低到高排列
+/// * `x` - 自变量的值
+///
+/// # Returns
+///
+/// * `G` - 承诺多项式在 x 处的值
+pub fn evaluate_commitments<G: Group>(commitments: &[G], x: G::Scalar) -> G {
+    if commitments.len() < MSM_THRESHOLD {
+        return commitments
+            .iter()
+            .rev()
+            .fold(G::identity(), |acc, commitment| acc * x + commitment);
+    }
+
+    let mut powers = Vec::with_capacity(commitments.len());
+    let mut power = G::Scalar::ONE;
+    for _ in commitments {
+        powers.push(power);
+        power *= x;
+    }
+    multi_scalar_mul(commitments, &powers)
+}
+
+/// 在接受份额之前检查分发者公布的承诺向量是否与门限值 t 相符
+///
+/// 承诺的个数必须恰好为 t。最高次项的承诺为单位元意味着多项式的实际次数低于 t - 1，
+/// 更少的份额就能恢复秘密；常数项的承诺为单位元意味着秘密为 0。两者都会被拒绝。
+///
+/// # Arguments
+///
+/// * `commitments` - Feldman 承诺列表
+/// * `t` - 约定的门限值
+///
+/// # Returns
+///
+/// * `Result<(), CommitmentError>` - 承诺向量合法时返回 `Ok(())`
+pub fn verify_commitment_degree<G: Group>(commitments: &[G], t: usize) -> Result<(), CommitmentError> {
+    if commitments.len() != t || t == 0 {
+        return Err(CommitmentError::LengthMismatch {
+            expected: t,
+            actual: commitments.len(),
+        });
+    }
+    for index in [0, t - 1] {
+        if bool::from(commitments[index].is_identity()) {
+            return Err(CommitmentError::IdentityCommitment { index });
+        }
+    }
+    Ok(())
+}
+
+// 压缩 SEC1 点编码的长度
+const COMPRESSED_POINT_LEN: usize = 33;
+
+/// 将承诺列表编码为字节串：承诺数量 (u32 大端) || 每个承诺的 33 字节压缩 SEC1 编码
+///
+/// # Arguments
+///
+/// * `commitments` - 承诺列表
+///
+/// # Returns
+///
+/// * `Vec<u8>` - 编码后的字节串
+pub fn commitments_to_bytes(commitments: &[ProjectivePoint]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(4 + COMPRESSED_POINT_LEN * commitments.len());
+    bytes.extend_from_slice(&(commitments.len() as u32).to_be_bytes());
+    for commitment in commitments {
+        bytes.extend_from_slice(&commitment.to_bytes());
+    }
+    bytes
+}
+
+/// 从 `commitments_to_bytes` 的编码中解码承诺列表，拒绝截断的输入、多余的字节和不在曲线上的点
+///
+/// # Arguments
+///
+/// * `bytes` - 编码后的字节串
+///
+/// # Returns
+///
+/// * `Result<Vec<ProjectivePoint>, DecodeError>` - 承诺列表
+pub fn commitments_from_bytes(bytes: &[u8]) -> Result<Vec<ProjectivePoint>, DecodeError> {
+    if bytes.len() < 4 {
+        return Err(DecodeError::Truncated);
+    }
+    let mut count = [0u8; 4];
+    count.copy_from_slice(&bytes[..4]);
+    let count = u32::from_be_bytes(count) as usize;
+
+    let body = &bytes[4..];
+    let expected = count.checked_mul(COMPRESSED_POINT_LEN).ok_or(DecodeError::Truncated)?;
+    if body.len() < expected {
+        return Err(DecodeError::Truncated);
+    }
+    if body.len() > expected {
+        return Err(DecodeError::TrailingBytes);
+    }
+
+    body.chunks_exact(COMPRESSED_POINT_LEN)
+        .map(|chunk| {
+            // 只接受压缩编码（以及单位元），拒绝同样是 33 字节的紧凑编码
+            if ![0x00, 0x02, 0x03].contains(&chunk[0]) {
+                return Err(DecodeError::InvalidPoint);
+            }
+            let mut repr = <ProjectivePoint as GroupEncoding>::Repr::default();
+            repr.copy_from_slice(chunk);
+            Option::from(ProjectivePoint::from_bytes(&repr)).ok_or(DecodeError::InvalidPoint)
+        })
+        .collect()
+}
+
+/// 在不恢复秘密的情况下检查 Feldman 分发的秘密是否对应已知的公钥
+///
+/// 常数项的承诺 C_0 = g * secret。分享私钥时，若承诺使用的生成元 g 就是公钥的基点，
+/// 验证者只需比较 C_0 与公钥即可确认分发者分享的是预期的私钥。
+///
+/// # Arguments
+///
+/// * `commitments` - Feldman 承诺列表
+/// * `expected_pubkey` - 预期的公钥 g * secret
+/// * `g` - 生成承诺时使用的生成元，必须是公钥的基点
+///
+/// # Returns
+///
+/// * `bool` - 承诺列表非空、g 不是单位元且 C_0 等于公钥时返回 true
+pub fn verify_secret_commitment<G: Group>(commitments: &[G], expected_pubkey: G, g: G) -> bool {
+    if bool::from(g.is_identity()) {
+        return false;
+    }
+    commitments.first() == Some(&expected_pubkey)
+}
+
+/// 恢复秘密并用 Feldman 承诺检查结果，即使份额事先没有逐个验证也能保证端到端的完整性
+///
+/// 恢复后只需一次标量乘法比较 g * secret 与常数项的承诺 C_0，比逐个验证份额便宜得多；
+/// 检查失败说明至少有一个损坏的份额混入了恢复，但无法指出是哪一个。
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+/// * `commitments` - Feldman 承诺列表
+/// * `g` - 生成承诺时使用的生成元
+///
+/// # Returns
+///
+/// * `Result<G::Scalar, ReconstructError>` - 恢复的秘密；份额为空时返回 `InsufficientShares`，
+///   存在 x 坐标为 0 的份额时返回 `ZeroShareIndex`，x 坐标重复时返回 `DuplicateShareIndex`，
+///   承诺列表为空或 g * secret 不等于 C_0 时返回 `CommitmentMismatch`
+pub fn reconstruct_and_verify<G: Group>(
+    shares: &[(G::Scalar, G::Scalar)],
+    commitments: &[G],
+    g: G,
+) -> Result<G::Scalar, ReconstructError>
+where
+    G::Scalar: CoefficientField,
+{
+    if shares.is_empty() {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    if shares.iter().any(|(x, _)| bool::from(x.is_zero())) {
+        return Err(ReconstructError::ZeroShareIndex);
+    }
+    let secret = interpolate_at(shares, G::Scalar::ZERO)?;
+    if commitments.first() != Some(&(g * secret)) {
+        return Err(ReconstructError::CommitmentMismatch);
+    }
+    Ok(secret)
+}
+
 /// 使用 Feldman 承诺验证份额的有效性
 ///
+/// 本函数不检查承诺的个数：截断的承诺向量会被当作一个次数更低的多项式的承诺，
+/// 其结果只说明份额与这个多项式不符，而不能说明承诺本身有问题。验证者知道约定的门限值时，
+/// 应使用 [`try_verify_share_with_feldman_vss`] 或 [`FeldmanVerifier::with_threshold`]，
+/// 它们会以 `CommitmentLengthMismatch` / `LengthMismatch` 明确报告承诺个数不符。
+///
 /// # Arguments
 ///
 /// * `share` - 要验证的份额 (x, y)
@@ -98,10 +1411,10 @@ pub fn generate_shares_with_feldman_vss<R: Rng>(
 /// # Returns
 ///
 /// * `bool` - 如果份额有效，则返回 true；否则返回 false
-pub fn verify_share_with_feldman_vss(
-    share: (Scalar, Scalar),
-    commitments: &[ProjectivePoint],
-    g: ProjectivePoint,
+pub fn verify_share_with_feldman_vss<G: Group>(
+    share: (G::Scalar, G::Scalar),
+    commitments: &[G],
+    g: G,
 ) -> bool {
     let (x, y) = share;
 
@@ -109,16 +1422,345 @@ pub fn verify_share_with_feldman_vss(
     let g_to_y = g * y;
 
     // 计算承诺的累加值 C_0 + C_1 * x + C_2 * x^2 + ...
-    let mut commitment_at_x = ProjectivePoint::IDENTITY;
-    for (i, commitment) in commitments.iter().enumerate() {
-        commitment_at_x += *commitment * x.pow(&[i as u64, 0, 0, 0]);
-    }
+    let commitment_at_x = evaluate_commitments(commitments, x);
 
     // 验证 g^y 是否等于承诺的累加值
     g_to_y == commitment_at_x
 }
 
-/// 采用 Pedersen 可验证秘密共享方案生成 n 个份额，至少需要 t 个份额才能恢复秘密，并返回份额、对应的承诺以及致盲多项式
+/// 与 `verify_share_with_feldman_vss` 相同，但会先检查份额和承诺向量是否合法
+///
+/// x 坐标为 0 的份额就是秘密本身，会被拒绝；承诺个数与门限值不符时，
+/// 份额会针对一个次数不同的多项式进行验证，同样会被拒绝。
+///
+/// # Arguments
+///
+/// * `share` - 要验证的份额 (x, y)
+/// * `commitments` - Feldman 承诺列表
+/// * `g` - 生成元
+/// * `t` - 约定的门限值
+///
+/// # Returns
+///
+/// * `Result<bool, SecretSharingError>` - 份额是否有效；x 坐标为 0 时返回 `ZeroShareIndex`，
+///   承诺个数不等于 t 时返回 `CommitmentLengthMismatch`
+pub fn try_verify_share_with_feldman_vss<G: Group>(
+    share: (G::Scalar, G::Scalar),
+    commitments: &[G],
+    g: G,
+    t: usize,
+) -> Result<bool, SecretSharingError> {
+    if commitments.len() != t {
+        return Err(SecretSharingError::CommitmentLengthMismatch {
+            expected: t,
+            actual: commitments.len(),
+        });
+    }
+    if bool::from(share.0.is_zero()) {
+        return Err(SecretSharingError::ZeroShareIndex);
+    }
+    Ok(verify_share_with_feldman_vss(share, commitments, g))
+}
+
+/// 针对同一组 Feldman 承诺反复验证份额的验证器，结果与 `verify_share_with_feldman_vss` 完全相同
+///
+/// 构造时预计算 g 的窗口表（见 [`FeldmanCommitter`]），并把承诺转换为仿射坐标以使用混合加法。
+/// 验证时 g * y 只需查表，承诺多项式用霍纳法则求值：x 不超过 64 比特时（例如 x = 1 到 n），
+/// 每一步的 acc * x 按 x 的比特做倍点加法，而不是完整的 256 比特标量乘法。
+/// 构造的一次性开销与 [`FeldmanCommitter::new`] 相同；之后每次验证约需 64 次点加法加上
+/// t 步霍纳法则，每步的倍点次数等于 x 的比特数；`verify_share_with_feldman_vss` 每次都需要
+/// 一次完整的标量乘法计算 g * y，再用 t 次标量乘法或一次多标量乘法对承诺求值。
+/// 适合用同一组承诺验证成百上千个份额的场景。
+/// 实际耗时与平台有关，用 `cargo bench --bench feldman_verifier` 测量。
+pub struct FeldmanVerifier {
+    g_table: FeldmanCommitter,
+    commitments: Vec<ProjectivePoint>,
+    affine_commitments: Vec<AffinePoint>,
+}
+
+impl FeldmanVerifier {
+    /// 为一组承诺和生成元构建验证器
+    ///
+    /// # Arguments
+    ///
+    /// * `commitments` - Feldman 承诺列表
+    /// * `g` - 生成元
+    pub fn new(commitments: &[ProjectivePoint], g: ProjectivePoint) -> Self {
+        FeldmanVerifier {
+            g_table: FeldmanCommitter::new(g),
+            commitments: commitments.to_vec(),
+            affine_commitments: commitments.iter().map(|commitment| commitment.to_affine()).collect(),
+        }
+    }
+
+    /// 与 `new` 相同，但先用 [`verify_commitment_degree`] 检查承诺向量与约定的门限值相符
+    ///
+    /// # Arguments
+    ///
+    /// * `commitments` - Feldman 承诺列表
+    /// * `g` - 生成元
+    /// * `t` - 约定的门限值
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeldmanVerifier, CommitmentError>` - 验证器；承诺个数不等于 t 时返回 `LengthMismatch`，
+    ///   常数项或最高次项的承诺为单位元时返回 `IdentityCommitment`
+    pub fn with_threshold(commitments: &[ProjectivePoint], g: ProjectivePoint, t: usize) -> Result<Self, CommitmentError> {
+        verify_commitment_degree(commitments, t)?;
+        Ok(Self::new(commitments, g))
+    }
+
+    /// 验证份额的有效性
+    ///
+    /// # Arguments
+    ///
+    /// * `share` - 要验证的份额 (x, y)
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - 如果份额有效，则返回 true；否则返回 false
+    pub fn verify(&self, share: (Scalar, Scalar)) -> bool {
+        let (x, y) = share;
+        self.g_table.mul(&y) == self.evaluate(x)
+    }
+
+    // 计算承诺多项式在 x 处的值；x 和承诺都是公开的，不需要常数时间
+    fn evaluate(&self, x: Scalar) -> ProjectivePoint {
+        let bytes = x.to_bytes();
+        if bytes[..24].iter().any(|&b| b != 0) {
+            return evaluate_commitments(&self.commitments, x);
+        }
+        let small = u64::from_be_bytes(bytes[24..].try_into().expect("8 bytes"));
+        self.affine_commitments.iter().rev().fold(ProjectivePoint::IDENTITY, |acc, commitment| {
+            // acc * x，从最高位开始倍点加法
+            let mut product = ProjectivePoint::IDENTITY;
+            for bit in (0..64 - small.leading_zeros()).rev() {
+                product = product.double();
+                if (small >> bit) & 1 == 1 {
+                    product += acc;
+                }
+            }
+            product + commitment
+        })
+    }
+}
+
+/// 使用 Feldman 承诺批量验证一组份额的有效性
+///
+/// 先用随机线性组合一次检查全部份额：g * Σ r_i y_i 是否等于 Σ_j C_j * (Σ_i r_i x_i^j)。
+/// 各份额共享同一次 t + 1 项的多标量乘法，每个份额只需 t 次域乘法累加 r_i x_i^j，
+/// 而逐个验证每个份额都需要一次 g * y 和一次承诺求值。全部份额有效时直接返回；
+/// 检查不通过时再逐个验证，找出无效的份额。
+/// 权重 r_i 为 128 比特，由 SM3 对生成元、全部承诺和全部份额求摘要后派生，存在无效份额时
+/// 批量检查仍通过的概率约为 2^-128。
+///
+/// # Arguments
+///
+/// * `shares` - 要验证的份额列表
+/// * `commitments` - Feldman 承诺列表
+/// * `g` - 生成元
+///
+/// # Returns
+///
+/// * `Vec<bool>` - 与份额一一对应的验证结果
+///
+/// 启用 `rayon` 特性时，批量检查不通过后的逐个验证并行执行，结果顺序不变。
+pub fn verify_shares_with_feldman_vss<G: Group + GroupEncoding>(
+    shares: &[(G::Scalar, G::Scalar)],
+    commitments: &[G],
+    g: G,
+) -> Vec<bool> {
+    if feldman_batch_check(shares, commitments, g) {
+        return vec![true; shares.len()];
+    }
+
+    #[cfg(feature = "rayon")]
+    let iter = shares.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let iter = shares.iter();
+    iter.map(|&(x, y)| g * y == evaluate_commitments(commitments, x))
+        .collect()
+}
+
+// 批量验证中派生权重的域分隔字符串
+const FELDMAN_BATCH_DOMAIN: &[u8] = b"shamir_secret_sharing/feldman_batch";
+
+// 检查 g * Σ r_i y_i - Σ_j C_j * (Σ_i r_i x_i^j) 是否为单位元
+fn feldman_batch_check<G: Group + GroupEncoding>(
+    shares: &[(G::Scalar, G::Scalar)],
+    commitments: &[G],
+    g: G,
+) -> bool {
+    // 权重绑定全部公开输入和份额，分发者无法在选定承诺和份额之前预知
+    let mut hasher = Sm3::new();
+    hasher.update(FELDMAN_BATCH_DOMAIN);
+    hasher.update(g.to_bytes());
+    hasher.update((commitments.len() as u64).to_be_bytes());
+    for commitment in commitments {
+        hasher.update(commitment.to_bytes());
+    }
+    hasher.update((shares.len() as u64).to_be_bytes());
+    for (x, y) in shares {
+        hasher.update(x.to_repr());
+        hasher.update(y.to_repr());
+    }
+    let seed = hasher.finalize();
+
+    let mut weighted_y = G::Scalar::ZERO;
+    let mut scalars = vec![G::Scalar::ZERO; commitments.len()];
+    for (i, &(x, y)) in shares.iter().enumerate() {
+        let digest = Sm3::new()
+            .chain_update(seed)
+            .chain_update((i as u64).to_be_bytes())
+            .finalize();
+        let r = G::Scalar::from_u128(u128::from_be_bytes(
+            digest[..16].try_into().expect("16 bytes"),
+        ));
+        weighted_y += r * y;
+        // 累加 r_i * x_i^j
+        let mut term = r;
+        for scalar in scalars.iter_mut() {
+            *scalar += term;
+            term *= x;
+        }
+    }
+
+    let mut points = commitments.to_vec();
+    points.push(g);
+    scalars.push(-weighted_y);
+    bool::from(multi_scalar_mul(&points, &scalars).is_identity())
+}
+
+/// 使用 Feldman 承诺验证全部份额，并返回无效份额的下标
+///
+/// # Arguments
+///
+/// * `shares` - 要验证的份额列表
+/// * `commitments` - Feldman 承诺列表
+/// * `g` - 生成元
+///
+/// # Returns
+///
+/// * `(bool, Vec<usize>)` - 全部份额是否有效，以及无效份额在 `shares` 中的下标
+pub fn verify_all_with_feldman_vss<G: Group + GroupEncoding>(
+    shares: &[(G::Scalar, G::Scalar)],
+    commitments: &[G],
+    g: G,
+) -> (bool, Vec<usize>) {
+    let invalid: Vec<usize> = verify_shares_with_feldman_vss(shares, commitments, g)
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, valid)| !valid)
+        .map(|(i, _)| i)
+        .collect();
+    (invalid.is_empty(), invalid)
+}
+
+/// 先用 Feldman 承诺过滤掉无效份额，再用剩余的有效份额恢复秘密
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表，其中可能包含被篡改的份额
+/// * `commitments` - Feldman 承诺列表
+/// * `g` - 生成元
+/// * `t` - 恢复秘密所需的最小份额数
+///
+/// # Returns
+///
+/// * `Result<(G::Scalar, Vec<usize>), ReconstructError>` - 恢复的秘密以及被丢弃的份额在 `shares` 中的下标；
+///   有效份额不足 t 个时返回 `InsufficientShares`
+pub fn reconstruct_secret_robust<G: Group + GroupEncoding>(
+    shares: &[(G::Scalar, G::Scalar)],
+    commitments: &[G],
+    g: G,
+    t: usize,
+) -> Result<(G::Scalar, Vec<usize>), ReconstructError>
+where
+    G::Scalar: CoefficientField,
+{
+    let (_, discarded) = verify_all_with_feldman_vss(shares, commitments, g);
+    let valid: Vec<(G::Scalar, G::Scalar)> = shares
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !discarded.contains(i))
+        .map(|(_, &share)| share)
+        .take(t)
+        .collect();
+
+    if t == 0 || valid.len() < t {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    Ok((interpolate_at(&valid, G::Scalar::ZERO)?, discarded))
+}
+
+/// Pedersen 分发的结果：份额列表、承诺列表以及与份额一一对应的盲化值 (x, r(x))
+pub type PedersenShares<G = ProjectivePoint> = (
+    Vec<(<G as Group>::Scalar, <G as Group>::Scalar)>,
+    Vec<G>,
+    Vec<(<G as Group>::Scalar, <G as Group>::Scalar)>,
+);
+
+/// 由域分隔字符串派生一个与 SM2 基点相互独立的生成元，可用作 Pedersen VSS 的 h
+///
+/// Pedersen 承诺 g * a + h * b 的绑定性要求没有人知道 log_g(h)。用 `g * random_scalar` 构造 h 时，
+/// 选择随机数的人知道这个离散对数，可以把同一个承诺打开成不同的值，从而在验证通过的前提下分发不一致的份额。
+///
+/// 本函数以 `shamir_secret_sharing/independent_generator` 为域分隔字符串、以 `domain_separator` 为消息调用
+/// [`hash_to_curve`](crate::hash::hash_to_curve)。点由公开的哈希值决定，没有人能选择它，
+/// 因此也没有人知道它相对于基点或其他派生生成元的离散对数。不同用途应使用不同的域分隔字符串。
+///
+/// # Arguments
+///
+/// * `domain_separator` - 域分隔字符串
+///
+/// # Returns
+///
+/// * `ProjectivePoint` - 派生出的生成元，相同的域分隔字符串总是得到相同的点
+pub fn independent_generator(domain_separator: &[u8]) -> ProjectivePoint {
+    hash_to_curve(INDEPENDENT_GENERATOR_DOMAIN, domain_separator)
+}
+
+// independent_generator 传给 hash_to_curve 的域分隔字符串，调用者给出的字符串作为消息
+const INDEPENDENT_GENERATOR_DOMAIN: &[u8] = b"shamir_secret_sharing/independent_generator";
+
+// 默认 Pedersen 生成元 h 的域分隔字符串
+const DEFAULT_PEDERSEN_H_DOMAIN: &[u8] = b"shamir_secret_sharing/pedersen/h";
+
+/// 默认的 Pedersen 生成元对：g 为 SM2 基点，h 为 `independent_generator(b"shamir_secret_sharing/pedersen/h")`
+///
+/// h 由固定的域分隔字符串哈希到曲线上得到，没有人知道 log_g(h)，一般用户无需自行选择生成元。
+///
+/// # Returns
+///
+/// * `(ProjectivePoint, ProjectivePoint)` - (g, h)，每次调用都得到相同的点
+pub fn default_pedersen_generators() -> (ProjectivePoint, ProjectivePoint) {
+    (ProjectivePoint::GENERATOR, independent_generator(DEFAULT_PEDERSEN_H_DOMAIN))
+}
+
+/// 使用 [`default_pedersen_generators`] 生成 Pedersen 份额、承诺和盲化值，见 [`generate_shares_with_pedersen_vss`]
+///
+/// 验证份额时同样使用 `default_pedersen_generators()` 返回的 g 和 h。
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD` 则 panic
+pub fn split_pedersen_default<R: RngCore + CryptoRng>(
+    secret: Scalar,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> PedersenShares<ProjectivePoint> {
+    let (g, h) = default_pedersen_generators();
+    generate_shares_with_pedersen_vss(secret, n, t, g, h, rng)
+}
+
+/// 采用 Pedersen 可验证秘密共享方案生成 n 个份额，至少需要 t 个份额才能恢复秘密，并返回份额、对应的承诺以及每个份额的盲化值
+///
+/// 盲化多项式本身不会返回：第 i 个参与者只应得到自己的盲化值 r_i = r(x_i)，
+/// 公开盲化多项式会破坏 Pedersen 承诺的隐藏性。
+///
+/// h 必须与 g 相互独立：若分发者知道 log_g(h)，就能把同一个承诺打开成不同的份额。
+/// 不要使用 `g * random_scalar` 作为 h，而应使用 [`independent_generator`]，或直接使用 [`split_pedersen_default`]。
 ///
 /// # Arguments
 ///
@@ -131,34 +1773,57 @@ pub fn verify_share_with_feldman_vss(
 ///
 /// # Returns
 ///
-/// * `(Vec<(Scalar, Scalar)>, Vec<ProjectivePoint>, Polynomial)` - 包含份额的列表、对应的 Pedersen 承诺列表以及盲化多项式
-pub fn generate_shares_with_pedersen_vss<R: Rng>(
-    secret: Scalar,
+/// * `PedersenShares<G>` - 包含份额的列表、对应的 Pedersen 承诺列表以及与份额一一对应的盲化值 (x, r(x))
+///
+/// # Panics
+///
+/// 如果 t 为 0 或大于 `MAX_THRESHOLD` 则 panic
+pub fn generate_shares_with_pedersen_vss<G: Group, R: RngCore + CryptoRng>(
+    secret: G::Scalar,
     n: usize,
     t: usize,
-    g: ProjectivePoint,
-    h: ProjectivePoint,
+    g: G,
+    h: G,
     rng: &mut R,
-) -> (Vec<(Scalar, Scalar)>, Vec<ProjectivePoint>, Polynomial) {
+) -> PedersenShares<G>
+where
+    G::Scalar: CoefficientField,
+{
     // 创建一个 t-1 次的随机多项式，其常数项为秘密值
-    let poly = Polynomial::new(secret, t - 1, rng);
+    let poly = Polynomial::new(secret, degree_or_panic(t), rng);
     // 生成多项式系数的 Pedersen 承诺和盲化多项式
     let (commitments, blinding_poly) = poly.pedersen_commit(g, h, rng);
 
-    // 生成 n 个份额，每个份额是一个 (x, y) 对
-    let shares = (1..=n)
+    // 生成 n 个份额及其盲化值，x 坐标为 1 到 n 的整数
+    let (shares, blinding_values) = (1..=n)
         .map(|i| {
-            // x 坐标为 1 到 n 的整数
-            let x = Scalar::from(i as u64);
-            // y 坐标为多项式在 x 处的值
-            let y = poly.evaluate(x);
-            // 返回 (x, y) 对
-            (x, y)
+            let x = G::Scalar::from(i as u64);
+            ((x, poly.evaluate(x)), (x, blinding_poly.evaluate(x)))
         })
-        .collect();
+        .unzip();
 
-    // 返回份额、对应的承诺以及盲化多项式
-    (shares, commitments, blinding_poly)
+    // 返回份额、对应的承诺以及盲化值
+    (shares, commitments, blinding_values)
+}
+
+/// 与 `generate_shares_with_pedersen_vss` 相同，但会先检查参数，对不合法的门限值返回错误而不是 panic
+///
+/// # Returns
+///
+/// * `Result<PedersenShares<G>, SecretSharingError>` - 份额、承诺和盲化值；n 为 0、t 为 0 或 t 大于 n 时返回 `InvalidThreshold`
+pub fn try_generate_shares_with_pedersen_vss<G: Group, R: RngCore + CryptoRng>(
+    secret: G::Scalar,
+    n: usize,
+    t: usize,
+    g: G,
+    h: G,
+    rng: &mut R,
+) -> Result<PedersenShares<G>, SecretSharingError>
+where
+    G::Scalar: CoefficientField,
+{
+    validate_threshold(n, t)?;
+    Ok(generate_shares_with_pedersen_vss(secret, n, t, g, h, rng))
 }
 
 /// 使用 Pedersen 承诺验证份额的有效性
@@ -166,35 +1831,121 @@ pub fn generate_shares_with_pedersen_vss<R: Rng>(
 /// # Arguments
 ///
 /// * `share` - 要验证的份额 (x, y)
+/// * `blinding_value` - 该份额的盲化值 r(x)
 /// * `commitments` - Pedersen 承诺列表
-/// * `blinding_poly` - 盲化多项式
 /// * `g` - 生成元 g
 /// * `h` - 生成元 h
 ///
 /// # Returns
 ///
 /// * `bool` - 如果份额有效，则返回 true；否则返回 false
-pub fn verify_share_with_pedersen_vss(
-    share: (Scalar, Scalar),
-    commitments: &[ProjectivePoint],
-    blinding_poly: &Polynomial,
-    g: ProjectivePoint,
-    h: ProjectivePoint,
+pub fn verify_share_with_pedersen_vss<G: Group>(
+    share: (G::Scalar, G::Scalar),
+    blinding_value: G::Scalar,
+    commitments: &[G],
+    g: G,
+    h: G,
 ) -> bool {
     let (x, y) = share;
 
-    // 计算 g^y
-    let g_to_y = g * y;
+    // 计算承诺的累加值 C_0 + C_1 * x + C_2 * x^2 + ...
+    let commitment_at_x = evaluate_commitments(commitments, x);
 
-    // 计算 h^(blinding_poly(x))
-    let h_to_blinding_at_x = h * blinding_poly.evaluate(x);
+    // 验证 g^y * h^r(x) 是否等于承诺的累加值
+    g * y + h * blinding_value == commitment_at_x
+}
 
-    // 计算承诺的累加值 C_0 + C_1 * x + C_2 * x^2 + ...
-    let mut commitment_at_x = ProjectivePoint::IDENTITY;
-    for (i, commitment) in commitments.iter().enumerate() {
-        commitment_at_x += *commitment * x.pow(&[i as u64, 0, 0, 0]);
+/// 验证 Pedersen 份额及其盲化值，结果与 `verify_share_with_pedersen_vss` 相同
+///
+/// 承诺的累加值始终用霍纳法则计算，每个承诺只需一次标量乘法和一次加法；最后把 g * y、h * r
+/// 和承诺的累加值合成一个点，只做一次单位元判断，而不是分别计算两边再比较。
+/// 不保存也不依赖任何多项式状态，每次调用只使用传入的份额、盲化值和承诺。
+///
+/// # Arguments
+///
+/// * `share` - 要验证的份额 (x, y)
+/// * `blinding_value` - 分发者发给该份额持有者的盲化值 r(x)
+/// * `commitments` - Pedersen 承诺列表
+/// * `g` - 生成元 g
+/// * `h` - 生成元 h
+///
+/// # Returns
+///
+/// * `bool` - g * y + h * r(x) - Σ C_j * x^j 为单位元时返回 true
+pub fn verify_share_pedersen_precomputed<G: Group>(
+    share: (G::Scalar, G::Scalar),
+    blinding_value: G::Scalar,
+    commitments: &[G],
+    g: G,
+    h: G,
+) -> bool {
+    let (x, y) = share;
+    let commitment_sum = commitments
+        .iter()
+        .rev()
+        .fold(G::identity(), |acc, commitment| acc * x + commitment);
+    bool::from((g * y + h * blinding_value - commitment_sum).is_identity())
+}
+
+/// 以椭圆曲线群 G 为参数的秘密共享方案
+///
+/// 本模块中的 Shamir、Feldman 和 Pedersen 函数对标量域和群都是泛型的，
+/// 这里把它们按曲线收拢在一起，便于在 SM2 之外的曲线（例如 P-256、secp256k1）上使用同一套代码。
+pub struct SecretSharing<G: Group>(PhantomData<G>);
+
+/// SM2 曲线上的秘密共享方案，与本模块的具体 API 一致
+pub type Sm2SecretSharing = SecretSharing<ProjectivePoint>;
+
+impl<G: Group> SecretSharing<G>
+where
+    G::Scalar: CoefficientField,
+{
+    /// 见 [`generate_shares`]
+    pub fn generate_shares<R: RngCore + CryptoRng>(secret: G::Scalar, n: usize, t: usize, rng: &mut R) -> Vec<(G::Scalar, G::Scalar)> {
+        generate_shares(secret, n, t, rng)
+    }
+
+    /// 见 [`reconstruct_secret`]
+    pub fn reconstruct_secret(shares: &[(G::Scalar, G::Scalar)]) -> G::Scalar {
+        reconstruct_secret(shares)
+    }
+
+    /// 见 [`generate_shares_with_feldman_vss`]
+    pub fn generate_shares_with_feldman_vss<R: RngCore + CryptoRng>(
+        secret: G::Scalar,
+        n: usize,
+        t: usize,
+        g: G,
+        rng: &mut R,
+    ) -> FeldmanShares<G> {
+        generate_shares_with_feldman_vss(secret, n, t, g, rng)
+    }
+
+    /// 见 [`verify_share_with_feldman_vss`]
+    pub fn verify_share_with_feldman_vss(share: (G::Scalar, G::Scalar), commitments: &[G], g: G) -> bool {
+        verify_share_with_feldman_vss(share, commitments, g)
+    }
+
+    /// 见 [`generate_shares_with_pedersen_vss`]
+    pub fn generate_shares_with_pedersen_vss<R: RngCore + CryptoRng>(
+        secret: G::Scalar,
+        n: usize,
+        t: usize,
+        g: G,
+        h: G,
+        rng: &mut R,
+    ) -> PedersenShares<G> {
+        generate_shares_with_pedersen_vss(secret, n, t, g, h, rng)
     }
 
-    // 验证 g^y * h^(blinding_poly(x)) 是否等于承诺的累加值
-    g_to_y + h_to_blinding_at_x == commitment_at_x
+    /// 见 [`verify_share_with_pedersen_vss`]
+    pub fn verify_share_with_pedersen_vss(
+        share: (G::Scalar, G::Scalar),
+        blinding_value: G::Scalar,
+        commitments: &[G],
+        g: G,
+        h: G,
+    ) -> bool {
+        verify_share_with_pedersen_vss(share, blinding_value, commitments, g, h)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+use sm2::dsa::signature::Verifier;
+use sm2::dsa::{Signature, VerifyingKey};
+use sm2::Scalar;
+use crate::error::ShareError;
+use crate::secret_sharing::{reconstruct_secret, validate_threshold};
+
+/// 提交份额时可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitError {
+    /// 份额的 x 坐标不属于任何已登记的参与者
+    UnknownParticipant,
+    /// 提交的 nonce 与本次会话的挑战值不一致（例如重放的旧提交）
+    StaleNonce,
+    /// 参与者签名验证失败
+    InvalidSignature,
+    /// 该参与者在本次会话中已经提交过份额
+    DuplicateSubmission,
+}
+
+impl core::fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SubmitError::UnknownParticipant => write!(f, "unknown participant"),
+            SubmitError::StaleNonce => write!(f, "nonce does not match the session challenge"),
+            SubmitError::InvalidSignature => write!(f, "invalid participant signature"),
+            SubmitError::DuplicateSubmission => write!(f, "participant already submitted a share"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SubmitError {}
+
+/// 构造参与者需要签名的消息：nonce || x || y
+///
+/// # Arguments
+///
+/// * `nonce` - 本次会话的挑战值
+/// * `x` - 份额的 x 坐标
+/// * `y` - 份额的 y 坐标
+///
+/// # Returns
+///
+/// * `Vec<u8>` - 待签名的字节串
+pub fn session_message(nonce: &[u8; 32], x: Scalar, y: Scalar) -> Vec<u8> {
+    let mut message = Vec::with_capacity(96);
+    message.extend_from_slice(nonce);
+    message.extend_from_slice(&x.to_bytes());
+    message.extend_from_slice(&y.to_bytes());
+    message
+}
+
+/// 交互式恢复会话：每个参与者对本次会话的挑战值签名后提交份额，
+/// 只有 nonce 新鲜且签名有效的份额才会被接受，从而防止旧份额被重放
+pub struct SessionReconstructor {
+    // 本次会话的挑战值
+    challenge: [u8; 32],
+    // 恢复秘密所需的最小份额数
+    t: usize,
+    // 参与者的 x 坐标及其 SM2 验签公钥
+    participant_keys: Vec<(Scalar, VerifyingKey)>,
+    // 已接受的份额
+    submissions: Vec<(Scalar, Scalar)>,
+}
+
+impl SessionReconstructor {
+    /// 创建一个新的恢复会话
+    ///
+    /// # Arguments
+    ///
+    /// * `challenge` - 本次会话的新鲜挑战值
+    /// * `t` - 恢复秘密所需的最小份额数
+    /// * `participant_keys` - 参与者的 x 坐标及其 SM2 验签公钥
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, ShareError>` - 新的会话；没有参与者、t 为 0、t 大于参与者人数或超过 `MAX_THRESHOLD` 时返回错误
+    pub fn new(
+        challenge: [u8; 32],
+        t: usize,
+        participant_keys: Vec<(Scalar, VerifyingKey)>,
+    ) -> Result<Self, ShareError> {
+        // t = 0 时 reconstruct 在收到任何份额之前就会返回 0
+        validate_threshold(participant_keys.len(), t)?;
+        Ok(SessionReconstructor {
+            challenge,
+            t,
+            participant_keys,
+            submissions: Vec::new(),
+        })
+    }
+
+    /// 提交一个份额
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 份额的 x 坐标
+    /// * `y` - 份额的 y 坐标
+    /// * `nonce` - 参与者签名时使用的挑战值，必须等于本次会话的挑战值
+    /// * `sig` - 参与者对 `session_message(nonce, x, y)` 的 SM2 签名
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), SubmitError>` - 份额被接受时返回 Ok
+    pub fn submit(
+        &mut self,
+        x: Scalar,
+        y: Scalar,
+        nonce: [u8; 32],
+        sig: &Signature,
+    ) -> Result<(), SubmitError> {
+        // 查找该 x 坐标对应的参与者公钥
+        let key = self
+            .participant_keys
+            .iter()
+            .find(|(px, _)| *px == x)
+            .map(|(_, key)| key)
+            .ok_or(SubmitError::UnknownParticipant)?;
+
+        // 拒绝不属于本次会话的 nonce
+        if nonce != self.challenge {
+            return Err(SubmitError::StaleNonce);
+        }
+
+        // 验证签名同时覆盖 nonce 和份额
+        key.verify(&session_message(&nonce, x, y), sig)
+            .map_err(|_| SubmitError::InvalidSignature)?;
+
+        if self.submissions.iter().any(|(sx, _)| *sx == x) {
+            return Err(SubmitError::DuplicateSubmission);
+        }
+
+        self.submissions.push((x, y));
+        Ok(())
+    }
+
+    /// 返回已接受的份额数量
+    pub fn accepted(&self) -> usize {
+        self.submissions.len()
+    }
+
+    /// 在收到至少 t 个有效份额后恢复秘密
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Scalar>` - 份额不足 t 个时返回 None
+    pub fn reconstruct(&self) -> Option<Scalar> {
+        if self.submissions.len() < self.t {
+            return None;
+        }
+        Some(reconstruct_secret(&self.submissions[..self.t]))
+    }
+}
@@ -0,0 +1,301 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+use sm2::elliptic_curve::sec1::ToEncodedPoint;
+use sm2::{ProjectivePoint, Scalar};
+use sm3::{Digest, Sm3};
+use rand::{CryptoRng, RngCore};
+use crate::error::{DecodeError, IndexMismatch};
+use crate::secret::{fmt_field, fmt_index, Revealed};
+use crate::secret_sharing::{self, scalar_from_bytes};
+
+// 份额集合编码的魔数和版本号
+const SHARE_SET_MAGIC: [u8; 2] = *b"SS";
+const SHARE_SET_VERSION: u8 = 1;
+// 头部长度：魔数 2 字节 + 版本 1 字节 + 份额数量 4 字节
+const SHARE_SET_HEADER_LEN: usize = 7;
+// 份额指纹的域分隔标签
+const FINGERPRINT_DOMAIN: &[u8] = b"shamir_secret_sharing share fingerprint v1";
+
+/// 秘密份额，即多项式在 `index` 处的值 `value`
+///
+/// 份额的加减法对应秘密的加减法：两个在相同 x 坐标上的份额相加，得到两个秘密之和的份额；
+/// 份额乘以公开常数，得到秘密乘以该常数的份额。
+/// 只有 x 坐标相同的份额才能相加减，`+` 和 `-` 在 x 坐标不同时会 panic，
+/// 需要处理错误时请使用 `checked_add` 和 `checked_sub`。
+///
+/// `Debug` 和 `Display` 只输出 x 坐标，需要输出份额的值时使用 [`Share::reveal`]。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Share {
+    index: Scalar,
+    value: Scalar,
+}
+
+impl Share {
+    /// 由 x 坐标和 y 坐标创建份额
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 份额的 x 坐标
+    /// * `value` - 份额的 y 坐标
+    pub fn new(index: Scalar, value: Scalar) -> Self {
+        Share { index, value }
+    }
+
+    /// 返回份额的 x 坐标
+    pub fn index(&self) -> Scalar {
+        self.index
+    }
+
+    /// 返回份额的 y 坐标
+    pub fn value(&self) -> Scalar {
+        self.value
+    }
+
+    /// 返回可以输出份额值的包装，仅在确实需要完整输出时使用
+    pub fn reveal(&self) -> Revealed<'_, Self> {
+        Revealed(self)
+    }
+
+    /// 计算份额的指纹，用于在不暴露 y 的情况下识别和匹配份额
+    ///
+    /// 指纹为 SM3(域分隔标签 || x || 压缩编码的 G * y) 的前 8 字节：G * y 是 y 的单向承诺，
+    /// 截断后的哈希也无法反推 y，因此指纹不是秘密，可以记录日志或显示给操作员。
+    /// x 坐标和值都相同的份额指纹相同，否则以压倒性的概率不同。
+    ///
+    /// # Returns
+    ///
+    /// * `[u8; 8]` - 份额的指纹
+    pub fn fingerprint(&self) -> [u8; 8] {
+        let commitment = (ProjectivePoint::GENERATOR * self.value).to_affine().to_encoded_point(true);
+        let digest = Sm3::new()
+            .chain_update(FINGERPRINT_DOMAIN)
+            .chain_update(self.index.to_bytes())
+            .chain_update(commitment.as_bytes())
+            .finalize();
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&digest[..8]);
+        fingerprint
+    }
+
+    /// 两个份额相加，x 坐标不同时返回错误
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - 另一个份额
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Share, IndexMismatch>` - 两个秘密之和的份额
+    pub fn checked_add(&self, other: &Share) -> Result<Share, IndexMismatch> {
+        if self.index != other.index {
+            return Err(IndexMismatch);
+        }
+        Ok(Share::new(self.index, self.value + other.value))
+    }
+
+    /// 两个份额相减，x 坐标不同时返回错误
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - 另一个份额
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Share, IndexMismatch>` - 两个秘密之差的份额
+    pub fn checked_sub(&self, other: &Share) -> Result<Share, IndexMismatch> {
+        if self.index != other.index {
+            return Err(IndexMismatch);
+        }
+        Ok(Share::new(self.index, self.value - other.value))
+    }
+}
+
+// 只输出 x 坐标，份额的值用 .. 代替
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Share {{ index: ")?;
+        fmt_index(&self.index, f)?;
+        write!(f, ", value: .. }}")
+    }
+}
+
+impl fmt::Display for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "share #")?;
+        fmt_index(&self.index, f)?;
+        write!(f, " (value redacted)")
+    }
+}
+
+impl fmt::Display for Revealed<'_, Share> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "share #")?;
+        fmt_index(&self.0.index, f)?;
+        write!(f, " = ")?;
+        fmt_field(&self.0.value, f)
+    }
+}
+
+impl fmt::Debug for Revealed<'_, Share> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Share {{ index: ")?;
+        fmt_index(&self.0.index, f)?;
+        write!(f, ", value: ")?;
+        fmt_field(&self.0.value, f)?;
+        write!(f, " }}")
+    }
+}
+
+impl From<(Scalar, Scalar)> for Share {
+    fn from((index, value): (Scalar, Scalar)) -> Self {
+        Share::new(index, value)
+    }
+}
+
+impl From<Share> for (Scalar, Scalar) {
+    fn from(share: Share) -> Self {
+        (share.index, share.value)
+    }
+}
+
+impl Add for Share {
+    type Output = Share;
+
+    /// # Panics
+    ///
+    /// 如果两个份额的 x 坐标不同则 panic
+    fn add(self, other: Share) -> Share {
+        self.checked_add(&other).expect("cannot add shares with different x-coordinates")
+    }
+}
+
+impl Sub for Share {
+    type Output = Share;
+
+    /// # Panics
+    ///
+    /// 如果两个份额的 x 坐标不同则 panic
+    fn sub(self, other: Share) -> Share {
+        self.checked_sub(&other).expect("cannot subtract shares with different x-coordinates")
+    }
+}
+
+impl Mul<Scalar> for Share {
+    type Output = Share;
+
+    fn mul(self, c: Scalar) -> Share {
+        Share::new(self.index, self.value * c)
+    }
+}
+
+/// 生成 n 个 `Share` 类型的份额，至少需要 t 个份额才能恢复秘密
+///
+/// 这是 `secret_sharing::generate_shares` 的类型化版本，后者继续以 `(x, y)` 元组的形式提供
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Vec<Share>` - 份额列表
+pub fn generate_shares<R: RngCore + CryptoRng>(secret: Scalar, n: usize, t: usize, rng: &mut R) -> Vec<Share> {
+    secret_sharing::generate_shares(secret, n, t, rng)
+        .into_iter()
+        .map(Share::from)
+        .collect()
+}
+
+/// 使用拉格朗日插值从 `Share` 类型的份额恢复秘密
+///
+/// 这是 `secret_sharing::reconstruct_secret` 的类型化版本
+///
+/// # Arguments
+///
+/// * `shares` - 份额列表
+///
+/// # Returns
+///
+/// * `Scalar` - 恢复的秘密
+pub fn reconstruct_secret(shares: &[Share]) -> Scalar {
+    let tuples: Vec<(Scalar, Scalar)> = shares.iter().map(|&share| share.into()).collect();
+    secret_sharing::reconstruct_secret(&tuples)
+}
+
+/// 一组完整的份额，提供不依赖 serde 的紧凑二进制编码
+///
+/// `Debug` 只输出份额数量。
+#[derive(Clone, PartialEq, Eq)]
+pub struct ShareSet {
+    shares: Vec<(Scalar, Scalar)>,
+}
+
+impl fmt::Debug for ShareSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShareSet").field("len", &self.shares.len()).finish_non_exhaustive()
+    }
+}
+
+impl ShareSet {
+    /// 由份额列表创建份额集合
+    pub fn new(shares: Vec<(Scalar, Scalar)>) -> Self {
+        ShareSet { shares }
+    }
+
+    /// 返回集合中的份额
+    pub fn shares(&self) -> &[(Scalar, Scalar)] {
+        &self.shares
+    }
+
+    /// 编码为字节串："SS" || 版本 || 份额数量 (u32 大端) || (x || y) * 数量，
+    /// 其中 x 和 y 均为 32 字节大端编码
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(SHARE_SET_HEADER_LEN + 64 * self.shares.len());
+        bytes.extend_from_slice(&SHARE_SET_MAGIC);
+        bytes.push(SHARE_SET_VERSION);
+        bytes.extend_from_slice(&(self.shares.len() as u32).to_be_bytes());
+        for (x, y) in &self.shares {
+            bytes.extend_from_slice(&x.to_bytes());
+            bytes.extend_from_slice(&y.to_bytes());
+        }
+        bytes
+    }
+
+    /// 从字节串解码，检查头部、长度以及每个标量编码是否规范
+    pub fn from_bytes(bytes: &[u8]) -> Result<ShareSet, DecodeError> {
+        if bytes.len() < SHARE_SET_HEADER_LEN {
+            return Err(DecodeError::Truncated);
+        }
+        if bytes[..2] != SHARE_SET_MAGIC || bytes[2] != SHARE_SET_VERSION {
+            return Err(DecodeError::UnsupportedVersion);
+        }
+
+        let mut count = [0u8; 4];
+        count.copy_from_slice(&bytes[3..SHARE_SET_HEADER_LEN]);
+        let count = u32::from_be_bytes(count) as usize;
+
+        let body = &bytes[SHARE_SET_HEADER_LEN..];
+        let expected = count.checked_mul(64).ok_or(DecodeError::Truncated)?;
+        if body.len() < expected {
+            return Err(DecodeError::Truncated);
+        }
+        if body.len() > expected {
+            return Err(DecodeError::TrailingBytes);
+        }
+
+        let shares = body
+            .chunks_exact(64)
+            .map(|record| {
+                let x = scalar_from_bytes(&record[..32]);
+                let y = scalar_from_bytes(&record[32..]);
+                x.zip(y).ok_or(DecodeError::NonCanonicalScalar)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ShareSet { shares })
+    }
+}
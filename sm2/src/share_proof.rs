@@ -0,0 +1,93 @@
+//! 份额的 Schnorr 知识证明
+//!
+//! 份额持有者把份额交给合并者时，可以附带一个非交互式证明，表明自己知道满足
+//! g * y = Σ C_j * x^j 的 y，即持有与分发者公布的 Feldman 承诺一致的真实份额。
+//! 合并者只凭公开的承诺即可验证，无需联系分发者；证明不泄露 y。
+//!
+//! 证明用 [`hash_to_scalar`] 做 Fiat-Shamir 变换，挑战值绑定域分隔标签、生成元、x 坐标、全部承诺、
+//! 公开值 Y = g * y 以及证明者的承诺 A = g * w，因此证明不能挪用到其他份额或其他分发上。
+
+use alloc::vec::Vec;
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::{ProjectivePoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use crate::hash::hash_to_scalar;
+use crate::secret_sharing::evaluate_commitments;
+
+// Fiat-Shamir 挑战值的域分隔标签
+const SHARE_PROOF_DOMAIN: &[u8] = b"shamir_secret_sharing/share_proof";
+
+/// 知道份额值 y 的非交互式 Schnorr 证明
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareProof {
+    /// 挑战值 c
+    pub challenge: Scalar,
+    /// 响应值 r = w - c * y
+    pub response: Scalar,
+}
+
+/// 证明知道与承诺一致的份额值
+///
+/// # Arguments
+///
+/// * `share` - 份额 (x, y)
+/// * `commitments` - 分发者公布的 Feldman 承诺
+/// * `g` - 承诺使用的生成元
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `ShareProof` - 证明；份额与承诺不一致时得到的证明无法通过验证
+pub fn prove_share_knowledge<R: RngCore + CryptoRng>(
+    share: (Scalar, Scalar),
+    commitments: &[ProjectivePoint],
+    g: ProjectivePoint,
+    rng: &mut R,
+) -> ShareProof {
+    let (x, y) = share;
+    let w = Scalar::random(&mut *rng);
+    let challenge = share_proof_challenge(x, commitments, g, g * y, g * w);
+    ShareProof {
+        challenge,
+        response: w - challenge * y,
+    }
+}
+
+/// 验证份额的知识证明
+///
+/// # Arguments
+///
+/// * `proof` - 份额持有者给出的证明
+/// * `x` - 份额的 x 坐标
+/// * `commitments` - 分发者公布的 Feldman 承诺
+/// * `g` - 承诺使用的生成元
+///
+/// # Returns
+///
+/// * `bool` - 证明者知道满足 g * y = Σ C_j * x^j 的 y 时返回 true
+pub fn verify_share_proof(proof: &ShareProof, x: Scalar, commitments: &[ProjectivePoint], g: ProjectivePoint) -> bool {
+    let public = evaluate_commitments(commitments, x);
+    // A = g * r + Y * c
+    let a = g * proof.response + public * proof.challenge;
+    proof.challenge == share_proof_challenge(x, commitments, g, public, a)
+}
+
+fn share_proof_challenge(
+    x: Scalar,
+    commitments: &[ProjectivePoint],
+    g: ProjectivePoint,
+    public: ProjectivePoint,
+    a: ProjectivePoint,
+) -> Scalar {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&g.to_bytes());
+    bytes.extend_from_slice(&x.to_bytes());
+    bytes.extend_from_slice(&(commitments.len() as u32).to_be_bytes());
+    for commitment in commitments {
+        bytes.extend_from_slice(&commitment.to_bytes());
+    }
+    bytes.extend_from_slice(&public.to_bytes());
+    bytes.extend_from_slice(&a.to_bytes());
+    hash_to_scalar(SHARE_PROOF_DOMAIN, &bytes)
+}
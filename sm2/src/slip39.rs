@@ -0,0 +1,138 @@
+//! SLIP-0039 风格的两级（组/成员）秘密共享
+//!
+//! 秘密先以组门限值 GT 在 G 个组之间分享，得到每个组的组份额；第 i 个组的组份额再以成员门限值 T_i
+//! 在该组的 N_i 个成员之间分享。恢复时需要至少 GT 个组，且每个组至少有 T_i 个成员到场。
+//!
+//! 这里只沿用 SLIP-0039 的组与成员结构和参数约定，两级都使用本库在 SM2 标量域上的 Shamir 分享，
+//! 不包含 SLIP-0039 在 GF(256) 上的编码、助记词格式和 RS1024 校验和，份额不能与硬件钱包直接互换。
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use sm2::Scalar;
+use rand::{CryptoRng, RngCore};
+use crate::error::{ReconstructError, SecretSharingError, ShareError};
+use crate::polynomial::CoefficientField;
+use crate::secret_sharing::{try_generate_shares, try_reconstruct_secret, validate_threshold};
+
+/// 一个成员持有的份额
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slip39Share<F = Scalar> {
+    /// 所在组的编号，从 1 开始，也是组份额的 x 坐标
+    pub group_index: u8,
+    /// 恢复秘密所需的组数 GT
+    pub group_threshold: u8,
+    /// 组的总数 G
+    pub group_count: u8,
+    /// 组内成员编号，从 1 开始，也是成员份额的 x 坐标
+    pub member_index: u8,
+    /// 恢复组份额所需的成员数 T_i
+    pub member_threshold: u8,
+    /// 成员份额的值
+    pub value: F,
+}
+
+/// 生成两级份额
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `group_threshold` - 恢复秘密所需的组数 GT
+/// * `groups` - 每个组的 `(成员门限值 T_i, 成员数 N_i)`
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<Vec<Vec<Slip39Share<F>>>, ShareError>` - 按组排列的成员份额；
+///   组门限值或任意一组的成员门限值不合法时返回错误，此时不会生成任何份额
+pub fn split_slip39<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    rng: &mut R,
+) -> Result<Vec<Vec<Slip39Share<F>>>, ShareError> {
+    if groups.len() > u8::MAX as usize {
+        return Err(ShareError::ThresholdExceedsShares);
+    }
+    validate_threshold(groups.len(), group_threshold as usize)?;
+    for &(member_threshold, member_count) in groups {
+        validate_threshold(member_count as usize, member_threshold as usize)?;
+    }
+
+    let group_shares = try_generate_shares(secret, groups.len(), group_threshold as usize, rng)?;
+    let mut result = Vec::with_capacity(groups.len());
+    for (i, (&(member_threshold, member_count), &(_, group_value))) in groups.iter().zip(&group_shares).enumerate() {
+        let members = try_generate_shares(group_value, member_count as usize, member_threshold as usize, rng)?;
+        result.push(
+            members
+                .into_iter()
+                .enumerate()
+                .map(|(j, (_, value))| Slip39Share {
+                    group_index: (i + 1) as u8,
+                    group_threshold,
+                    group_count: groups.len() as u8,
+                    member_index: (j + 1) as u8,
+                    member_threshold,
+                    value,
+                })
+                .collect(),
+        );
+    }
+    Ok(result)
+}
+
+/// 从成员份额恢复秘密
+///
+/// 成员数不足门限值的组会被忽略；只要有至少 GT 个组满足各自的成员门限值即可恢复。
+///
+/// # Arguments
+///
+/// * `shares` - 任意组的成员份额
+///
+/// # Returns
+///
+/// * `Result<F, ReconstructError>` - 恢复的秘密；份额的组参数不一致时返回 `InconsistentShares`，
+///   同一组内成员编号重复时返回 `DuplicateShareIndex`，满足门限的组不足 GT 个时返回 `InsufficientShares`
+pub fn reconstruct_slip39<F: CoefficientField>(shares: &[Slip39Share<F>]) -> Result<F, ReconstructError> {
+    let first = shares.first().ok_or(ReconstructError::InsufficientShares)?;
+    if shares
+        .iter()
+        .any(|s| s.group_threshold != first.group_threshold || s.group_count != first.group_count)
+    {
+        return Err(ReconstructError::InconsistentShares);
+    }
+
+    let mut by_group: BTreeMap<u8, Vec<&Slip39Share<F>>> = BTreeMap::new();
+    for share in shares {
+        by_group.entry(share.group_index).or_default().push(share);
+    }
+
+    let mut group_shares = Vec::new();
+    for (&group_index, members) in &by_group {
+        let member_threshold = members[0].member_threshold;
+        if members.iter().any(|m| m.member_threshold != member_threshold) {
+            return Err(ReconstructError::InconsistentShares);
+        }
+        if members.len() < member_threshold as usize {
+            continue;
+        }
+        let points: Vec<(F, F)> = members
+            .iter()
+            .map(|m| (F::from(m.member_index as u64), m.value))
+            .collect();
+        group_shares.push((F::from(group_index as u64), try_reconstruct_secret(&points).map_err(reconstruct_error)?));
+    }
+
+    if group_shares.len() < first.group_threshold as usize {
+        return Err(ReconstructError::InsufficientShares);
+    }
+    try_reconstruct_secret(&group_shares).map_err(reconstruct_error)
+}
+
+// 成员和组编号都来自 u8 字段，只可能出现编号重复或为 0 的错误
+fn reconstruct_error(e: SecretSharingError) -> ReconstructError {
+    match e {
+        SecretSharingError::DuplicateShareIndex => ReconstructError::DuplicateShareIndex,
+        SecretSharingError::ZeroShareIndex => ReconstructError::ZeroShareIndex,
+        _ => ReconstructError::InsufficientShares,
+    }
+}
@@ -0,0 +1,196 @@
+//! 确定性测试向量
+//!
+//! 每个向量固定秘密、ChaCha20 种子、份额数量和门限值，生成元 g 为 SM2 基点，
+//! Pedersen 的 h 为 `independent_generator(b"shamir_secret_sharing/test_vectors/h")`。
+//! 以种子初始化的 ChaCha20 随机数生成器依次产生多项式的 t-1 个随机系数（Pedersen 方案随后产生盲化多项式的 t 个系数），
+//! 份额的 x 坐标为 1 到 n。
+//!
+//! 期望值以小写十六进制保存：份额为 64 字节的 x || y（均为 32 字节大端编码），
+//! 承诺为 33 字节的压缩 SEC1 编码，盲化值为 32 字节大端编码。其他实现可以用这些向量检查兼容性，
+//! [`verify_test_vector`] 则用于发现本库自身的回归。
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::elliptic_curve::PrimeField;
+use sm2::{FieldBytes, ProjectivePoint, Scalar};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use crate::secret_sharing::{
+    generate_shares_deterministic, generate_shares_with_feldman_vss, generate_shares_with_pedersen_vss,
+    independent_generator,
+};
+
+/// 测试向量使用的分享方案
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// Shamir 分享，使用 `generate_shares_deterministic`，没有承诺
+    Shamir,
+    /// Feldman VSS，承诺生成元为 g
+    Feldman,
+    /// Pedersen VSS，承诺生成元为 g 和 h
+    Pedersen,
+}
+
+/// 一个测试向量：固定的输入和以十六进制保存的期望输出
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestVector {
+    /// 分享方案
+    pub scheme: Scheme,
+    /// 秘密，32 字节大端编码
+    pub secret: &'static str,
+    /// ChaCha20 的种子，32 字节
+    pub seed: &'static str,
+    /// 份额的总数
+    pub n: usize,
+    /// 门限值
+    pub t: usize,
+    /// 期望的份额 x || y
+    pub shares: &'static [&'static str],
+    /// 期望的承诺，Shamir 方案为空
+    pub commitments: &'static [&'static str],
+    /// 期望的盲化值 r(x_i)，仅 Pedersen 方案非空
+    pub blinding_values: &'static [&'static str],
+}
+
+/// 测试向量中 Pedersen 方案使用的生成元 h
+pub fn test_vector_h() -> ProjectivePoint {
+    independent_generator(b"shamir_secret_sharing/test_vectors/h")
+}
+
+/// 用测试向量的输入重新计算份额、承诺和盲化值，并与期望值逐字节比较
+///
+/// # Arguments
+///
+/// * `vector` - 测试向量
+///
+/// # Returns
+///
+/// * `bool` - 所有输出与期望值完全一致时返回 true
+///
+/// # Panics
+///
+/// 如果向量中的秘密或种子不是 64 个十六进制字符，或秘密不是规范的标量编码则 panic
+pub fn verify_test_vector(vector: &TestVector) -> bool {
+    let (shares, commitments, blinding_values) = compute(vector);
+    shares == vector.shares && commitments == vector.commitments && blinding_values == vector.blinding_values
+}
+
+/// 本库的测试向量
+pub const TEST_VECTORS: [TestVector; 3] = [
+    TestVector {
+        scheme: Scheme::Shamir,
+        secret: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        seed: "0000000000000000000000000000000000000000000000000000000000000000",
+        n: 5,
+        t: 3,
+        shares: &[
+            "000000000000000000000000000000000000000000000000000000000000000150fb3c2df64d8c24bf8b5530186c156fc6230554a7f59f23301bcc4820ad5049",
+            "0000000000000000000000000000000000000000000000000000000000000002567829538b44a35d655760d4967bb1406cb4aba8227b601227b60336176fcb39",
+            "00000000000000000000000000000000000000000000000000000000000000031077c973c2ea4bb0f96d2cf8863be18103c6050d83a658e3fee7bee500648eef",
+            "00000000000000000000000000000000000000000000000000000000000000047efa1c8d9d3e851f7bccb99be7aca630fd5af0efed3c8ec4096cf35e1560dc8e",
+            "0000000000000000000000000000000000000000000000000000000000000005a1ff22a21a414fa8ec7606bebacdff50e76f8fe43d77fc86f389ac981c8f72f3",
+        ],
+        commitments: &[],
+        blinding_values: &[],
+    },
+    TestVector {
+        scheme: Scheme::Feldman,
+        secret: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        seed: "0101010101010101010101010101010101010101010101010101010101010101",
+        n: 5,
+        t: 3,
+        shares: &[
+            "0000000000000000000000000000000000000000000000000000000000000001210c6fa9f438608378dc2d59eeda2077f48a7342faa69c6c152d199b74825764",
+            "00000000000000000000000000000000000000000000000000000000000000027fb04a5e5089827080884b0d0c88de61b63f2d3a2ad971fbf877489d6d8196c3",
+            "00000000000000000000000000000000000000000000000000000000000000031bec922118f86bce1f0d6324651947cce32b608d82e7919b6e3bb317cd459b19",
+            "0000000000000000000000000000000000000000000000000000000000000004f5c146f04d851c9c546b759ff88b5cb85f56cc13465d05a11df2411d0778e6ac",
+            "00000000000000000000000000000000000000000000000000000000000000050d2e68ceee2f94db20a2827fc6df1d25d4b5d18a0fe7be8b0c6716916e9bb613",
+        ],
+        commitments: &[
+            "02a3d7b9ab9abd9c10bfd27e6db1eb80b170c07aca09ffd914c7c57e62c0e50ff3",
+            "039c3d636a425ee3992bd6031c61dffffc083097f18ecafe1896d2ce71c5e58438",
+            "02df434315be414e40ab4cb09d56ef1954420b6b16cf5aa75174e8a3086811a72a",
+        ],
+        blinding_values: &[],
+    },
+    TestVector {
+        scheme: Scheme::Pedersen,
+        secret: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        seed: "0202020202020202020202020202020202020202020202020202020202020202",
+        n: 4,
+        t: 2,
+        shares: &[
+            "0000000000000000000000000000000000000000000000000000000000000001f6a22eac03c8106dd21d16d77e8341702592a5742c82556a75f2b9a9c6e7aded",
+            "0000000000000000000000000000000000000000000000000000000000000002ed435b56038b1ad49c3123a3f0f974d1c910596a23298f928010652f37dcfc98",
+            "0000000000000000000000000000000000000000000000000000000000000003e3e48800034e253b66453070636fa8336c8e0d6019d0c9ba8a2e10b4a8d24b43",
+            "0000000000000000000000000000000000000000000000000000000000000004da85b4aa03112fa230593d3cd5e5db95100bc156107803e2944bbc3a19c799ee",
+        ],
+        commitments: &[
+            "03ed408f9fb3a2c820e459be78848ef9b0f8437b032ae26aa72ab1aeb5cadd7df1",
+            "026eb081f41328ba384e2462be992da123e45985a5d5fd61165de1a76242e0c26a",
+        ],
+        blinding_values: &[
+            "ca4d10d5428ba81548ceb2548d143a593b160dbea7e98c332665a5882125fd8e",
+            "15174447901ff3f6b7591936e2fc9108c068399826a119d39a57c3e072a59255",
+            "5fe177b8ddb43fd825e3801938e4e7b7b7be44dcc71eac9f6205d641fdfa683f",
+            "aaabab2a2b488bb9946de6fb8ecd3e66af145021679c3f6b29b3e8a3894f3e29",
+        ],
+    },
+];
+
+// 用测试向量的输入计算十六进制编码的份额、承诺和盲化值
+fn compute(vector: &TestVector) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let secret = Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(decode_hex32(vector.secret))))
+        .expect("test vector secret must be a canonical scalar");
+    let seed = decode_hex32(vector.seed);
+    let g = ProjectivePoint::GENERATOR;
+
+    let (shares, commitments, blinding_values) = match vector.scheme {
+        Scheme::Shamir => (generate_shares_deterministic(secret, vector.n, vector.t, seed), Vec::new(), Vec::new()),
+        Scheme::Feldman => {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let (shares, commitments) = generate_shares_with_feldman_vss(secret, vector.n, vector.t, g, &mut rng);
+            (shares, commitments, Vec::new())
+        }
+        Scheme::Pedersen => {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let (shares, commitments, blinding_values) =
+                generate_shares_with_pedersen_vss(secret, vector.n, vector.t, g, test_vector_h(), &mut rng);
+            (shares, commitments, blinding_values.into_iter().map(|(_, r)| r).collect())
+        }
+    };
+
+    (
+        shares
+            .iter()
+            .map(|(x, y)| {
+                let mut bytes = Vec::with_capacity(64);
+                bytes.extend_from_slice(&x.to_bytes());
+                bytes.extend_from_slice(&y.to_bytes());
+                encode_hex(&bytes)
+            })
+            .collect(),
+        commitments.iter().map(|c| encode_hex(&c.to_bytes())).collect(),
+        blinding_values.iter().map(|r| encode_hex(&r.to_bytes())).collect(),
+    )
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        write!(hex, "{:02x}", byte).expect("writing to a String cannot fail");
+    }
+    hex
+}
+
+fn decode_hex32(hex: &str) -> [u8; 32] {
+    assert_eq!(hex.len(), 64, "expected 64 hex characters");
+    let mut bytes = [0u8; 32];
+    for (byte, chunk) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let digits = core::str::from_utf8(chunk).expect("hex must be ASCII");
+        *byte = u8::from_str_radix(digits, 16).expect("invalid hex digit");
+    }
+    bytes
+}
@@ -0,0 +1,234 @@
+//! 门限 SM2 签名
+//!
+//! SM2 签名 s = (1 + d)^-1 * (k - r * d) 对私钥 d 不是线性的，因此分享的不是 d，而是 w = (1 + d)^-1：
+//! 改写得 s = w * (k + r) - r。若 nonce 取 k = u * (1 + d)，则 s = u + r * w - r 对 u 和 w 都是线性的，
+//! t 个签名者可以各自用自己的份额计算部分签名，由合并者插值得到标准的 SM2 签名，私钥从未被恢复。
+//!
+//! 协议流程：
+//!
+//! 1. 分发者调用 [`split_signing_key`] 分享 w，并公开公钥 P = d * G。
+//! 2. 每个签名者调用 [`commit_nonce`] 选择随机的 u_i，公开 R_i = u_i * (G + P)。
+//!    R = Σ R_i = u * (1 + d) * G，即 k = u * (1 + d)。
+//! 3. 合并者用 [`challenge`] 计算 r = e + x(R)，其中 e 由 [`message_digest`] 得到。
+//! 4. 每个签名者调用 [`partial_sign`] 得到部分签名 σ_i = r * w_i + u_i / λ_i，
+//!    λ_i 为本次签名者集合上 x = 0 处的拉格朗日系数。
+//! 5. 合并者调用 [`combine_partial_signatures`]：对 σ_i 插值得到 Σ λ_i * σ_i = r * w + u，
+//!    减去 r 即为 s。
+//!
+//! 协议假设签名者诚实执行，不包含零知识证明；每个 nonce 只能使用一次。
+
+use alloc::vec::Vec;
+use core::fmt;
+use sm2::dsa::Signature;
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::ops::Reduce;
+use sm2::elliptic_curve::point::AffineCoordinates;
+use sm2::elliptic_curve::sec1::ToEncodedPoint;
+use sm2::{FieldBytes, ProjectivePoint, Scalar, U256};
+use rand::{CryptoRng, RngCore};
+use sm3::{Digest, Sm3};
+use crate::error::SecretSharingError;
+use crate::secret_sharing::{lagrange_at_zero, reconstruct_secret, try_generate_shares_with_feldman_vss};
+
+// SM2 曲线方程的系数 a 和 b，用于计算用户信息摘要 Z
+const EQUATION_A: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+];
+const EQUATION_B: [u8; 32] = [
+    0x28, 0xe9, 0xfa, 0x9e, 0x9d, 0x9f, 0x5e, 0x34, 0x4d, 0x5a, 0x9e, 0x4b, 0xcf, 0x65, 0x09, 0xa7, 0xf3, 0x97, 0x89,
+    0xf5, 0x15, 0xab, 0x8f, 0x92, 0xdd, 0xbc, 0xbd, 0x41, 0x4d, 0x94, 0x0e, 0x93,
+];
+
+/// 门限签名中可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdSignError {
+    /// 私钥为 0 或 n - 1，1 + d 不可逆
+    InvalidKey,
+    /// 签名者自己的 x 坐标不在签名者集合中
+    SignerNotInSet,
+    /// 签名者集合中有重复或为 0 的 x 坐标
+    InvalidSignerSet,
+    /// 得到的 r 或 s 为 0，或 r + k 为 0，需要换用新的 nonce 重新签名
+    DegenerateSignature,
+    /// 门限值或份额数量不合法
+    Invalid(SecretSharingError),
+}
+
+impl fmt::Display for ThresholdSignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThresholdSignError::InvalidKey => write!(f, "private key must not be 0 or n - 1"),
+            ThresholdSignError::SignerNotInSet => write!(f, "signer is not part of the signer set"),
+            ThresholdSignError::InvalidSignerSet => write!(f, "signer set has duplicate or zero x-coordinates"),
+            ThresholdSignError::DegenerateSignature => write!(f, "degenerate signature, retry with fresh nonces"),
+            ThresholdSignError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ThresholdSignError {}
+
+impl From<SecretSharingError> for ThresholdSignError {
+    fn from(e: SecretSharingError) -> Self {
+        ThresholdSignError::Invalid(e)
+    }
+}
+
+/// 分发者分享签名私钥的结果
+#[derive(Debug, Clone)]
+pub struct ThresholdSigningKey {
+    /// w = (1 + d)^-1 的份额
+    pub shares: Vec<(Scalar, Scalar)>,
+    /// w 的多项式的 Feldman 承诺，可用 `verify_share_with_feldman_vss` 验证份额；
+    /// 其中 commitments[0] = w * G，不是签名公钥
+    pub commitments: Vec<ProjectivePoint>,
+    /// 签名公钥 P = d * G，用于验证合并后的签名
+    pub public_key: ProjectivePoint,
+}
+
+/// 签名者在一次签名中使用的秘密 nonce u_i，不可复制，传给 `partial_sign` 后即被消耗
+pub struct SigningNonce(Scalar);
+
+/// 分享 SM2 私钥 d 用于门限签名
+///
+/// # Arguments
+///
+/// * `d` - SM2 私钥
+/// * `n` - 份额的总数
+/// * `t` - 签名所需的最少签名者数
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Result<ThresholdSigningKey, ThresholdSignError>` - w = (1 + d)^-1 的份额、承诺和公钥；
+///   d 为 0 或 n - 1 时返回 `InvalidKey`，门限值不合法时返回 `Invalid`
+pub fn split_signing_key<R: RngCore + CryptoRng>(
+    d: Scalar,
+    n: usize,
+    t: usize,
+    rng: &mut R,
+) -> Result<ThresholdSigningKey, ThresholdSignError> {
+    if bool::from(d.is_zero()) {
+        return Err(ThresholdSignError::InvalidKey);
+    }
+    let w = Option::<Scalar>::from((Scalar::ONE + d).invert()).ok_or(ThresholdSignError::InvalidKey)?;
+    let (shares, commitments) = try_generate_shares_with_feldman_vss(w, n, t, ProjectivePoint::GENERATOR, rng)?;
+    Ok(ThresholdSigningKey {
+        shares,
+        commitments,
+        public_key: ProjectivePoint::GENERATOR * d,
+    })
+}
+
+/// 计算待签名消息的摘要 e = SM3(Z || msg)，Z 为由区分标识和公钥得到的用户信息摘要
+///
+/// # Arguments
+///
+/// * `distid` - 签名者的区分标识，与验证时使用的标识相同
+/// * `public_key` - 签名公钥
+/// * `msg` - 待签名消息
+///
+/// # Returns
+///
+/// * `[u8; 32]` - 消息摘要
+///
+/// # Panics
+///
+/// 如果区分标识长度超过 8191 字节则 panic
+pub fn message_digest(distid: &str, public_key: &ProjectivePoint, msg: &[u8]) -> [u8; 32] {
+    let entla = u16::try_from(distid.len() * 8).expect("distinguishing identifier is too long");
+    let g = ProjectivePoint::GENERATOR.to_affine().to_encoded_point(false);
+    let p = public_key.to_affine().to_encoded_point(false);
+    let z = Sm3::new()
+        .chain_update(entla.to_be_bytes())
+        .chain_update(distid)
+        .chain_update(EQUATION_A)
+        .chain_update(EQUATION_B)
+        .chain_update(&g.as_bytes()[1..])
+        .chain_update(&p.as_bytes()[1..])
+        .finalize();
+    Sm3::new().chain_update(z).chain_update(msg).finalize().into()
+}
+
+/// 选择本次签名的 nonce u_i 并计算需要公开的 R_i = u_i * (G + P)
+///
+/// # Returns
+///
+/// * `(SigningNonce, ProjectivePoint)` - 秘密 nonce 和公开的 R_i
+pub fn commit_nonce<R: RngCore + CryptoRng>(public_key: &ProjectivePoint, rng: &mut R) -> (SigningNonce, ProjectivePoint) {
+    let u = Scalar::random(rng);
+    (SigningNonce(u), (ProjectivePoint::GENERATOR + public_key) * u)
+}
+
+/// 由消息摘要和所有签名者公开的 R_i 计算 r = e + x(Σ R_i)
+///
+/// # Returns
+///
+/// * `Result<Scalar, ThresholdSignError>` - r；r 为 0 时返回 `DegenerateSignature`
+pub fn challenge(digest: &[u8; 32], nonce_commitments: &[ProjectivePoint]) -> Result<Scalar, ThresholdSignError> {
+    let big_r = nonce_commitments.iter().fold(ProjectivePoint::IDENTITY, |acc, r_i| acc + r_i);
+    let e = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*digest));
+    let r = e + <Scalar as Reduce<U256>>::reduce_bytes(&big_r.to_affine().x());
+    if bool::from(r.is_zero()) {
+        return Err(ThresholdSignError::DegenerateSignature);
+    }
+    Ok(r)
+}
+
+/// 计算部分签名 σ_i = r * w_i + u_i / λ_i
+///
+/// # Arguments
+///
+/// * `share` - 签名者持有的 w 的份额 (x_i, w_i)
+/// * `nonce` - 签名者在 `commit_nonce` 中得到的秘密 nonce
+/// * `signer_xs` - 本次参与签名的所有签名者的 x 坐标
+/// * `r` - `challenge` 计算得到的 r
+///
+/// # Returns
+///
+/// * `Result<(Scalar, Scalar), ThresholdSignError>` - 部分签名 (x_i, σ_i)；
+///   x_i 不在 `signer_xs` 中或 `signer_xs` 有重复、为 0 的 x 坐标时返回错误
+pub fn partial_sign(
+    share: (Scalar, Scalar),
+    nonce: SigningNonce,
+    signer_xs: &[Scalar],
+    r: Scalar,
+) -> Result<(Scalar, Scalar), ThresholdSignError> {
+    let (x_i, w_i) = share;
+    for (i, x) in signer_xs.iter().enumerate() {
+        if bool::from(x.is_zero()) || signer_xs[..i].contains(x) {
+            return Err(ThresholdSignError::InvalidSignerSet);
+        }
+    }
+    if !signer_xs.contains(&x_i) {
+        return Err(ThresholdSignError::SignerNotInSet);
+    }
+    // x 坐标互不相同且不为 0 时 λ_i 非零
+    let lambda_inv = lagrange_at_zero(x_i, signer_xs).invert().unwrap();
+    Ok((x_i, r * w_i + nonce.0 * lambda_inv))
+}
+
+/// 合并 t 个部分签名，得到标准的 SM2 签名 (r, s)
+///
+/// # Arguments
+///
+/// * `partials` - 所有签名者的部分签名
+/// * `r` - `challenge` 计算得到的 r
+///
+/// # Returns
+///
+/// * `Result<Signature, ThresholdSignError>` - SM2 签名；s 为 0 或 r + s 为 0 时返回 `DegenerateSignature`
+///
+/// # Panics
+///
+/// 如果部分签名的 x 坐标有重复则 panic
+pub fn combine_partial_signatures(partials: &[(Scalar, Scalar)], r: Scalar) -> Result<Signature, ThresholdSignError> {
+    // Σ λ_i * σ_i = r * w + u
+    let s = reconstruct_secret(partials) - r;
+    if bool::from(s.is_zero()) || bool::from((r + s).is_zero()) {
+        return Err(ThresholdSignError::DegenerateSignature);
+    }
+    Signature::from_scalars(r, s).map_err(|_| ThresholdSignError::DegenerateSignature)
+}
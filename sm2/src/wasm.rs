@@ -0,0 +1,106 @@
+//! WebAssembly 接口
+//!
+//! 启用 `wasm` 特性后通过 `wasm-bindgen` 向 JavaScript 导出以下函数：
+//!
+//! - `generateShares(secretHex, n, t)`：分享一个 64 位十六进制编码的秘密，返回份额的 JSON 数组
+//!   `[{"x": "<hex>", "y": "<hex>"}, ...]`；
+//! - `reconstructSecret(sharesJson)`：从上述格式的 JSON 数组恢复秘密，返回十六进制字符串。
+//!
+//! 标量均为 32 字节大端编码。错误以带可读消息的 JS 异常抛出。
+//! 在浏览器中运行时，随机数来自 `getrandom` 的 `js` 后端（`crypto.getRandomValues`）。
+//!
+//! `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib` 生成 `.wasm` 文件，
+//! 再用 `wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/shamir_secret_sharing.wasm`
+//! 生成 JavaScript 绑定。浏览器中的测试见 `tests/wasm_test.rs`。
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use rand::rngs::OsRng;
+use serde_json::{json, Value};
+use sm2::Scalar;
+use wasm_bindgen::prelude::*;
+use crate::secret_sharing::{scalar_from_bytes, try_generate_shares, try_reconstruct_secret};
+
+/// 分享一个秘密，返回份额的 JSON 数组
+///
+/// # Arguments
+///
+/// * `secret_hex` - 秘密的 32 字节大端十六进制编码，大小写均可
+/// * `n` - 份额的总数
+/// * `t` - 恢复秘密所需的最小份额数
+///
+/// # Returns
+///
+/// * `Result<String, JsError>` - 形如 `[{"x": "...", "y": "..."}]` 的 JSON 字符串；
+///   秘密编码不合法或门限值不合法时抛出异常
+#[wasm_bindgen(js_name = generateShares)]
+pub fn generate_shares(secret_hex: &str, n: usize, t: usize) -> Result<String, JsError> {
+    let secret = scalar_from_hex(secret_hex).map_err(|e| JsError::new(&format!("invalid secret: {}", e)))?;
+    let shares = try_generate_shares(secret, n, t, &mut OsRng).map_err(|e| JsError::new(&e.to_string()))?;
+    let records: Vec<Value> = shares
+        .iter()
+        .map(|(x, y)| json!({ "x": scalar_to_hex(x), "y": scalar_to_hex(y) }))
+        .collect();
+    Ok(Value::Array(records).to_string())
+}
+
+/// 从份额的 JSON 数组恢复秘密
+///
+/// # Arguments
+///
+/// * `shares_json` - `generateShares` 输出格式的 JSON 字符串，应只包含 t 个（或更多个一致的）份额
+///
+/// # Returns
+///
+/// * `Result<String, JsError>` - 秘密的十六进制编码；JSON 格式错误、编码不合法、
+///   份额为空或 x 坐标重复时抛出异常
+#[wasm_bindgen(js_name = reconstructSecret)]
+pub fn reconstruct_secret(shares_json: &str) -> Result<String, JsError> {
+    let records: Vec<Value> =
+        serde_json::from_str(shares_json).map_err(|e| JsError::new(&format!("invalid shares JSON: {}", e)))?;
+    let mut shares = Vec::with_capacity(records.len());
+    for (i, record) in records.iter().enumerate() {
+        let x = field_of(record, "x").map_err(|e| JsError::new(&format!("share {}: {}", i, e)))?;
+        let y = field_of(record, "y").map_err(|e| JsError::new(&format!("share {}: {}", i, e)))?;
+        shares.push((x, y));
+    }
+    let secret = try_reconstruct_secret(&shares).map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(scalar_to_hex(&secret))
+}
+
+// 读取份额记录中名为 `name` 的十六进制标量
+fn field_of(record: &Value, name: &str) -> Result<Scalar, String> {
+    let hex = record
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("missing string field `{}`", name))?;
+    scalar_from_hex(hex).map_err(|e| format!("field `{}`: {}", name, e))
+}
+
+fn scalar_to_hex(scalar: &Scalar) -> String {
+    scalar.to_bytes().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn scalar_from_hex(hex: &str) -> Result<Scalar, &'static str> {
+    let hex = hex.as_bytes();
+    if hex.len() != 64 {
+        return Err("expected 64 hex digits");
+    }
+    let mut bytes = [0u8; 32];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks_exact(2)) {
+        let hi = hex_digit(pair[0]).ok_or("invalid hex digit")?;
+        let lo = hex_digit(pair[1]).ok_or("invalid hex digit")?;
+        *byte = (hi << 4) | lo;
+    }
+    scalar_from_bytes(&bytes).ok_or("scalar is not reduced modulo the group order")
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
@@ -0,0 +1,38 @@
+//! 加权秘密共享
+//!
+//! 权重为 w 的参与者持有 w 个不同的份额。所有份额来自同一个 t-1 次多项式，
+//! 因此只要在场参与者的权重之和达到 t，就可以把他们的份额合并起来直接用 `reconstruct_secret` 恢复秘密。
+
+use alloc::vec::Vec;
+use rand::{CryptoRng, RngCore};
+use crate::polynomial::CoefficientField;
+use crate::secret_sharing::generate_shares;
+
+/// 按权重为每个参与者生成份额
+///
+/// 第 i 个参与者得到 `weights[i]` 个份额，x 坐标取自一段连续区间：
+/// 参与者 0 为 1..=w_0，参与者 1 为 w_0+1..=w_0+w_1，依此类推，不同参与者的 x 坐标不会重复。
+///
+/// # Arguments
+///
+/// * `secret` - 要分享的秘密
+/// * `weights` - 每个参与者的权重
+/// * `t` - 恢复秘密所需的最小权重之和
+/// * `rng` - 随机数生成器
+///
+/// # Returns
+///
+/// * `Vec<Vec<(F, F)>>` - 每个参与者的份额列表
+pub fn generate_weighted_shares<F: CoefficientField, R: RngCore + CryptoRng>(
+    secret: F,
+    weights: &[usize],
+    t: usize,
+    rng: &mut R,
+) -> Vec<Vec<(F, F)>> {
+    let total = weights.iter().sum();
+    let mut shares = generate_shares(secret, total, t, rng).into_iter();
+    weights
+        .iter()
+        .map(|&weight| shares.by_ref().take(weight).collect())
+        .collect()
+}
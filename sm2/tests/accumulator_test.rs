@@ -0,0 +1,28 @@
+use shamir_secret_sharing::accumulator::ReconstructionAccumulator;
+use shamir_secret_sharing::error::SecretSharingError;
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_accumulator_matches_batch_reconstruct() {
+    let mut rng = rand::thread_rng();
+    let secret = Scalar::random(&mut rng);
+    let t = 4;
+    let shares = generate_shares(secret, 7, t, &mut rng);
+
+    let mut accumulator = ReconstructionAccumulator::new();
+    for (k, &(x, y)) in shares.iter().enumerate() {
+        assert_eq!(accumulator.try_finalize(t).is_some(), k >= t);
+        accumulator.add_share(x, y).unwrap();
+        // 每加入一个份额都与批量恢复一致
+        assert_eq!(accumulator.try_finalize(1), Some(reconstruct_secret(&shares[..=k])));
+    }
+    assert_eq!(accumulator.len(), 7);
+    assert_eq!(accumulator.try_finalize(t), Some(secret));
+
+    // 出错时累加器保持不变
+    assert_eq!(accumulator.add_share(shares[2].0, shares[2].1), Err(SecretSharingError::DuplicateShareIndex));
+    assert_eq!(accumulator.add_share(Scalar::ZERO, secret), Err(SecretSharingError::ZeroShareIndex));
+    assert_eq!(accumulator.try_finalize(t), Some(secret));
+}
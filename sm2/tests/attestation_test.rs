@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+use rand::rngs::OsRng;
+use shamir_secret_sharing::attestation::reconstruct_with_attestation;
+use shamir_secret_sharing::secret_sharing::generate_shares;
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_attestation_lists_sorted_xs_used() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+
+    // 以乱序提供份额 x = 5, 2, 4, 1
+    let provided = vec![shares[4], shares[1], shares[3], shares[0]];
+    let (reconstructed, attestation) = reconstruct_with_attestation(&provided, 3).unwrap();
+    assert_eq!(reconstructed, secret);
+
+    // 只记录实际使用的前 t 个份额，并按数值排序
+    let expected: Vec<Scalar> = [2u64, 4, 5].iter().map(|&i| Scalar::from(i)).collect();
+    assert_eq!(attestation.xs_used, expected);
+    assert!(attestation.verify());
+
+    // 篡改 x 坐标后摘要不再匹配
+    let mut tampered = attestation.clone();
+    tampered.xs_used[0] = Scalar::from(3u64);
+    assert!(!tampered.verify());
+
+    // 份额不足时不进行恢复
+    assert!(reconstruct_with_attestation(&provided[..2], 3).is_none());
+}
+
+#[test]
+fn test_attestation_rejects_invalid_input() {
+    let mut rng = OsRng;
+    let shares = generate_shares(Scalar::random(&mut rng), 5, 3, &mut rng);
+
+    // t = 0 时没有使用任何份额，不应产生审计记录
+    assert!(reconstruct_with_attestation(&shares, 0).is_none());
+    // x 坐标重复时返回 None 而不是 panic
+    let duplicated = [shares[0], shares[0], shares[1]];
+    assert!(reconstruct_with_attestation(&duplicated, 3).is_none());
+}
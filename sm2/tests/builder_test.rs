@@ -0,0 +1,68 @@
+use shamir_secret_sharing::builder::{BuilderError, SecretSharingBuilder};
+use shamir_secret_sharing::error::SecretSharingError;
+use shamir_secret_sharing::secret_sharing::{reconstruct_secret, verify_share_with_feldman_vss, verify_share_with_pedersen_vss};
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::Group;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_builder_terminals() {
+    let mut rng = rand::thread_rng();
+    let secret = Scalar::random(&mut rng);
+    let g = ProjectivePoint::GENERATOR;
+    let h = ProjectivePoint::random(&mut rng);
+    let builder = SecretSharingBuilder::new().secret(secret).shares(5).threshold(3);
+
+    let shares = builder.split(&mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert_eq!(reconstruct_secret(&shares[..3]), secret);
+
+    let (shares, commitments) = builder.split_feldman(&mut rng).unwrap();
+    assert!(shares.iter().all(|&share| verify_share_with_feldman_vss(share, &commitments, g)));
+    assert_eq!(reconstruct_secret(&shares[2..]), secret);
+
+    let (shares, commitments, blinding) = builder.generator_g(g).generator_h(h).split_pedersen(&mut rng).unwrap();
+    for (&share, &(_, r)) in shares.iter().zip(&blinding) {
+        assert!(verify_share_with_pedersen_vss(share, r, &commitments, g, h));
+    }
+    assert_eq!(reconstruct_secret(&shares[1..4]), secret);
+}
+
+#[test]
+fn test_builder_validates_configuration() {
+    let mut rng = rand::thread_rng();
+    let secret = Scalar::random(&mut rng);
+    let g = ProjectivePoint::GENERATOR;
+
+    assert_eq!(
+        SecretSharingBuilder::<ProjectivePoint>::new().shares(5).threshold(3).split(&mut rng),
+        Err(BuilderError::MissingParameter("secret"))
+    );
+    assert_eq!(
+        SecretSharingBuilder::<ProjectivePoint>::new().secret(secret).threshold(3).split_feldman(&mut rng),
+        Err(BuilderError::MissingParameter("shares"))
+    );
+
+    let builder = SecretSharingBuilder::new().secret(secret).shares(3).threshold(4);
+    assert_eq!(builder.split(&mut rng), Err(BuilderError::Invalid(SecretSharingError::InvalidThreshold)));
+    assert_eq!(builder.split_feldman(&mut rng), Err(BuilderError::Invalid(SecretSharingError::InvalidThreshold)));
+
+    let builder = builder.threshold(2);
+    assert_eq!(builder.split_pedersen(&mut rng), Err(BuilderError::MissingParameter("generator_h")));
+    assert_eq!(builder.clone().generator_h(g).split_pedersen(&mut rng), Err(BuilderError::InvalidGenerators));
+    assert_eq!(
+        builder.generator_h(ProjectivePoint::IDENTITY).split_pedersen(&mut rng),
+        Err(BuilderError::InvalidGenerators)
+    );
+}
+
+#[test]
+fn test_builder_debug_hides_secret() {
+    let secret = Scalar::from(0x1234_5678u64);
+    let builder = SecretSharingBuilder::<ProjectivePoint>::new().secret(secret).shares(5).threshold(3);
+    let output = format!("{:?}", builder);
+    assert!(output.contains("secret: true"));
+    assert!(output.contains("n: Some(5)"));
+    assert!(!output.contains("12345678"));
+    assert!(!output.contains(&format!("{:?}", secret)));
+}
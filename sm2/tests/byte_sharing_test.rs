@@ -0,0 +1,38 @@
+use rand::{rngs::OsRng, RngCore};
+use shamir_secret_sharing::byte_sharing::{reconstruct_bytes, split_bytes};
+use shamir_secret_sharing::error::{ReconstructError, ShareError};
+
+#[test]
+fn test_split_and_reconstruct_13_byte_secret() {
+    let mut rng = OsRng;
+    // 末尾的零字节不能丢失
+    let secret = *b"hello\0world\0\0";
+
+    let shares = split_bytes(&secret, 5, 3, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert_eq!(reconstruct_bytes(&shares[0..3]).unwrap(), secret.to_vec());
+    assert_eq!(reconstruct_bytes(&shares[2..5]).unwrap(), secret.to_vec());
+}
+
+#[test]
+fn test_split_and_reconstruct_64_byte_secret() {
+    let mut rng = OsRng;
+    let mut secret = [0u8; 64];
+    rng.fill_bytes(&mut secret);
+
+    let shares = split_bytes(&secret, 4, 2, &mut rng).unwrap();
+    // 4 字节长度前缀加 64 字节秘密共需 3 个分块
+    assert_eq!(shares[0].values().len(), 3);
+    assert_eq!(reconstruct_bytes(&[shares[3].clone(), shares[1].clone()]).unwrap(), secret.to_vec());
+}
+
+#[test]
+fn test_split_bytes_edge_cases() {
+    let mut rng = OsRng;
+
+    let shares = split_bytes(&[], 3, 2, &mut rng).unwrap();
+    assert_eq!(reconstruct_bytes(&shares[0..2]).unwrap(), Vec::<u8>::new());
+
+    assert_eq!(split_bytes(b"key", 2, 3, &mut rng), Err(ShareError::ThresholdExceedsShares));
+    assert_eq!(reconstruct_bytes(&[]), Err(ReconstructError::InsufficientShares));
+}
@@ -0,0 +1,76 @@
+#![cfg(any(feature = "cbor", feature = "bincode"))]
+
+use rand::rngs::OsRng;
+#[cfg(feature = "bincode")]
+use shamir_secret_sharing::codec::{from_bincode, to_bincode};
+#[cfg(feature = "cbor")]
+use shamir_secret_sharing::codec::{from_cbor, to_cbor};
+use shamir_secret_sharing::codec::{CodecError, Commitments};
+use shamir_secret_sharing::secret_sharing::generate_shares_with_feldman_vss;
+use shamir_secret_sharing::share::ShareSet;
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+fn sample() -> (ShareSet, Commitments) {
+    let secret = Scalar::random(&mut OsRng);
+    let (shares, commitments) = generate_shares_with_feldman_vss(secret, 5, 3, ProjectivePoint::GENERATOR, &mut OsRng);
+    (ShareSet::new(shares), Commitments(commitments))
+}
+
+#[test]
+#[cfg(feature = "cbor")]
+fn test_cbor_round_trip() {
+    let (set, commitments) = sample();
+    assert_eq!(from_cbor::<ShareSet>(&to_cbor(&set).unwrap()).unwrap(), set);
+    assert_eq!(from_cbor::<Commitments>(&to_cbor(&commitments).unwrap()).unwrap(), commitments);
+}
+
+#[test]
+#[cfg(feature = "bincode")]
+fn test_bincode_round_trip() {
+    let (set, commitments) = sample();
+    let encoded = to_bincode(&set).unwrap();
+    assert_eq!(from_bincode::<ShareSet>(&encoded).unwrap(), set);
+    // 编码是确定的
+    assert_eq!(to_bincode(&set).unwrap(), encoded);
+    assert_eq!(from_bincode::<Commitments>(&to_bincode(&commitments).unwrap()).unwrap(), commitments);
+
+    let mut trailing = encoded.clone();
+    trailing.push(0);
+    assert_eq!(from_bincode::<ShareSet>(&trailing), Err(CodecError::Decode));
+    assert_eq!(from_bincode::<ShareSet>(&encoded[..encoded.len() - 1]), Err(CodecError::Decode));
+}
+
+#[test]
+#[cfg(all(feature = "cbor", feature = "bincode"))]
+fn test_codecs_decode_identically() {
+    let (set, commitments) = sample();
+    let via_cbor: ShareSet = from_cbor(&to_cbor(&set).unwrap()).unwrap();
+    let via_bincode: ShareSet = from_bincode(&to_bincode(&set).unwrap()).unwrap();
+    assert_eq!(via_cbor, via_bincode);
+    assert_eq!(via_cbor, ShareSet::from_bytes(&set.to_bytes()).unwrap());
+
+    let via_cbor: Commitments = from_cbor(&to_cbor(&commitments).unwrap()).unwrap();
+    let via_bincode: Commitments = from_bincode(&to_bincode(&commitments).unwrap()).unwrap();
+    assert_eq!(via_cbor, via_bincode);
+}
+
+#[test]
+#[cfg(feature = "cbor")]
+fn test_decode_rejects_invalid_content() {
+    // CBOR 本身合法，但内容不是份额集合的编码
+    let bogus = to_cbor(&b"not a share set".to_vec()).unwrap();
+    assert_eq!(from_cbor::<ShareSet>(&bogus), Err(CodecError::Decode));
+    let (_, commitments) = sample();
+    let encoded = to_cbor(&commitments).unwrap();
+    assert_eq!(from_cbor::<ShareSet>(&encoded), Err(CodecError::Decode));
+}
+
+#[test]
+#[cfg(feature = "cbor")]
+fn test_cbor_huge_length_hint_is_rejected() {
+    // 声明长度为 2^44 的 CBOR 数组，不能按长度提示预分配内存
+    let hostile = [0x9b, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(from_cbor::<Commitments>(&hostile), Err(CodecError::Decode));
+    assert_eq!(from_cbor::<ShareSet>(&hostile), Err(CodecError::Decode));
+}
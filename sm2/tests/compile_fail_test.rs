@@ -0,0 +1,5 @@
+#[test]
+fn test_non_crypto_rng_is_rejected() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,113 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::dkg::{combine_public_key, cross_check_subshares, DkgError, DkgParticipant};
+use shamir_secret_sharing::secret_sharing::{reconstruct_secret, MAX_THRESHOLD};
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_dkg_three_parties() {
+    let mut rng = OsRng;
+    let (n, t) = (3, 2);
+    let mut participants: Vec<DkgParticipant> = (1..=n).map(|i| DkgParticipant::new(i, n, t, &mut rng)).collect();
+    let all_commitments: Vec<Vec<ProjectivePoint>> = participants.iter().map(|p| p.commitments().to_vec()).collect();
+
+    // 每个参与者把子份额发给其他所有参与者
+    for dealer in 1..=n {
+        for recipient in 1..=n {
+            if dealer == recipient {
+                continue;
+            }
+            let subshare = participants[dealer - 1].subshare_for(recipient).unwrap();
+            participants[recipient - 1]
+                .receive_subshare(dealer, subshare, &all_commitments[dealer - 1])
+                .unwrap();
+        }
+    }
+
+    let final_shares: Vec<(Scalar, Scalar)> = participants.iter().map(|p| p.finalize().unwrap()).collect();
+    let public_key = combine_public_key(&all_commitments);
+
+    // 任意 t 个最终份额恢复的联合私钥与联合公钥对应
+    for pair in [[0, 1], [0, 2], [1, 2]] {
+        let shares: Vec<(Scalar, Scalar)> = pair.iter().map(|&i| final_shares[i]).collect();
+        assert_eq!(ProjectivePoint::GENERATOR * reconstruct_secret(&shares), public_key);
+    }
+}
+
+#[test]
+fn test_dkg_rejects_bad_subshares() {
+    let mut rng = OsRng;
+    let mut alice = DkgParticipant::new(1, 3, 2, &mut rng);
+    let bob = DkgParticipant::new(2, 3, 2, &mut rng);
+
+    // 篡改的子份额
+    let bad = bob.subshare_for(1).unwrap() + Scalar::ONE;
+    assert_eq!(
+        alice.receive_subshare(2, bad, bob.commitments()),
+        Err(DkgError::InvalidSubshare { dealer: 2 })
+    );
+    // 截断的承诺
+    assert_eq!(
+        alice.receive_subshare(2, bob.subshare_for(1).unwrap(), &bob.commitments()[..1]),
+        Err(DkgError::InvalidCommitments { dealer: 2 })
+    );
+    assert_eq!(
+        alice.receive_subshare(1, alice.subshare_for(1).unwrap(), bob.commitments()),
+        Err(DkgError::UnknownDealer { dealer: 1 })
+    );
+    assert_eq!(
+        alice.receive_subshare(4, bob.subshare_for(1).unwrap(), bob.commitments()),
+        Err(DkgError::UnknownDealer { dealer: 4 })
+    );
+
+    alice.receive_subshare(2, bob.subshare_for(1).unwrap(), bob.commitments()).unwrap();
+    assert_eq!(
+        alice.receive_subshare(2, bob.subshare_for(1).unwrap(), bob.commitments()),
+        Err(DkgError::DuplicateDealer { dealer: 2 })
+    );
+    assert_eq!(alice.finalize(), Err(DkgError::MissingSubshares { received: 1, expected: 2 }));
+
+    // 编号 0 处的子份额就是 bob 的秘密贡献
+    assert_eq!(bob.subshare_for(0), Err(DkgError::ZeroShareIndex));
+}
+
+#[test]
+fn test_cross_check_subshares_pinpoints_malicious_dealer() {
+    let mut rng = OsRng;
+    let (n, t) = (3, 2);
+    let dealers: Vec<DkgParticipant> = (1..=n).map(|i| DkgParticipant::new(i, n, t, &mut rng)).collect();
+    let all_commitments: Vec<Vec<ProjectivePoint>> = dealers.iter().map(|p| p.commitments().to_vec()).collect();
+
+    // 诚实的分发者
+    for recipient in 1..=n {
+        let received: Vec<(usize, Scalar)> =
+            dealers.iter().map(|d| (d.index(), d.subshare_for(recipient).unwrap())).collect();
+        assert!(cross_check_subshares(recipient, t, &received, &all_commitments).is_empty());
+    }
+
+    // 分发者 2 给参与者 3 发送了与其承诺不符的子份额
+    let received: Vec<(usize, Scalar)> = dealers
+        .iter()
+        .map(|d| {
+            let subshare = d.subshare_for(3).unwrap();
+            (d.index(), if d.index() == 2 { subshare + Scalar::ONE } else { subshare })
+        })
+        .collect();
+    assert_eq!(cross_check_subshares(3, t, &received, &all_commitments), vec![2]);
+
+    // 未知的分发者和截断的承诺同样被报告
+    let mut truncated = all_commitments.clone();
+    truncated[0].truncate(1);
+    let received = vec![
+        (1, dealers[0].subshare_for(3).unwrap()),
+        (3, dealers[2].subshare_for(3).unwrap()),
+        (4, Scalar::ONE),
+    ];
+    assert_eq!(cross_check_subshares(3, t, &received, &truncated), vec![1, 4]);
+}
+
+#[test]
+#[should_panic(expected = "threshold must be between 1 and MAX_THRESHOLD")]
+fn test_dkg_rejects_threshold_above_maximum() {
+    let n = MAX_THRESHOLD + 1;
+    DkgParticipant::new(1, n, n, &mut OsRng);
+}
@@ -0,0 +1,61 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::dual_threshold::{detect_tampering, generate_dual_threshold, DualThresholdError};
+use shamir_secret_sharing::error::{ReconstructError, ShareError};
+use shamir_secret_sharing::secret_sharing::reconstruct_secret;
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_detect_tampering_with_d_shares() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_dual_threshold(secret, 7, 5, 3, &mut OsRng).unwrap();
+    assert_eq!(detect_tampering(&shares[..3], 3), Ok(false));
+    assert_eq!(detect_tampering(&shares[2..6], 3), Ok(false));
+    assert_eq!(detect_tampering(&shares, 3), Ok(false));
+
+    // 分别篡改 d 个份额中某一个的值、校验值和标签
+    let mut tampered = shares[..3].to_vec();
+    tampered[1].value += Scalar::ONE;
+    assert_eq!(detect_tampering(&tampered, 3), Ok(true));
+
+    let mut tampered = shares[..3].to_vec();
+    tampered[0].check += Scalar::ONE;
+    assert_eq!(detect_tampering(&tampered, 3), Ok(true));
+
+    let mut tampered = shares[..3].to_vec();
+    tampered[2].tag += Scalar::ONE;
+    assert_eq!(detect_tampering(&tampered, 3), Ok(true));
+}
+
+#[test]
+fn test_reconstruct_with_t_shares() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_dual_threshold(secret, 7, 5, 3, &mut OsRng).unwrap();
+    let plain: Vec<(Scalar, Scalar)> = shares.iter().map(|share| share.share()).collect();
+    assert_eq!(reconstruct_secret(&plain[2..]), secret);
+    assert_ne!(reconstruct_secret(&plain[..4]), secret);
+}
+
+#[test]
+fn test_dual_threshold_invalid_parameters() {
+    let secret = Scalar::ONE;
+    assert_eq!(
+        generate_dual_threshold(secret, 5, 3, 1, &mut OsRng),
+        Err(DualThresholdError::InvalidCheckThreshold)
+    );
+    assert_eq!(
+        generate_dual_threshold(secret, 5, 3, 4, &mut OsRng),
+        Err(DualThresholdError::InvalidCheckThreshold)
+    );
+    assert_eq!(
+        generate_dual_threshold(secret, 2, 3, 2, &mut OsRng),
+        Err(DualThresholdError::Invalid(ShareError::ThresholdExceedsShares))
+    );
+
+    let shares = generate_dual_threshold(secret, 5, 3, 3, &mut OsRng).unwrap();
+    assert_eq!(detect_tampering(&shares[..2], 3), Err(ReconstructError::InsufficientShares));
+    assert_eq!(
+        detect_tampering(&[shares[0], shares[1], shares[0]], 3),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
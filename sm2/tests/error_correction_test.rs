@@ -0,0 +1,51 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::ReconstructError;
+use shamir_secret_sharing::error_correction::reconstruct_with_error_correction;
+use shamir_secret_sharing::secret_sharing::generate_shares;
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_error_correction_recovers_from_corrupt_share() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+
+    // 5 个份额、t = 2，最多可纠正 1 个错误
+    let mut shares = generate_shares(secret, 5, 2, &mut rng);
+    assert_eq!(reconstruct_with_error_correction(&shares, 2, 1), Ok(secret));
+
+    for bad in 0..5 {
+        let mut corrupted = shares.clone();
+        corrupted[bad].1 = Scalar::random(&mut rng);
+        assert_eq!(reconstruct_with_error_correction(&corrupted, 2, 1), Ok(secret));
+    }
+
+    // 更高的门限值：9 个份额、t = 3，纠正 3 个错误
+    shares = generate_shares(secret, 9, 3, &mut rng);
+    for i in [0, 4, 8] {
+        shares[i].1 += Scalar::ONE;
+    }
+    assert_eq!(reconstruct_with_error_correction(&shares, 3, 3), Ok(secret));
+}
+
+#[test]
+fn test_error_correction_fails_gracefully() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let mut shares = generate_shares(secret, 5, 2, &mut rng);
+
+    // 冗余度不足：5 < 2 + 2 * 2
+    assert_eq!(
+        reconstruct_with_error_correction(&shares, 2, 2),
+        Err(ReconstructError::InsufficientShares)
+    );
+
+    // 错误份额多于 max_errors
+    for share in shares.iter_mut().take(3) {
+        share.1 = Scalar::random(&mut rng);
+    }
+    assert_eq!(
+        reconstruct_with_error_correction(&shares, 2, 1),
+        Err(ReconstructError::TooManyErrors)
+    );
+}
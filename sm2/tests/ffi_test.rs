@@ -0,0 +1,80 @@
+#![cfg(feature = "capi")]
+
+use std::ptr;
+use shamir_secret_sharing::ffi::{
+    ss_generate_shares, ss_reconstruct, SS_ERR_BUFFER_TOO_SMALL, SS_ERR_DUPLICATE_SHARE, SS_ERR_INVALID_SHARES,
+    SS_ERR_INVALID_THRESHOLD, SS_ERR_NON_CANONICAL, SS_ERR_NULL_POINTER, SS_OK,
+};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_ffi_round_trip() {
+    let secret = Scalar::random(&mut rand::thread_rng()).to_bytes();
+    let mut buf = vec![0u8; 5 * 64];
+    let status = unsafe { ss_generate_shares(secret.as_ptr(), 5, 3, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(status, SS_OK);
+
+    // 任取 3 条记录恢复秘密
+    let mut out = [0u8; 32];
+    let status = unsafe { ss_reconstruct(buf[64..].as_ptr(), 3, out.as_mut_ptr()) };
+    assert_eq!(status, SS_OK);
+    assert_eq!(&out[..], &secret[..]);
+}
+
+#[test]
+fn test_ffi_error_codes() {
+    let secret = Scalar::ONE.to_bytes();
+    let mut buf = vec![0u8; 3 * 64];
+    let mut out = [0u8; 32];
+    unsafe {
+        assert_eq!(ss_generate_shares(ptr::null(), 3, 2, buf.as_mut_ptr(), buf.len()), SS_ERR_NULL_POINTER);
+        assert_eq!(ss_generate_shares(secret.as_ptr(), 3, 2, ptr::null_mut(), buf.len()), SS_ERR_NULL_POINTER);
+        assert_eq!(ss_generate_shares(secret.as_ptr(), 3, 4, buf.as_mut_ptr(), buf.len()), SS_ERR_INVALID_THRESHOLD);
+        assert_eq!(ss_generate_shares(secret.as_ptr(), 4, 2, buf.as_mut_ptr(), buf.len()), SS_ERR_BUFFER_TOO_SMALL);
+        assert_eq!(ss_generate_shares([0xff; 32].as_ptr(), 3, 2, buf.as_mut_ptr(), buf.len()), SS_ERR_NON_CANONICAL);
+
+        assert_eq!(ss_generate_shares(secret.as_ptr(), 3, 2, buf.as_mut_ptr(), buf.len()), SS_OK);
+        assert_eq!(ss_reconstruct(ptr::null(), 2, out.as_mut_ptr()), SS_ERR_NULL_POINTER);
+        assert_eq!(ss_reconstruct(buf.as_ptr(), 2, ptr::null_mut()), SS_ERR_NULL_POINTER);
+
+        // 同一条记录出现两次
+        let mut duplicated = buf[..64].to_vec();
+        duplicated.extend_from_slice(&buf[..64]);
+        assert_eq!(ss_reconstruct(duplicated.as_ptr(), 2, out.as_mut_ptr()), SS_ERR_DUPLICATE_SHARE);
+
+        buf[32..64].fill(0xff);
+        assert_eq!(ss_reconstruct(buf.as_ptr(), 2, out.as_mut_ptr()), SS_ERR_NON_CANONICAL);
+    }
+    // 失败时不写入输出
+    assert_eq!(out, [0u8; 32]);
+}
+
+#[test]
+fn test_header_matches_rust_definitions() {
+    // 头文件是手工维护的，逐项核对其中的错误码和函数声明
+    let header = include_str!("../include/shamir_secret_sharing.h");
+    let defines: Vec<(&str, i32)> = header
+        .lines()
+        .filter_map(|line| line.strip_prefix("#define SS_"))
+        .map(|rest| {
+            let (name, value) = rest.split_once(' ').unwrap();
+            (name, value.trim().parse().unwrap())
+        })
+        .collect();
+    let expected = [
+        ("OK", SS_OK),
+        ("ERR_NULL_POINTER", SS_ERR_NULL_POINTER),
+        ("ERR_INVALID_THRESHOLD", SS_ERR_INVALID_THRESHOLD),
+        ("ERR_BUFFER_TOO_SMALL", SS_ERR_BUFFER_TOO_SMALL),
+        ("ERR_NON_CANONICAL", SS_ERR_NON_CANONICAL),
+        ("ERR_DUPLICATE_SHARE", SS_ERR_DUPLICATE_SHARE),
+        ("ERR_INVALID_SHARES", SS_ERR_INVALID_SHARES),
+    ];
+    assert_eq!(defines, expected);
+
+    assert!(header.contains(
+        "int ss_generate_shares(const uint8_t *secret_bytes, size_t n, size_t t, uint8_t *out_buf, size_t out_len);"
+    ));
+    assert!(header.contains("int ss_reconstruct(const uint8_t *shares_bytes, size_t count, uint8_t *out_secret);"));
+}
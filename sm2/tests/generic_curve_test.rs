@@ -0,0 +1,51 @@
+use rand::rngs::OsRng;
+use p256::elliptic_curve::ff::Field;
+use p256::elliptic_curve::group::Group;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::{SecretSharing, Sm2SecretSharing};
+
+type P256SecretSharing = SecretSharing<p256::ProjectivePoint>;
+
+#[test]
+fn test_shamir_and_vss_on_p256() {
+    let mut rng = OsRng;
+    let g = p256::ProjectivePoint::GENERATOR;
+    let h = p256::ProjectivePoint::random(&mut rng);
+    let secret = p256::Scalar::random(&mut rng);
+
+    // Shamir
+    let shares = P256SecretSharing::generate_shares(secret, 5, 3, &mut rng);
+    assert_eq!(P256SecretSharing::reconstruct_secret(&shares[1..4]), secret);
+
+    // Feldman
+    let (shares, commitments) = P256SecretSharing::generate_shares_with_feldman_vss(secret, 5, 3, g, &mut rng);
+    for share in &shares {
+        assert!(P256SecretSharing::verify_share_with_feldman_vss(*share, &commitments, g));
+    }
+    let mut tampered = shares[0];
+    tampered.1 += p256::Scalar::ONE;
+    assert!(!P256SecretSharing::verify_share_with_feldman_vss(tampered, &commitments, g));
+
+    // Pedersen
+    let (shares, commitments, blinding_values) =
+        P256SecretSharing::generate_shares_with_pedersen_vss(secret, 5, 3, g, h, &mut rng);
+    for (share, blinding) in shares.iter().zip(&blinding_values) {
+        assert!(P256SecretSharing::verify_share_with_pedersen_vss(*share, blinding.1, &commitments, g, h));
+    }
+    assert_eq!(P256SecretSharing::reconstruct_secret(&shares[2..5]), secret);
+
+    // 多项式同样可以定义在 P-256 的标量域上
+    let poly = Polynomial::new(secret, 2, &mut rng);
+    let points: Vec<(p256::Scalar, p256::Scalar)> = (1..=3u64)
+        .map(|i| (p256::Scalar::from(i), poly.evaluate(p256::Scalar::from(i))))
+        .collect();
+    assert_eq!(Polynomial::lagrange_interpolate(&points).unwrap().coefficients(), poly.coefficients());
+}
+
+#[test]
+fn test_sm2_alias_matches_concrete_api() {
+    let mut rng = OsRng;
+    let secret = sm2::Scalar::random(&mut rng);
+    let shares = Sm2SecretSharing::generate_shares(secret, 4, 2, &mut rng);
+    assert_eq!(Sm2SecretSharing::reconstruct_secret(&shares[2..4]), secret);
+}
@@ -0,0 +1,97 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::gf256::{combine_gf256, inv, mul, split_gf256, Gf256Error};
+use shamir_secret_sharing::error::ShareError;
+
+#[test]
+fn test_gf256_field_vectors() {
+    // FIPS-197 第 4.2 节的乘法示例
+    assert_eq!(mul(0x57, 0x83), 0xc1);
+    assert_eq!(mul(0x57, 0x13), 0xfe);
+    // FIPS-197 第 5.1.1 节：{53} 的逆元为 {ca}
+    assert_eq!(inv(0x53), Some(0xca));
+    // Vault shamir 包的域运算测试
+    assert_eq!(mul(3, 7), 9);
+    assert_eq!(mul(3, 0), 0);
+    assert_eq!(mul(6, inv(3).unwrap()), 2);
+    assert_eq!(inv(0), None);
+    for a in 1..=255u8 {
+        assert_eq!(mul(a, inv(a).unwrap()), 1);
+    }
+}
+
+#[test]
+fn test_combine_gf256_known_vector() {
+    // f(x) = 0x42 + 0x57 * x，由 FIPS-197 的乘积得到 f(0x83) = 0x42 ^ 0xc1，f(0x13) = 0x42 ^ 0xfe
+    let shares = [vec![0x83, 0x83], vec![0xbc, 0x13]];
+    assert_eq!(combine_gf256(&shares).unwrap(), vec![0x42]);
+}
+
+#[test]
+fn test_vault_combine_all_orderings() {
+    // 移植自 hashicorp/vault shamir/shamir_test.go 的 TestCombine：
+    // Split("test", 5, 3) 后，任意 3 个不同份额的全部 60 种排列都能恢复秘密
+    let secret = b"test";
+    let shares = split_gf256(secret, 5, 3, &mut OsRng).unwrap();
+    for i in 0..5 {
+        for j in (0..5).filter(|&j| j != i) {
+            for k in (0..5).filter(|&k| k != i && k != j) {
+                let parts = [&shares[i], &shares[j], &shares[k]];
+                assert_eq!(combine_gf256(&parts).unwrap(), secret);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_vault_combine_invalid() {
+    // 移植自 hashicorp/vault shamir/shamir_test.go 的 TestCombine_invalid，份额按 Vault 的格式
+    // 解释为 y 值后跟 1 字节的 x 坐标
+    assert_eq!(combine_gf256(&[b"foo".to_vec(), b"ba".to_vec()]), Err(Gf256Error::LengthMismatch));
+    assert_eq!(combine_gf256(&[b"f".to_vec(), b"b".to_vec()]), Err(Gf256Error::MalformedShare));
+    assert_eq!(combine_gf256(&[b"foo".to_vec(), b"foo".to_vec()]), Err(Gf256Error::DuplicateShareIndex));
+}
+
+#[test]
+fn test_split_combine_gf256_multi_byte() {
+    let secret = b"correct horse battery staple \x00\xff";
+    let shares = split_gf256(secret, 5, 3, &mut OsRng).unwrap();
+    assert_eq!(shares.len(), 5);
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.len(), secret.len() + 1);
+        assert_eq!(share[secret.len()], i as u8 + 1);
+    }
+    assert_eq!(combine_gf256(&shares[..3]).unwrap(), secret);
+    assert_eq!(combine_gf256(&[&shares[4], &shares[1], &shares[2]]).unwrap(), secret);
+    assert_eq!(combine_gf256(&shares).unwrap(), secret);
+}
+
+#[test]
+fn test_split_combine_gf256_single_byte() {
+    for &byte in &[0x00u8, 0x01, 0x80, 0xff] {
+        let shares = split_gf256(&[byte], 255, 2, &mut OsRng).unwrap();
+        assert_eq!(combine_gf256(&shares[253..]).unwrap(), vec![byte]);
+
+        // t = 1 时每个份额的 y 值就是秘密本身
+        let shares = split_gf256(&[byte], 3, 1, &mut OsRng).unwrap();
+        assert!(shares.iter().all(|share| share[0] == byte));
+        assert_eq!(combine_gf256(&shares[2..]).unwrap(), vec![byte]);
+    }
+}
+
+#[test]
+fn test_split_gf256_invalid_parameters() {
+    assert_eq!(split_gf256(&[], 3, 2, &mut OsRng), Err(Gf256Error::EmptySecret));
+    assert_eq!(split_gf256(b"x", 256, 2, &mut OsRng), Err(Gf256Error::TooManyShares));
+    assert_eq!(split_gf256(b"x", 3, 4, &mut OsRng), Err(Gf256Error::Invalid(ShareError::ThresholdExceedsShares)));
+    assert_eq!(split_gf256(b"x", 3, 0, &mut OsRng), Err(Gf256Error::Invalid(ShareError::ZeroThreshold)));
+}
+
+#[test]
+fn test_combine_gf256_rejects_malformed_shares() {
+    let empty: [Vec<u8>; 0] = [];
+    assert_eq!(combine_gf256(&empty), Err(Gf256Error::InsufficientShares));
+    assert_eq!(combine_gf256(&[vec![1u8]]), Err(Gf256Error::MalformedShare));
+    assert_eq!(combine_gf256(&[vec![1u8, 1], vec![2, 3, 2]]), Err(Gf256Error::LengthMismatch));
+    assert_eq!(combine_gf256(&[vec![1u8, 0]]), Err(Gf256Error::ZeroShareIndex));
+    assert_eq!(combine_gf256(&[vec![1u8, 1], vec![2, 1]]), Err(Gf256Error::DuplicateShareIndex));
+}
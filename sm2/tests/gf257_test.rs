@@ -0,0 +1,73 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::ShareError;
+use shamir_secret_sharing::gf257::Gf257;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret, try_generate_shares};
+use sm2::elliptic_curve::ff::{Field, PrimeField};
+
+#[test]
+fn test_gf257_field_arithmetic() {
+    assert_eq!(Gf257::new(200) + Gf257::new(100), Gf257::new(43));
+    assert_eq!(Gf257::new(3) - Gf257::new(5), Gf257::new(255));
+    assert_eq!(Gf257::new(3) * Gf257::ROOT_OF_UNITY_INV, Gf257::ONE);
+    assert_eq!(Gf257::new(2) * Gf257::TWO_INV, Gf257::ONE);
+    for v in 1..257 {
+        let a = Gf257::new(v);
+        assert_eq!(a * a.invert().unwrap(), Gf257::ONE);
+        let square = a.square();
+        assert_eq!(square.sqrt().unwrap().square(), square);
+    }
+    assert!(bool::from(Gf257::ZERO.invert().is_none()));
+    // 3 的阶为 256
+    assert_eq!(Gf257::MULTIPLICATIVE_GENERATOR.pow([128]), -Gf257::ONE);
+    assert!(bool::from(Gf257::from_repr([0x01, 0x01]).is_none()));
+    assert_eq!(Gf257::from_repr(Gf257::new(256).to_repr()).unwrap(), Gf257::new(256));
+}
+
+#[test]
+fn test_gf257_known_vector() {
+    // f(x) = 42 + 7x + 3x^2 (mod 257)
+    let poly = Polynomial::from_coefficients(vec![Gf257::new(42), Gf257::new(7), Gf257::new(3)]);
+    let expected = [(1, 52), (2, 68), (3, 90), (4, 118), (5, 152)];
+    let shares: Vec<(Gf257, Gf257)> = expected
+        .iter()
+        .map(|&(x, y)| {
+            assert_eq!(poly.evaluate(Gf257::new(x)), Gf257::new(y));
+            (Gf257::new(x), Gf257::new(y))
+        })
+        .collect();
+    assert_eq!(reconstruct_secret(&shares[..3]), Gf257::new(42));
+    assert_eq!(reconstruct_secret(&shares[2..]), Gf257::new(42));
+}
+
+#[test]
+fn test_gf257_bytewise_sharing() {
+    let secret = b"toy";
+    let shares: Vec<Vec<(Gf257, Gf257)>> = secret
+        .iter()
+        .map(|&b| generate_shares(Gf257::new(b as u16), 5, 3, &mut OsRng))
+        .collect();
+    let recovered: Vec<u8> = shares
+        .iter()
+        .map(|byte_shares| reconstruct_secret(&byte_shares[1..4]).value() as u8)
+        .collect();
+    assert_eq!(recovered, secret);
+}
+
+#[test]
+fn test_gf257_share_count_is_bounded_by_field_order() {
+    // x = 257 在 GF(257) 中回绕为 0，第 257 个份额就是秘密本身
+    let secret = Gf257::new(7);
+    assert_eq!(try_generate_shares(secret, 257, 3, &mut OsRng), Err(ShareError::ZeroShareIndex));
+    assert_eq!(try_generate_shares(secret, 300, 3, &mut OsRng), Err(ShareError::ZeroShareIndex));
+
+    let shares = try_generate_shares(secret, 256, 3, &mut OsRng).unwrap();
+    assert_eq!(shares[255].0, Gf257::new(256));
+    assert_eq!(reconstruct_secret(&shares[253..]), secret);
+}
+
+#[test]
+#[should_panic(expected = "n must be smaller than the field order")]
+fn test_gf257_generate_shares_panics_at_field_order() {
+    generate_shares(Gf257::new(7), 257, 3, &mut OsRng);
+}
@@ -0,0 +1,36 @@
+use shamir_secret_sharing::hash::{hash_to_curve, hash_to_scalar};
+use sm2::elliptic_curve::ff::PrimeField;
+use sm2::elliptic_curve::group::Group;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_hash_to_scalar() {
+    let a = hash_to_scalar(b"test/domain-a", b"message");
+    assert_eq!(a, hash_to_scalar(b"test/domain-a", b"message"));
+    // 域分隔
+    assert_ne!(a, hash_to_scalar(b"test/domain-b", b"message"));
+    assert_ne!(a, hash_to_scalar(b"test/domain-a", b"other message"));
+    // 长度前缀使边界不同的输入互不相同
+    assert_ne!(hash_to_scalar(b"ab", b"c"), hash_to_scalar(b"a", b"bc"));
+
+    for i in 0u32..64 {
+        let scalar = hash_to_scalar(b"test/scalars", &i.to_be_bytes());
+        assert!(!bool::from(scalar.is_zero()));
+        // 规范编码：重新解析得到相同的标量
+        assert_eq!(Option::<Scalar>::from(Scalar::from_repr(scalar.to_repr())), Some(scalar));
+    }
+}
+
+#[test]
+fn test_hash_to_curve() {
+    let p = hash_to_curve(b"test/domain-a", b"message");
+    assert_eq!(p, hash_to_curve(b"test/domain-a", b"message"));
+    assert_ne!(p, hash_to_curve(b"test/domain-b", b"message"));
+    assert_ne!(p, hash_to_curve(b"test/domain-a", b"other message"));
+    assert_ne!(hash_to_curve(b"ab", b"c"), hash_to_curve(b"a", b"bc"));
+    assert_ne!(p, ProjectivePoint::GENERATOR);
+
+    for i in 0u32..16 {
+        assert!(!bool::from(hash_to_curve(b"test/points", &i.to_be_bytes()).is_identity()));
+    }
+}
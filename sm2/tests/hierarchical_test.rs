@@ -0,0 +1,42 @@
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+use shamir_secret_sharing::error::ReconstructError;
+use shamir_secret_sharing::secret_sharing::MAX_THRESHOLD;
+use shamir_secret_sharing::hierarchical::{
+    generate_hierarchical_shares, reconstruct_hierarchical_secret, HierarchicalShare,
+};
+
+#[test]
+fn test_two_level_policy() {
+    let mut rng = rand::thread_rng();
+    let secret = Scalar::random(&mut rng);
+    // 2 位董事、4 位经理；需要 3 人且其中至少 1 位董事
+    let levels = [(2, 1), (4, 3)];
+    let shares = generate_hierarchical_shares(secret, &levels, &mut rng);
+    assert_eq!(shares.len(), 6);
+    assert!(shares[..2].iter().all(|s| s.level == 0 && s.derivative == 0));
+    assert!(shares[2..].iter().all(|s| s.level == 1 && s.derivative == 1));
+
+    let pick = |indices: &[usize]| -> Vec<HierarchicalShare> {
+        indices.iter().map(|&i| shares[i]).collect()
+    };
+
+    // 授权集合：至少 1 位董事，共 3 人
+    for authorized in [&[0, 2, 3][..], &[1, 4, 5], &[0, 1, 2], &[0, 1, 5], &[0, 2, 3, 4]] {
+        assert_eq!(reconstruct_hierarchical_secret(&pick(authorized), 3), Ok(secret));
+    }
+
+    // 非授权集合：没有董事，或人数不足
+    for unauthorized in [&[2, 3, 4][..], &[2, 3, 4, 5], &[0, 2], &[0, 1]] {
+        assert_eq!(
+            reconstruct_hierarchical_secret(&pick(unauthorized), 3),
+            Err(ReconstructError::InsufficientShares)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "threshold must be between 1 and MAX_THRESHOLD")]
+fn test_hierarchical_rejects_threshold_above_maximum() {
+    generate_hierarchical_shares(Scalar::ONE, &[(1, 1), (1, MAX_THRESHOLD + 1)], &mut rand::thread_rng());
+}
@@ -0,0 +1,55 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::ipa::verify_open;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::generate_shares_with_polynomial;
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_ipa_open_share_values() {
+    let mut rng = OsRng;
+    for t in [1, 2, 3, 4, 5, 8] {
+        let (shares, poly) = generate_shares_with_polynomial(Scalar::random(&mut rng), 6.max(t), t, &mut rng);
+        let (commitment, blinding) = poly.commit_ipa(&mut rng);
+        for &(x, y) in &shares {
+            let (value, proof) = poly.open(blinding, x, &mut rng);
+            assert_eq!(value, y);
+            assert_eq!(proof.l.len(), t.next_power_of_two().trailing_zeros() as usize);
+            assert!(verify_open(commitment, t, x, value, &proof));
+        }
+    }
+}
+
+#[test]
+fn test_ipa_rejects_wrong_openings() {
+    let mut rng = OsRng;
+    let t = 3;
+    let poly = Polynomial::new(Scalar::random(&mut rng), t - 1, &mut rng);
+    let (commitment, blinding) = poly.commit_ipa(&mut rng);
+    let x = Scalar::from(2u64);
+    let (value, proof) = poly.open(blinding, x, &mut rng);
+    assert!(verify_open(commitment, t, x, value, &proof));
+
+    // 错误的值
+    assert!(!verify_open(commitment, t, x, value + Scalar::ONE, &proof));
+    // 其他点
+    assert!(!verify_open(commitment, t, Scalar::from(3u64), value, &proof));
+    // 其他承诺
+    let (other, _) = poly.commit_ipa(&mut rng);
+    assert!(!verify_open(other, t, x, value, &proof));
+    assert!(!verify_open(commitment + ProjectivePoint::GENERATOR, t, x, value, &proof));
+    // 篡改的证明
+    let mut tampered = proof.clone();
+    tampered.a += Scalar::ONE;
+    assert!(!verify_open(commitment, t, x, value, &tampered));
+    let mut tampered = proof.clone();
+    tampered.l[0] += ProjectivePoint::GENERATOR;
+    assert!(!verify_open(commitment, t, x, value, &tampered));
+    // 轮数与门限值不符
+    assert!(!verify_open(commitment, 5, x, value, &proof));
+    assert!(!verify_open(commitment, 0, x, value, &proof));
+
+    // 错误的盲化值得到的证明无法通过验证
+    let (value, proof) = poly.open(blinding + Scalar::ONE, x, &mut rng);
+    assert!(!verify_open(commitment, t, x, value, &proof));
+}
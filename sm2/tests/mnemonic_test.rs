@@ -0,0 +1,45 @@
+#![cfg(feature = "mnemonic")]
+
+use rand::rngs::OsRng;
+use shamir_secret_sharing::mnemonic::{share_from_mnemonic, share_to_mnemonic, MnemonicError, SHARE_MNEMONIC_WORDS};
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_mnemonic_round_trip() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_shares(secret, 3, 2, &mut OsRng);
+
+    let phrases: Vec<String> = shares.iter().map(share_to_mnemonic).collect();
+    assert!(phrases.iter().all(|p| p.split(' ').count() == SHARE_MNEMONIC_WORDS));
+
+    // 大写和多余的空白不影响解析
+    let messy = phrases[0].to_uppercase().replace(' ', " \n\t ");
+    let decoded = vec![share_from_mnemonic(&messy).unwrap(), share_from_mnemonic(&phrases[2]).unwrap()];
+    assert_eq!(decoded, vec![shares[0], shares[2]]);
+    assert_eq!(reconstruct_secret(&decoded), secret);
+}
+
+#[test]
+fn test_mnemonic_rejects_corrupted_words() {
+    // 固定的份额使校验和的检测结果确定
+    let share = (Scalar::from(7u64), Scalar::from(0x0123_4567_89ab_cdefu64));
+    let phrase = share_to_mnemonic(&share);
+    let mut words: Vec<&str> = phrase.split(' ').collect();
+
+    assert_eq!(
+        share_from_mnemonic(&words[1..].join(" ")),
+        Err(MnemonicError::WordCount { actual: SHARE_MNEMONIC_WORDS - 1 })
+    );
+
+    let original = words[30];
+    words[30] = "notaword";
+    assert_eq!(share_from_mnemonic(&words.join(" ")), Err(MnemonicError::UnknownWord { position: 30 }));
+
+    // 交换 y 部分的两个不同单词会破坏校验和
+    words[30] = original;
+    let j = (25..SHARE_MNEMONIC_WORDS).find(|&j| words[j] != words[24]).unwrap();
+    words.swap(24, j);
+    assert_eq!(share_from_mnemonic(&words.join(" ")), Err(MnemonicError::InvalidChecksum));
+}
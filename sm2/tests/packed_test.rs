@@ -0,0 +1,44 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::ShareError;
+use shamir_secret_sharing::gf257::Gf257;
+use shamir_secret_sharing::packed::{generate_packed_shares, reconstruct_packed};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_packed_three_secrets() {
+    let mut rng = OsRng;
+    let secrets: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+    // 隐私门限 t = 3，恢复需要 t + k - 1 = 5 个份额
+    let shares = generate_packed_shares(&secrets, 7, 3, &mut rng).unwrap();
+    assert_eq!(shares.len(), 7);
+
+    assert_eq!(reconstruct_packed(&shares[..5], 3).unwrap(), secrets);
+    assert_eq!(reconstruct_packed(&shares[2..], 3).unwrap(), secrets);
+    // 份额不足时得到的是错误的结果
+    assert_ne!(reconstruct_packed(&shares[..4], 3).unwrap(), secrets);
+}
+
+#[test]
+fn test_packed_rejects_too_few_shares() {
+    let secrets = [Scalar::ONE, Scalar::from(2u64)];
+    assert_eq!(
+        generate_packed_shares(&secrets, 3, 3, &mut OsRng),
+        Err(ShareError::ThresholdExceedsShares)
+    );
+    assert_eq!(generate_packed_shares(&secrets, 3, 0, &mut OsRng), Err(ShareError::ZeroThreshold));
+}
+
+#[test]
+fn test_packed_rejects_reserved_points_outside_small_field() {
+    let secrets = [Gf257::new(10), Gf257::new(20), Gf257::new(30)];
+    // 255 + 2 + 3 - 1 = 259 >= 257，保留点 -1..=-4 会与 x = 253..=256 重合
+    assert_eq!(
+        generate_packed_shares(&secrets, 255, 2, &mut OsRng),
+        Err(ShareError::DuplicateShareIndex)
+    );
+
+    // 252 + 2 + 3 - 1 = 256 < 257 时所有点互不相同
+    let shares = generate_packed_shares(&secrets, 252, 2, &mut OsRng).unwrap();
+    assert_eq!(reconstruct_packed(&shares[100..104], 3).unwrap(), secrets);
+}
@@ -0,0 +1,59 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::{ReconstructError, ShareError};
+use shamir_secret_sharing::participant::{generate_shares_for, participant_index, reconstruct_participant_shares};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+fn names() -> Vec<String> {
+    ["alice", "bob", "carol", "dave", "erin"].iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_named_participants() {
+    let secret = Scalar::random(&mut OsRng);
+    let identities = names();
+    let shares = generate_shares_for(&identities, 3, secret, &mut OsRng).unwrap();
+    assert_eq!(shares.len(), 5);
+    for (share, identity) in shares.iter().zip(&identities) {
+        assert_eq!(&share.identity, identity);
+        assert_eq!(share.share.0, participant_index(identity));
+    }
+    assert_eq!(reconstruct_participant_shares(&shares[..3]), Ok(secret));
+    assert_eq!(reconstruct_participant_shares(&[shares[4].clone(), shares[0].clone(), shares[2].clone()]), Ok(secret));
+
+    // Debug 不输出份额的值
+    let debug = format!("{:?}", shares[0]);
+    assert!(debug.contains("alice"));
+    assert!(debug.ends_with("value: .. }"));
+}
+
+#[test]
+fn test_participant_indices_are_distinct_and_nonzero() {
+    let mut indices: Vec<Scalar> = names().iter().map(|name| participant_index(name)).collect();
+    assert!(indices.iter().all(|x| !bool::from(x.is_zero())));
+    assert_eq!(participant_index("alice"), indices[0]);
+    // hash_to_scalar("shamir_secret_sharing/participant_index", "alice")
+    let encoded: String = indices[0].to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(encoded, "a5bd6bae0a3d648b8546d3ca8aee1b91c3c18f9d07a211c6ba3c5c50f2a7734f");
+    let len = indices.len();
+    indices.sort_by_key(|x| x.to_bytes());
+    indices.dedup();
+    assert_eq!(indices.len(), len);
+}
+
+#[test]
+fn test_participant_shares_are_bound_to_identities() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_shares_for(&names(), 3, secret, &mut OsRng).unwrap();
+    let mut swapped = shares[..3].to_vec();
+    swapped[0].identity = "mallory".to_string();
+    assert_eq!(reconstruct_participant_shares(&swapped), Err(ReconstructError::InconsistentShares));
+    assert_eq!(
+        reconstruct_participant_shares(&[shares[1].clone(), shares[1].clone()]),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+    assert_eq!(reconstruct_participant_shares(&[]), Err(ReconstructError::InsufficientShares));
+
+    let duplicated = vec!["alice".to_string(), "bob".to_string(), "alice".to_string()];
+    assert_eq!(generate_shares_for(&duplicated, 2, secret, &mut OsRng), Err(ShareError::DuplicateShareIndex));
+}
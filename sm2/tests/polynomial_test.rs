@@ -1,6 +1,9 @@
+// 保留原有测试中 x.pow(&[..]) 的写法
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 use sm2::elliptic_curve::ff::Field;
 use sm2::{ProjectivePoint, Scalar, elliptic_curve::group::Group};
-use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::polynomial::{FeldmanCommitter, Polynomial};
 use rand::Rng;
 
 #[test]
@@ -143,3 +146,154 @@ fn test_feldman_commitment_with_random_degree() {
 
     println!("All random degree and point tests passed for Feldman commitment!");
 }
+
+#[test]
+fn test_feldman_committer_matches_naive() {
+    let mut rng = rand::thread_rng();
+    let g = ProjectivePoint::GENERATOR;
+    let committer = FeldmanCommitter::new(g);
+
+    // 边界标量
+    for k in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+        assert_eq!(committer.mul(&k), g * k);
+    }
+
+    let poly = Polynomial::new(Scalar::random(&mut rng), 16, &mut rng);
+    assert_eq!(committer.commit(&poly), poly.feldman_commit(g));
+
+    // 非标准生成元
+    let h = ProjectivePoint::random(&mut rng);
+    assert_eq!(FeldmanCommitter::new(h).commit(&poly), poly.feldman_commit(h));
+}
+
+#[test]
+fn test_lagrange_interpolate_recovers_polynomial() {
+    let mut rng = rand::thread_rng();
+    let degree = 4;
+    let poly = Polynomial::new(Scalar::random(&mut rng), degree, &mut rng);
+
+    let points: Vec<(Scalar, Scalar)> = (0..=degree)
+        .map(|_| {
+            let x = Scalar::random(&mut rng);
+            (x, poly.evaluate(x))
+        })
+        .collect();
+
+    let interpolated = Polynomial::lagrange_interpolate(&points).unwrap();
+    assert_eq!(interpolated.coefficients(), poly.coefficients());
+
+    let duplicated = vec![points[0], points[0]];
+    assert!(Polynomial::lagrange_interpolate(&duplicated).is_err());
+    assert!(Polynomial::<Scalar>::lagrange_interpolate(&[]).is_err());
+}
+
+#[test]
+fn test_polynomial_add_and_mul_scalar() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        // 次数不同的两个多项式，检验补零逻辑
+        let p = Polynomial::new(Scalar::random(&mut rng), rng.gen_range(0..8), &mut rng);
+        let q = Polynomial::new(Scalar::random(&mut rng), rng.gen_range(0..8), &mut rng);
+        let s = Scalar::random(&mut rng);
+        let x = Scalar::random(&mut rng);
+
+        let sum = p.add(&q);
+        assert_eq!(sum.coefficients().len(), p.coefficients().len().max(q.coefficients().len()));
+        assert_eq!(sum.evaluate(x), p.evaluate(x) + q.evaluate(x));
+        assert_eq!(q.add(&p).coefficients(), sum.coefficients());
+        assert_eq!(p.mul_scalar(s).evaluate(x), p.evaluate(x) * s);
+    }
+}
+
+#[test]
+fn test_polynomial_mul() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let p = Polynomial::new(Scalar::random(&mut rng), rng.gen_range(0..8), &mut rng);
+        let q = Polynomial::new(Scalar::random(&mut rng), rng.gen_range(0..8), &mut rng);
+        let x = Scalar::random(&mut rng);
+
+        let product = p.mul(&q);
+        assert_eq!(
+            product.coefficients().len(),
+            p.coefficients().len() + q.coefficients().len() - 1
+        );
+        assert_eq!(product.evaluate(x), p.evaluate(x) * q.evaluate(x));
+    }
+
+    // 与零多项式相乘，无论零多项式是否有系数，结果都恒为 0
+    let p = Polynomial::new(Scalar::random(&mut rng), 3, &mut rng);
+    let x = Scalar::random(&mut rng);
+    let zero = Polynomial::from_coefficients(vec![Scalar::ZERO]);
+    let empty = Polynomial::from_coefficients(Vec::new());
+    assert_eq!(p.mul(&zero).evaluate(x), Scalar::ZERO);
+    assert_eq!(zero.mul(&p).evaluate(x), Scalar::ZERO);
+    assert!(p.mul(&empty).coefficients().is_empty());
+}
+
+#[test]
+fn test_lagrange_interpolate_known_quadratic() {
+    // p(x) = 3 + 5x + 7x^2
+    let expected = [Scalar::from(3u64), Scalar::from(5u64), Scalar::from(7u64)];
+    let points = [
+        (Scalar::from(1u64), Scalar::from(15u64)),
+        (Scalar::from(2u64), Scalar::from(41u64)),
+        (Scalar::from(4u64), Scalar::from(135u64)),
+    ];
+
+    let poly = Polynomial::lagrange_interpolate(&points).unwrap();
+    assert_eq!(poly.coefficients().len(), 3);
+    for (coeff, expected) in poly.coefficients().iter().zip(&expected) {
+        assert_eq!(coeff, expected);
+    }
+}
+
+#[test]
+fn test_evaluate_many_matches_evaluate() {
+    let mut rng = rand::thread_rng();
+    for degree in [0, 1, 5, 30] {
+        let poly = Polynomial::new(Scalar::random(&mut rng), degree, &mut rng);
+
+        // 100 个随机点
+        let random: Vec<Scalar> = (0..100).map(|_| Scalar::random(&mut rng)).collect();
+        // 100 个等差数列上的点，使用差分表递推
+        let start = Scalar::random(&mut rng);
+        let step = Scalar::random(&mut rng);
+        let progression: Vec<Scalar> = (0..100u64).map(|k| start + step * Scalar::from(k)).collect();
+        let consecutive: Vec<Scalar> = (1..=100u64).map(Scalar::from).collect();
+
+        for xs in [&random, &progression, &consecutive] {
+            let expected: Vec<Scalar> = xs.iter().map(|&x| poly.evaluate(x)).collect();
+            assert_eq!(poly.evaluate_many(xs), expected);
+        }
+    }
+    let poly = Polynomial::new(Scalar::random(&mut rng), 3, &mut rng);
+    assert!(poly.evaluate_many(&[]).is_empty());
+}
+
+#[test]
+fn test_polynomial_degree_ignores_trailing_zeros() {
+    let p = Polynomial::from_coefficients(vec![Scalar::ONE, Scalar::from(2u64), Scalar::ZERO, Scalar::ZERO]);
+    assert_eq!(p.degree(), 1);
+    assert_eq!(Polynomial::from_coefficients(vec![Scalar::ZERO, Scalar::ZERO]).degree(), 0);
+    assert_eq!(Polynomial::<Scalar>::from_coefficients(Vec::new()).degree(), 0);
+
+    let mut rng = rand::thread_rng();
+    let q = Polynomial::new(Scalar::random(&mut rng), 5, &mut rng);
+    assert_eq!(q.degree(), 5);
+}
+
+#[test]
+fn test_polynomial_debug_is_redacted() {
+    let secret = Scalar::random(&mut rand::thread_rng());
+    let poly = Polynomial::new(secret, 3, &mut rand::thread_rng());
+    let secret_hex: String = secret.to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+
+    let debug = format!("{:?}", poly);
+    assert!(debug.contains("degree: 3"));
+    assert!(!debug.contains(&secret_hex));
+    assert!(!debug.contains(&format!("{:?}", secret)));
+
+    // 显式 reveal 后才输出系数
+    assert!(format!("{:?}", poly.reveal()).contains(&secret_hex));
+}
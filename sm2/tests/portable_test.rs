@@ -0,0 +1,61 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::ReconstructError;
+use shamir_secret_sharing::portable::{reconstruct_portable, PortableError, PortableShare, Scheme};
+use shamir_secret_sharing::secret_sharing::{generate_shares, generate_shares_with_feldman_vss};
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::Group;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_reconstruct_portable_consistent_shares() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut rng);
+    let (shares, _) = generate_shares_with_feldman_vss(secret, 5, 3, g, &mut rng);
+    let portable = PortableShare::wrap_all(&shares, Scheme::Feldman { g }, 3, [7u8; 16]);
+
+    // 经过字节编码往返后仍可直接恢复
+    let decoded: Vec<PortableShare> = portable
+        .iter()
+        .map(|s| PortableShare::from_bytes(&s.to_bytes()).unwrap())
+        .collect();
+    assert_eq!(decoded, portable);
+    assert_eq!(reconstruct_portable(&decoded[1..4]), Ok(secret));
+
+    // 份额不足门限值
+    assert_eq!(
+        reconstruct_portable(&decoded[0..2]),
+        Err(PortableError::Reconstruct(ReconstructError::InsufficientShares))
+    );
+}
+
+#[test]
+fn test_reconstruct_portable_rejects_mismatched_parameters() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 3, 2, &mut rng);
+    let h = ProjectivePoint::random(&mut rng);
+    let g = ProjectivePoint::GENERATOR;
+
+    // 不同的分发标识
+    let mut mixed = PortableShare::wrap_all(&shares, Scheme::Plain, 2, [1u8; 16]);
+    mixed[1].deal_id = [2u8; 16];
+    assert_eq!(reconstruct_portable(&mixed), Err(PortableError::InconsistentShares));
+
+    // 不同的方案参数
+    let mut mixed = PortableShare::wrap_all(&shares, Scheme::Pedersen { g, h }, 2, [1u8; 16]);
+    mixed[0].scheme = Scheme::Pedersen { g: h, h: g };
+    assert_eq!(reconstruct_portable(&mixed), Err(PortableError::InconsistentShares));
+
+    // 不同的门限值
+    let mut mixed = PortableShare::wrap_all(&shares, Scheme::Plain, 2, [1u8; 16]);
+    mixed[2].threshold = 3;
+    assert_eq!(reconstruct_portable(&mixed), Err(PortableError::InconsistentShares));
+
+    // 截断的编码
+    let bytes = mixed[0].to_bytes();
+    assert_eq!(
+        PortableShare::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(PortableError::Malformed)
+    );
+}
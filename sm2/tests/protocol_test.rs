@@ -0,0 +1,104 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::ShareError;
+use shamir_secret_sharing::protocol::{ComplaintMessage, DealerResponse, ProtocolError, VssDealer, VssParticipant};
+use shamir_secret_sharing::secret_sharing::reconstruct_secret;
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_protocol_commit_verify_complain_resolve() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let (n, t) = (3, 2);
+    let mut dealer = VssDealer::new(secret, n, t, &mut rng).unwrap();
+    let mut participants: Vec<VssParticipant> = (1..=n).map(|i| VssParticipant::new(i, t)).collect();
+
+    // 承诺轮：参与者 2 的份额在传输中被篡改
+    let message = dealer.deal();
+    let mut complaints = Vec::new();
+    for participant in &mut participants {
+        let mut private = message.to_participant(participant.index()).unwrap();
+        if participant.index() == 2 {
+            private.shares[0].1 += Scalar::ONE;
+        }
+        // 验证轮
+        if let Some(complaint) = participant.receive(&private).unwrap() {
+            complaints.push(complaint);
+        }
+    }
+    assert_eq!(complaints, vec![ComplaintMessage { index: 2 }]);
+    assert_eq!(participants[1].share(), None);
+
+    // 投诉轮
+    let responses: Vec<DealerResponse> = complaints.iter().map(|c| dealer.respond(c).unwrap()).collect();
+    assert_eq!(dealer.complaints(), &[2]);
+
+    // 裁决轮：所有参与者检查所有回应
+    for participant in &mut participants {
+        for (complaint, response) in complaints.iter().zip(&responses) {
+            participant.resolve(complaint, response).unwrap();
+        }
+    }
+
+    let shares: Vec<(Scalar, Scalar)> = participants.iter().map(|p| p.share().unwrap()).collect();
+    assert_eq!(reconstruct_secret(&shares[1..]), secret);
+    assert_eq!(reconstruct_secret(&shares), secret);
+}
+
+#[test]
+fn test_protocol_detects_misbehaving_dealer() {
+    let mut rng = OsRng;
+    let mut dealer = VssDealer::new(Scalar::random(&mut rng), 3, 2, &mut rng).unwrap();
+    let message = dealer.deal();
+    let mut alice = VssParticipant::new(1, 2);
+
+    assert_eq!(
+        alice.resolve(&ComplaintMessage { index: 2 }, &dealer.respond(&ComplaintMessage { index: 2 }).unwrap()),
+        Err(ProtocolError::UnexpectedMessage)
+    );
+    assert_eq!(alice.receive(&message.to_participant(1).unwrap()), Ok(None));
+    assert_eq!(
+        alice.receive(&message.to_participant(1).unwrap()),
+        Err(ProtocolError::UnexpectedMessage)
+    );
+
+    // 分发者公开了错误的份额
+    let complaint = ComplaintMessage { index: 3 };
+    let mut response = dealer.respond(&complaint).unwrap();
+    response.revealed_share.1 += Scalar::ONE;
+    assert_eq!(alice.resolve(&complaint, &response), Err(ProtocolError::DealerMisbehaved { index: 3 }));
+    // 公开的份额属于其他参与者
+    let response = dealer.respond(&ComplaintMessage { index: 2 }).unwrap();
+    assert_eq!(alice.resolve(&complaint, &response), Err(ProtocolError::DealerMisbehaved { index: 3 }));
+
+    assert_eq!(
+        dealer.respond(&ComplaintMessage { index: 4 }),
+        Err(ProtocolError::UnknownParticipant { index: 4 })
+    );
+
+    // 截断的承诺
+    let mut bob = VssParticipant::new(2, 2);
+    let mut truncated = message.to_participant(2).unwrap();
+    truncated.commitments.truncate(1);
+    assert_eq!(bob.receive(&truncated), Err(ProtocolError::InvalidCommitments));
+
+    assert!(matches!(
+        VssDealer::new(Scalar::ONE, 3, 0, &mut rng),
+        Err(ProtocolError::Invalid(ShareError::ZeroThreshold))
+    ));
+}
+
+#[test]
+fn test_protocol_messages_debug_hides_share_values() {
+    let secret = Scalar::random(&mut OsRng);
+    let mut dealer = VssDealer::new(secret, 3, 2, &mut OsRng).unwrap();
+    let message = dealer.deal();
+    let output = format!("{:?}", message);
+    assert!(output.contains("shares: [(1, ..), (2, ..), (3, ..)]"));
+    for (_, y) in &message.shares {
+        assert!(!output.contains(&format!("{:?}", y)));
+    }
+
+    let response = dealer.respond(&ComplaintMessage { index: 2 }).unwrap();
+    assert_eq!(format!("{:?}", response), "DealerResponse { revealed_share: (2, ..) }");
+}
@@ -0,0 +1,101 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::SecretSharingError;
+use shamir_secret_sharing::pvss::{deal_pvss, decrypt_share, generate_pvss, pvss_generator, reconstruct_pvss_secret, verify_pvss};
+use shamir_secret_sharing::secret_sharing::independent_generator;
+use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::{Group, GroupEncoding};
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_pvss_deal_verify_decrypt_reconstruct() {
+    let mut rng = OsRng;
+    // 与 SM2 基点相互独立的承诺生成元
+    let g = ProjectivePoint::random(&mut rng);
+    let secret = Scalar::random(&mut rng);
+    let t = 3;
+
+    let secret_keys: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+    let pubkeys: Vec<ProjectivePoint> = secret_keys
+        .iter()
+        .map(|sk| ProjectivePoint::GENERATOR * sk)
+        .collect();
+
+    let transcript = deal_pvss(secret, &pubkeys, t, g, &mut rng);
+
+    // 任何人都可以公开验证分发记录
+    assert!(verify_pvss(&transcript, &pubkeys, g));
+
+    // 接收者各自解密，任意 t 个解密份额恢复 G * secret
+    let decrypted: Vec<(Scalar, ProjectivePoint)> = transcript
+        .encrypted_shares
+        .iter()
+        .zip(&secret_keys)
+        .map(|(share, sk)| decrypt_share(share, *sk).unwrap())
+        .collect();
+    let expected = ProjectivePoint::GENERATOR * secret;
+    assert_eq!(reconstruct_pvss_secret(&decrypted[0..3]), Ok(expected));
+    assert_eq!(reconstruct_pvss_secret(&decrypted[2..5]), Ok(expected));
+
+    // 篡改任何一个加密份额都会导致公开验证失败
+    let mut tampered = transcript.clone();
+    tampered.encrypted_shares[1].1 += ProjectivePoint::GENERATOR;
+    assert!(!verify_pvss(&tampered, &pubkeys, g));
+
+    // 使用错误的接收者公钥验证失败
+    let mut wrong_keys = pubkeys.clone();
+    wrong_keys.swap(0, 1);
+    assert!(!verify_pvss(&transcript, &wrong_keys, g));
+}
+
+#[test]
+fn test_pvss_decrypt_and_reconstruct_errors() {
+    let share = (Scalar::ONE, ProjectivePoint::GENERATOR);
+    // 私钥为 0 时无法求逆
+    assert_eq!(decrypt_share(&share, Scalar::ZERO), Err(SecretSharingError::NonInvertible));
+    assert_eq!(reconstruct_pvss_secret(&[]), Err(SecretSharingError::InsufficientShares));
+    assert_eq!(reconstruct_pvss_secret(&[share, share]), Err(SecretSharingError::DuplicateShareIndex));
+}
+
+#[test]
+fn test_generate_pvss_with_default_generator() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let g = pvss_generator();
+    assert_eq!(g, pvss_generator());
+    assert_eq!(g, independent_generator(b"shamir_secret_sharing/pvss/generator"));
+    assert_ne!(g, ProjectivePoint::GENERATOR);
+
+    // 4 个参与者，门限值 2
+    let secret_keys: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut rng)).collect();
+    let pubkeys: Vec<ProjectivePoint> = secret_keys
+        .iter()
+        .map(|sk| ProjectivePoint::GENERATOR * sk)
+        .collect();
+    let transcript = generate_pvss(secret, &pubkeys, 2, &mut rng);
+    assert_eq!(transcript.proofs.len(), 4);
+    assert!(verify_pvss(&transcript, &pubkeys, g));
+
+    // 篡改一个密文
+    let mut tampered = transcript.clone();
+    tampered.encrypted_shares[3].1 = tampered.encrypted_shares[3].1.double();
+    assert!(!verify_pvss(&tampered, &pubkeys, g));
+
+    let decrypted: Vec<(Scalar, ProjectivePoint)> = transcript
+        .encrypted_shares
+        .iter()
+        .zip(&secret_keys)
+        .map(|(share, sk)| decrypt_share(share, *sk).unwrap())
+        .collect();
+    assert_eq!(
+        reconstruct_pvss_secret(&decrypted[1..3]),
+        Ok(ProjectivePoint::GENERATOR * secret)
+    );
+}
+#[test]
+fn test_pvss_generator_is_stable() {
+    // hash_to_curve("shamir_secret_sharing/independent_generator", "shamir_secret_sharing/pvss/generator")；
+    // 派生方式改变会使已发布的分发记录无法验证
+    let expected = "020086a4fbdf32f276546e98fe78f2b5845235ec4bc4727a6b947fbf80ce0078dc";
+    let encoded: String = pvss_generator().to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(encoded, expected);
+}
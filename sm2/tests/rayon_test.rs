@@ -0,0 +1,43 @@
+#![cfg(feature = "rayon")]
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::{
+    generate_shares_with_feldman_vss, generate_shares_with_rng_unchecked, verify_share_with_feldman_vss,
+    verify_shares_with_feldman_vss,
+};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_parallel_generation_matches_sequential() {
+    let n = 1000;
+    let t = 10;
+    let secret = Scalar::random(&mut rand::thread_rng());
+
+    let shares = generate_shares_with_rng_unchecked(secret, n, t, &mut StdRng::seed_from_u64(7));
+    // 相同种子下逐个求值的顺序结果
+    let poly = Polynomial::new_with_rng_unchecked(secret, t - 1, &mut StdRng::seed_from_u64(7));
+    let expected: Vec<(Scalar, Scalar)> = (1..=n as u64)
+        .map(|i| (Scalar::from(i), poly.evaluate(Scalar::from(i))))
+        .collect();
+    assert_eq!(shares, expected);
+}
+
+#[test]
+fn test_parallel_verification_matches_sequential() {
+    let mut rng = rand::thread_rng();
+    let g = ProjectivePoint::GENERATOR;
+    let (mut shares, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), 1000, 5, g, &mut rng);
+    for i in [3, 500, 999] {
+        shares[i].1 += Scalar::ONE;
+    }
+
+    let expected: Vec<bool> = shares
+        .iter()
+        .map(|&share| verify_share_with_feldman_vss(share, &commitments, g))
+        .collect();
+    assert_eq!(verify_shares_with_feldman_vss(&shares, &commitments, g), expected);
+    assert_eq!(expected.iter().filter(|&&valid| !valid).count(), 3);
+}
@@ -0,0 +1,241 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::{SecretSharingError, ShareError};
+use shamir_secret_sharing::resharing::{combine_reshare, compute_new_share, proactive_reshare, redistribute, refresh_shares, reshare_threshold, revoke_and_refresh};
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_proactive_reshare_end_to_end() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+
+    // 原方案：3-of-5，由份额 1、3、5 的持有者参与重分发
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+    let old_shares = [shares[0], shares[2], shares[4]];
+    let old_xs: Vec<Scalar> = old_shares.iter().map(|&(x, _)| x).collect();
+
+    // 新方案：4-of-6，新参与者的 x 坐标为 11 到 16
+    let new_t = 4;
+    let new_xs: Vec<Scalar> = (11..=16u64).map(Scalar::from).collect();
+
+    // 每个旧持有者为所有新参与者生成子份额
+    let outgoing: Vec<Vec<(Scalar, Scalar)>> = old_shares
+        .iter()
+        .map(|&share| proactive_reshare(share, &old_xs, &new_xs, new_t, &mut rng).unwrap())
+        .collect();
+
+    // 每个新参与者按 old_xs 的顺序收集子份额并合并
+    let new_shares: Vec<(Scalar, Scalar)> = (0..new_xs.len())
+        .map(|j| {
+            let received: Vec<(Scalar, Scalar)> = outgoing.iter().map(|subs| subs[j]).collect();
+            combine_reshare(&received, &old_xs)
+        })
+        .collect();
+
+    for (share, x) in new_shares.iter().zip(&new_xs) {
+        assert_eq!(share.0, *x);
+    }
+
+    // 任意 new_t 个新份额都能恢复秘密
+    assert_eq!(reconstruct_secret(&new_shares[0..4]), secret);
+    assert_eq!(reconstruct_secret(&new_shares[2..6]), secret);
+
+    // 少于 new_t 个新份额无法恢复秘密
+    assert_ne!(reconstruct_secret(&new_shares[0..3]), secret);
+}
+
+#[test]
+fn test_refresh_shares() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let t = 4;
+    let old_shares = generate_shares(secret, 6, t, &mut rng);
+    let new_shares = refresh_shares(&old_shares, t, &mut rng);
+
+    // x 坐标不变，y 值全部改变
+    for (old, new) in old_shares.iter().zip(&new_shares) {
+        assert_eq!(old.0, new.0);
+        assert_ne!(old.1, new.1);
+    }
+
+    // 刷新后的份额恢复出相同的秘密
+    assert_eq!(reconstruct_secret(&new_shares[0..4]), secret);
+    assert_eq!(reconstruct_secret(&new_shares[2..6]), secret);
+
+    // 新旧份额各取一半无法恢复秘密
+    let mixed = [old_shares[0], old_shares[1], new_shares[2], new_shares[3]];
+    assert_ne!(reconstruct_secret(&mixed), secret);
+}
+
+#[test]
+fn test_redistribute_3_of_5_to_4_of_7() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let old_shares = generate_shares(secret, 5, 3, &mut rng);
+
+    let new_xs: Vec<Scalar> = (1..=7u64).map(Scalar::from).collect();
+    let new_shares = redistribute(&old_shares[1..], 3, &new_xs, 4, &mut rng).unwrap();
+    assert_eq!(new_shares.len(), 7);
+
+    // 任意 4 个新份额恢复出相同的秘密，3 个则不行
+    assert_eq!(reconstruct_secret(&new_shares[0..4]), secret);
+    assert_eq!(reconstruct_secret(&new_shares[3..7]), secret);
+    assert_ne!(reconstruct_secret(&new_shares[0..3]), secret);
+}
+
+#[test]
+fn test_resharing_rejects_zero_index() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let old_shares = generate_shares(secret, 3, 2, &mut rng);
+    let old_xs: Vec<Scalar> = old_shares[..2].iter().map(|&(x, _)| x).collect();
+
+    // x = 0 处的新份额就是秘密本身
+    let new_xs = [Scalar::from(5u64), Scalar::ZERO];
+    assert_eq!(redistribute(&old_shares, 2, &new_xs, 2, &mut rng), Err(ShareError::ZeroShareIndex));
+    assert_eq!(
+        proactive_reshare(old_shares[0], &old_xs, &new_xs, 2, &mut rng),
+        Err(ShareError::ZeroShareIndex)
+    );
+    let duplicated = [Scalar::from(5u64), Scalar::from(5u64)];
+    assert_eq!(redistribute(&old_shares, 2, &duplicated, 2, &mut rng), Err(ShareError::DuplicateShareIndex));
+}
+
+#[test]
+fn test_compute_new_share_enrolls_sixth_participant() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let mut shares = generate_shares(secret, 5, 3, &mut rng);
+
+    let x_new = Scalar::from(6u64);
+    let new_share = compute_new_share(&shares[1..4], x_new, 3).unwrap();
+    assert_eq!(new_share.0, x_new);
+    shares.push(new_share);
+
+    // 6 个份额全部位于同一多项式上，新份额与任意两个旧份额也能恢复秘密
+    assert_eq!(reconstruct_secret(&shares), secret);
+    assert_eq!(reconstruct_secret(&[shares[0], shares[4], shares[5]]), secret);
+
+    assert_eq!(compute_new_share(&shares, Scalar::from(2u64), 3), Err(SecretSharingError::DuplicateShareIndex));
+    assert_eq!(compute_new_share(&shares, Scalar::ZERO, 3), Err(SecretSharingError::ZeroShareIndex));
+    assert_eq!(compute_new_share(&shares[..2], Scalar::from(7u64), 3), Err(SecretSharingError::InsufficientShares));
+    assert_eq!(compute_new_share(&shares, Scalar::from(7u64), 0), Err(SecretSharingError::InvalidThreshold));
+}
+
+#[test]
+fn test_revoke_and_refresh() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+
+    // 撤销第 5 个参与者
+    let revoked = shares[4];
+    let refreshed = revoke_and_refresh(&shares[..4], 3, &mut rng).unwrap();
+    assert_eq!(refreshed.len(), 4);
+    assert!(refreshed.iter().zip(&shares).all(|(new, old)| new.0 == old.0 && new.1 != old.1));
+    assert_eq!(reconstruct_secret(&refreshed[1..]), secret);
+
+    // 被撤销的旧份额与任意两个新份额组合都恢复不出秘密
+    for i in 0..4 {
+        for j in i + 1..4 {
+            assert_ne!(reconstruct_secret(&[refreshed[i], refreshed[j], revoked]), secret);
+        }
+    }
+
+    assert_eq!(revoke_and_refresh(&shares[..2], 3, &mut rng), Err(SecretSharingError::InsufficientShares));
+    assert_eq!(revoke_and_refresh(&shares, 0, &mut rng), Err(SecretSharingError::InvalidThreshold));
+}
+
+#[test]
+fn test_resharing_rejects_invalid_old_xs() {
+    let mut rng = OsRng;
+    let shares = generate_shares(Scalar::random(&mut rng), 3, 2, &mut rng);
+    let new_xs = [Scalar::from(5u64), Scalar::from(6u64)];
+
+    // 重复的 old_xs 会得到错误的拉格朗日系数
+    let duplicated = [shares[0].0, shares[1].0, shares[1].0];
+    assert_eq!(
+        proactive_reshare(shares[0], &duplicated, &new_xs, 2, &mut rng),
+        Err(ShareError::DuplicateShareIndex)
+    );
+    let with_zero = [shares[0].0, Scalar::ZERO];
+    assert_eq!(
+        proactive_reshare(shares[0], &with_zero, &new_xs, 2, &mut rng),
+        Err(ShareError::ZeroShareIndex)
+    );
+    let repeated_share = [shares[0], shares[0], shares[1]];
+    assert_eq!(redistribute(&repeated_share, 2, &new_xs, 2, &mut rng), Err(ShareError::DuplicateShareIndex));
+    assert_eq!(redistribute(&shares, 0, &new_xs, 2, &mut rng), Err(ShareError::ZeroThreshold));
+}
+
+#[test]
+fn test_redistribute_rejects_invalid_thresholds() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 3, 2, &mut rng);
+    let new_xs = [Scalar::from(5u64), Scalar::from(6u64), Scalar::from(7u64)];
+
+    // 3 个新参与者无法凑齐 4 个份额
+    assert_eq!(redistribute(&shares, 2, &new_xs, 4, &mut rng), Err(ShareError::ThresholdExceedsShares));
+    assert_eq!(redistribute(&shares, 2, &[], 1, &mut rng), Err(ShareError::ZeroShares));
+    assert_eq!(redistribute(&shares, 2, &new_xs, 0, &mut rng), Err(ShareError::ZeroThreshold));
+    assert_eq!(redistribute(&shares[..1], 2, &new_xs, 2, &mut rng), Err(ShareError::InsufficientShares));
+
+    let new_shares = redistribute(&shares, 2, &new_xs, 3, &mut rng).unwrap();
+    assert_eq!(reconstruct_secret(&new_shares), secret);
+}
+
+#[test]
+#[should_panic(expected = "at least one old shareholder is required")]
+fn test_combine_reshare_rejects_empty_input() {
+    combine_reshare(&[], &[]);
+}
+
+#[test]
+#[should_panic(expected = "old_xs must be distinct")]
+fn test_combine_reshare_rejects_duplicate_old_xs() {
+    let x = Scalar::from(5u64);
+    let old_xs = [Scalar::from(1u64), Scalar::from(1u64)];
+    combine_reshare(&[(x, Scalar::ONE), (x, Scalar::ONE)], &old_xs);
+}
+
+#[test]
+fn test_proactive_reshare_rejects_invalid_new_threshold() {
+    let mut rng = OsRng;
+    let shares = generate_shares(Scalar::random(&mut rng), 3, 2, &mut rng);
+    let old_xs: Vec<Scalar> = shares.iter().map(|&(x, _)| x).collect();
+    let new_xs = [Scalar::from(1u64), Scalar::from(2u64)];
+
+    assert_eq!(
+        proactive_reshare(shares[0], &old_xs, &new_xs, 0, &mut rng),
+        Err(ShareError::ZeroThreshold)
+    );
+    assert_eq!(
+        proactive_reshare(shares[0], &old_xs, &new_xs, usize::MAX, &mut rng),
+        Err(ShareError::ThresholdTooLarge)
+    );
+    // 只有 2 个新参与者，门限值 3 永远无法满足
+    assert_eq!(
+        proactive_reshare(shares[0], &old_xs, &new_xs, 3, &mut rng),
+        Err(ShareError::ThresholdExceedsShares)
+    );
+    assert_eq!(proactive_reshare(shares[0], &old_xs, &[], 1, &mut rng), Err(ShareError::ZeroShares));
+}
+
+#[test]
+fn test_reshare_threshold_2_of_5_to_4_of_5() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let old_shares = generate_shares(secret, 5, 2, &mut rng);
+    let new_shares = reshare_threshold(&old_shares, 2, 4, &mut rng);
+
+    assert_eq!(new_shares.len(), 5);
+    assert!(old_shares.iter().zip(&new_shares).all(|(old, new)| old.0 == new.0));
+    assert_eq!(reconstruct_secret(&new_shares[..4]), secret);
+    assert_eq!(reconstruct_secret(&new_shares[1..]), secret);
+    // 少于 4 个新份额不再能恢复秘密
+    assert_ne!(reconstruct_secret(&new_shares[..3]), secret);
+    assert_ne!(reconstruct_secret(&new_shares[2..]), secret);
+    assert_ne!(reconstruct_secret(&new_shares[..2]), secret);
+}
@@ -0,0 +1,54 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::seal::{open_share, seal_shares, SealError};
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_seal_and_open_round_trip() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 3, 2, &mut rng);
+
+    // 三个接收者各自的 SM2 密钥对
+    let secret_keys: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+    let pubkeys: Vec<ProjectivePoint> = secret_keys
+        .iter()
+        .map(|sk| ProjectivePoint::GENERATOR * sk)
+        .collect();
+
+    let sealed = seal_shares(&shares, &pubkeys, &mut rng).unwrap();
+    assert_eq!(sealed.len(), 3);
+
+    let mut opened = Vec::new();
+    for (i, envelope) in sealed.iter().enumerate() {
+        // 每个接收者只能打开自己的信封
+        for (j, sk) in secret_keys.iter().enumerate() {
+            if i == j {
+                assert_eq!(open_share(envelope, *sk), Ok(shares[i]));
+            } else {
+                assert_eq!(open_share(envelope, *sk), Err(SealError::InvalidCiphertext));
+            }
+        }
+        opened.push(open_share(envelope, secret_keys[i]).unwrap());
+    }
+
+    assert_eq!(reconstruct_secret(&opened[0..2]), secret);
+}
+
+#[test]
+fn test_seal_rejects_tampering_and_length_mismatch() {
+    let mut rng = OsRng;
+    let shares = generate_shares(Scalar::random(&mut rng), 3, 2, &mut rng);
+    let sk = Scalar::random(&mut rng);
+    let pubkey = ProjectivePoint::GENERATOR * sk;
+
+    assert_eq!(
+        seal_shares(&shares, &[pubkey, pubkey], &mut rng),
+        Err(SealError::LengthMismatch)
+    );
+
+    let mut sealed = seal_shares(&shares[..1], &[pubkey], &mut rng).unwrap();
+    sealed[0].ciphertext[5] ^= 1;
+    assert_eq!(open_share(&sealed[0], sk), Err(SealError::InvalidCiphertext));
+}
@@ -1,7 +1,13 @@
-use rand::{rngs::OsRng, Rng, seq::SliceRandom};
-use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_secret, generate_shares_with_feldman_vss, verify_share_with_feldman_vss,generate_shares_with_pedersen_vss, verify_share_with_pedersen_vss};
+use std::collections::BTreeSet;
+use rand::{rngs::{OsRng, StdRng}, Rng, SeedableRng, seq::SliceRandom};
+use shamir_secret_sharing::error::{CommitmentError, DecodeError, IndexMismatch, ReconstructError, SecretSharingError, ShareError};
+use shamir_secret_sharing::gf257::Gf257;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret_sharing::{generate_shares, generate_shares_at, generate_shares_deterministic, generate_shares_with_rng_unchecked, try_generate_shares, generate_shares_shuffled, reconstruct_secret, interpolate_at, reconstruct_excluding, reconstruct_secret_at_many, reconstruct_from_raw, conditional_select_share, generate_shares_with_feldman_vss, verify_share_with_feldman_vss, verify_shares_with_feldman_vss, verify_all_with_feldman_vss, reconstruct_secret_robust, verify_commitment_degree, generate_point_shares, reconstruct_point_secret, evaluate_commitments,generate_shares_with_pedersen_vss, verify_share_with_pedersen_vss, try_generate_shares_with_feldman_vss, try_generate_shares_with_pedersen_vss, try_verify_share_with_feldman_vss, try_reconstruct_secret, reconstruct_secret_ct, verify_secret_commitment, generate_shares_indexed, reconstruct_secret_indexed, commitments_to_bytes, commitments_from_bytes, shares_consistent_with_threshold, generate_shares_iter, verify_share_set_consistency, reconstruct_secret_overdetermined, reconstruct_secret_fast, reconstruct_and_verify, generate_shares_with_polynomial, split_private_key, generate_shares_const, verify_share_pedersen_precomputed, independent_generator, reconstruct_from_iter, add_share_sets, scale_share_set, reconstruct_secret_checked, lagrange_coefficients, MAX_THRESHOLD, reconstruct_secret_columnar, generate_shares_random_indices, FeldmanVerifier, reconstruct_secret_with_contributions, default_pedersen_generators, split_pedersen_default};
 use sm2::{ProjectivePoint, Scalar};
 use sm2::elliptic_curve::ff::Field;
+use sm2::elliptic_curve::group::{Group, GroupEncoding};
+use sm2::elliptic_curve::subtle::Choice;
 
 #[test]
 fn test_secret_sharing() {
@@ -80,15 +86,16 @@ fn test_pedersen_vss() {
         let t = rng.gen_range(2..=n); // 随机生成门限值，至少为 2 且不超过 n
 
         // 生成带有 Pedersen 承诺的份额
-        let (shares, commitments, blinding_poly) =
+        let (shares, commitments, blinding_values) =
             generate_shares_with_pedersen_vss(secret, n, t, g, h, &mut rng);
 
         // 验证每个份额的有效性
-        for share in &shares {
+        for (share, blinding) in shares.iter().zip(&blinding_values) {
+            assert_eq!(share.0, blinding.0);
             assert!(verify_share_with_pedersen_vss(
                 *share,
+                blinding.1,
                 &commitments,
-                &blinding_poly,
                 g,
                 h
             ));
@@ -106,8 +113,8 @@ fn test_pedersen_vss() {
         tampered_shares[tamper_index].1 += Scalar::ONE;
         assert!(!verify_share_with_pedersen_vss(
             tampered_shares[tamper_index],
+            blinding_values[tamper_index].1,
             &commitments,
-            &blinding_poly,
             g,
             h
         ));
@@ -120,4 +127,1111 @@ fn test_pedersen_vss() {
         }
     }
     println!("All random tests passed for Pedersen VSS!");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_conditional_select_share() {
+    let mut rng = OsRng;
+    let shares = generate_shares(Scalar::random(&mut rng), 2, 2, &mut rng);
+    let (a, b) = (shares[0], shares[1]);
+
+    // Choice(0) 选择 a，Choice(1) 选择 b
+    assert_eq!(conditional_select_share(&a, &b, Choice::from(0)), a);
+    assert_eq!(conditional_select_share(&a, &b, Choice::from(1)), b);
+}
+
+#[test]
+fn test_reconstruct_from_raw() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let t = 3;
+    let shares = generate_shares(secret, 5, t, &mut rng);
+
+    // 将份额编码为 64 字节的记录 x || y
+    let records: Vec<[u8; 64]> = shares
+        .iter()
+        .map(|(x, y)| {
+            let mut record = [0u8; 64];
+            record[..32].copy_from_slice(&x.to_bytes());
+            record[32..].copy_from_slice(&y.to_bytes());
+            record
+        })
+        .collect();
+
+    assert_eq!(reconstruct_from_raw(&records, t), Ok(secret));
+    assert_eq!(
+        reconstruct_from_raw(&records[..2], t),
+        Err(ReconstructError::InsufficientShares)
+    );
+
+    // 全 0xff 不是规范的标量编码
+    let mut non_canonical = records.clone();
+    non_canonical[1][32..].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        reconstruct_from_raw(&non_canonical, t),
+        Err(ReconstructError::NonCanonicalEncoding)
+    );
+
+    let duplicated = vec![records[0], records[0], records[1]];
+    assert_eq!(
+        reconstruct_from_raw(&duplicated, t),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_generate_shares_shuffled() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let n = 10;
+    let t = 4;
+
+    let (shuffled, permutation) = generate_shares_shuffled(secret, n, t, &mut rng);
+    assert_eq!(shuffled.len(), n);
+
+    // 使用置换将打乱的份额映射回原来的顺序
+    let mut original = vec![(Scalar::ZERO, Scalar::ZERO); n];
+    for (k, &i) in permutation.iter().enumerate() {
+        original[i] = shuffled[k];
+    }
+    for (i, &(x, _)) in original.iter().enumerate() {
+        assert_eq!(x, Scalar::from(i as u64 + 1));
+    }
+
+    // 打乱顺序不影响恢复
+    assert_eq!(reconstruct_secret(&shuffled[0..t]), secret);
+}
+
+#[test]
+fn test_reconstruct_excluding_revoked() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let t = 3;
+    let shares = generate_shares(secret, 5, t, &mut rng);
+
+    // 吊销 2 个份额，剩余 3 个仍可恢复
+    let revoked: BTreeSet<Scalar> = [shares[0].0, shares[3].0].into_iter().collect();
+    assert_eq!(reconstruct_excluding(&shares, &revoked, t), Ok(secret));
+
+    // 吊销 3 个份额，剩余份额不足门限值
+    let revoked: BTreeSet<Scalar> = [shares[0].0, shares[1].0, shares[4].0].into_iter().collect();
+    assert_eq!(
+        reconstruct_excluding(&shares, &revoked, t),
+        Err(ReconstructError::InsufficientShares)
+    );
+}
+
+#[test]
+fn test_reconstruct_secret_at_many() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let t = 3;
+    let poly = Polynomial::new(secret, t - 1, &mut rng);
+    let shares: Vec<(Scalar, Scalar)> = (1..=5u64)
+        .map(|i| (Scalar::from(i), poly.evaluate(Scalar::from(i))))
+        .collect();
+
+    // 一次为 5 个新参与者签发份额
+    let targets: Vec<Scalar> = (6..=10u64).map(Scalar::from).collect();
+    let values = reconstruct_secret_at_many(&shares[0..t], &targets).unwrap();
+
+    for (x, y) in targets.iter().zip(&values) {
+        assert_eq!(*y, poly.evaluate(*x));
+    }
+
+    // x = 0 处的值即为秘密，不能作为份额签发
+    assert_eq!(
+        reconstruct_secret_at_many(&shares[1..4], &[Scalar::ZERO]),
+        Err(ReconstructError::ZeroShareIndex)
+    );
+}
+
+#[test]
+fn test_try_generate_shares_rejects_invalid_parameters() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+
+    assert_eq!(try_generate_shares(secret, 0, 0, &mut rng), Err(ShareError::ZeroShares));
+    assert_eq!(try_generate_shares(secret, 0, 1, &mut rng), Err(ShareError::ZeroShares));
+    assert_eq!(try_generate_shares(secret, 5, 0, &mut rng), Err(ShareError::ZeroThreshold));
+    assert_eq!(
+        try_generate_shares(secret, 3, 4, &mut rng),
+        Err(ShareError::ThresholdExceedsShares)
+    );
+
+    // 合法参数的行为与 generate_shares 一致
+    let shares = try_generate_shares(secret, 5, 3, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert_eq!(reconstruct_secret(&shares[0..3]), secret);
+}
+
+#[test]
+fn test_interpolate_at_matches_polynomial() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let t = 4;
+    let poly = Polynomial::new(secret, t - 1, &mut rng);
+    let shares: Vec<(Scalar, Scalar)> = (1..=t as u64)
+        .map(|i| (Scalar::from(i), poly.evaluate(Scalar::from(i))))
+        .collect();
+
+    // 任意点上的插值与直接求值一致
+    for _ in 0..10 {
+        let x = Scalar::random(&mut rng);
+        assert_eq!(interpolate_at(&shares, x), Ok(poly.evaluate(x)));
+    }
+
+    // x = 0 即恢复秘密
+    assert_eq!(interpolate_at(&shares, Scalar::ZERO), Ok(secret));
+    assert_eq!(reconstruct_secret(&shares), secret);
+
+    // 插值点与已有份额的 x 坐标重合时返回该份额的 y 值
+    assert_eq!(interpolate_at(&shares, shares[2].0), Ok(shares[2].1));
+
+    let duplicated = vec![shares[0], shares[0]];
+    assert_eq!(
+        interpolate_at(&duplicated, Scalar::ONE),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_batch_feldman_verification_flags_tampered_share() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut rng);
+    let (mut shares, commitments) = generate_shares_with_feldman_vss(secret, 6, 3, g, &mut rng);
+
+    assert_eq!(verify_shares_with_feldman_vss(&shares, &commitments, g), vec![true; 6]);
+    assert_eq!(verify_all_with_feldman_vss(&shares, &commitments, g), (true, vec![]));
+
+    // 只篡改第 4 个份额
+    shares[3].1 += Scalar::ONE;
+    let results = verify_shares_with_feldman_vss(&shares, &commitments, g);
+    for (i, valid) in results.iter().enumerate() {
+        assert_eq!(*valid, i != 3);
+        assert_eq!(*valid, verify_share_with_feldman_vss(shares[i], &commitments, g));
+    }
+    assert_eq!(verify_all_with_feldman_vss(&shares, &commitments, g), (false, vec![3]));
+
+    // 相互抵消的篡改不能骗过批量检查中的随机线性组合
+    shares[3].1 -= Scalar::ONE;
+    shares[1].1 += Scalar::ONE;
+    shares[4].1 -= Scalar::ONE;
+    assert_eq!(verify_all_with_feldman_vss(&shares, &commitments, g), (false, vec![1, 4]));
+}
+
+#[test]
+fn test_evaluate_commitments_matches_power_sum() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+
+    // 包括使用霍纳法则和使用多标量乘法的长度
+    for degree in (0..=20).chain([63, 255]) {
+        let poly = Polynomial::new(Scalar::random(&mut rng), degree, &mut rng);
+        let commitments = poly.feldman_commit(g);
+        let x = Scalar::random(&mut rng);
+
+        // 逐项计算 C_i * x^i 的旧方法
+        let mut expected = ProjectivePoint::IDENTITY;
+        for (i, commitment) in commitments.iter().enumerate() {
+            expected += *commitment * x.pow([i as u64, 0, 0, 0]);
+        }
+
+        assert_eq!(evaluate_commitments(&commitments, x), expected);
+        assert_eq!(evaluate_commitments(&commitments, x), g * poly.evaluate(x));
+
+        // x 为 0 和 1 的边界情况
+        assert_eq!(evaluate_commitments(&commitments, Scalar::ZERO), commitments[0]);
+        assert_eq!(
+            evaluate_commitments(&commitments, Scalar::ONE),
+            commitments.iter().fold(ProjectivePoint::IDENTITY, |acc, c| acc + c)
+        );
+    }
+}
+
+#[test]
+fn test_generate_shares_at_custom_indices() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let xs: Vec<Scalar> = [5u64, 9, 42].iter().map(|&i| Scalar::from(i)).collect();
+
+    let shares = generate_shares_at(secret, &xs, 2, &mut rng).unwrap();
+    for (share, x) in shares.iter().zip(&xs) {
+        assert_eq!(share.0, *x);
+    }
+    assert_eq!(reconstruct_secret(&shares[0..2]), secret);
+    assert_eq!(reconstruct_secret(&shares[1..3]), secret);
+
+    // 非法的 x 坐标
+    assert_eq!(generate_shares_at(secret, &[], 1, &mut rng), Err(ShareError::ZeroShares));
+    assert_eq!(
+        generate_shares_at(secret, &[Scalar::from(3u64), Scalar::ZERO], 2, &mut rng),
+        Err(ShareError::ZeroShareIndex)
+    );
+    assert_eq!(
+        generate_shares_at(secret, &[xs[0], xs[1], xs[0]], 2, &mut rng),
+        Err(ShareError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_pedersen_vss_mismatched_blinding_value() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let h = g * Scalar::random(&mut rng);
+    let secret = Scalar::random(&mut rng);
+    let (shares, commitments, blinding_values) =
+        generate_shares_with_pedersen_vss(secret, 4, 3, g, h, &mut rng);
+
+    // 正确的 (份额, 盲化值) 对可以通过验证
+    assert!(verify_share_with_pedersen_vss(shares[0], blinding_values[0].1, &commitments, g, h));
+
+    // 份额与其他参与者的盲化值搭配无法通过验证
+    assert!(!verify_share_with_pedersen_vss(shares[0], blinding_values[1].1, &commitments, g, h));
+    assert!(!verify_share_with_pedersen_vss(shares[2], blinding_values[3].1, &commitments, g, h));
+}
+
+#[test]
+fn test_generate_shares_deterministic() {
+    let secret = Scalar::random(&mut OsRng);
+    let to_bytes = |shares: &[(Scalar, Scalar)]| -> Vec<u8> {
+        shares
+            .iter()
+            .flat_map(|(x, y)| x.to_bytes().into_iter().chain(y.to_bytes()))
+            .collect()
+    };
+
+    // 相同的种子得到逐字节相同的份额
+    let a = generate_shares_deterministic(secret, 5, 3, [7u8; 32]);
+    let b = generate_shares_deterministic(secret, 5, 3, [7u8; 32]);
+    assert_eq!(to_bytes(&a), to_bytes(&b));
+    assert_eq!(reconstruct_secret(&a[0..3]), secret);
+
+    // 不同的种子得到不同的份额
+    let c = generate_shares_deterministic(secret, 5, 3, [8u8; 32]);
+    assert_ne!(to_bytes(&a), to_bytes(&c));
+    assert_eq!(reconstruct_secret(&c[2..5]), secret);
+}
+
+#[test]
+fn test_generate_shares_with_rng_unchecked() {
+    use rand::rngs::mock::StepRng;
+
+    // StepRng 不是密码学安全的随机数生成器，只能通过 unchecked 接口使用
+    let secret = Scalar::random(&mut OsRng);
+    let a = generate_shares_with_rng_unchecked(secret, 4, 3, &mut StepRng::new(1, 1));
+    let b = generate_shares_with_rng_unchecked(secret, 4, 3, &mut StepRng::new(1, 1));
+    assert_eq!(a, b);
+    assert_eq!(reconstruct_secret(&a[1..4]), secret);
+}
+
+#[test]
+fn test_reconstruct_secret_robust() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut rng);
+    let (mut shares, commitments) = generate_shares_with_feldman_vss(secret, 7, 4, g, &mut rng);
+
+    // 篡改其中两个份额
+    shares[1].1 += Scalar::ONE;
+    shares[4].1 = Scalar::random(&mut rng);
+
+    let (recovered, discarded) = reconstruct_secret_robust(&shares, &commitments, g, 4).unwrap();
+    assert_eq!(recovered, secret);
+    assert_eq!(discarded, vec![1, 4]);
+
+    // 有效份额不足门限值
+    assert_eq!(
+        reconstruct_secret_robust(&shares[..5], &commitments, g, 4),
+        Err(ReconstructError::InsufficientShares)
+    );
+}
+
+#[test]
+fn test_verify_commitment_degree() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut rng);
+    let (_, commitments) = generate_shares_with_feldman_vss(secret, 5, 3, g, &mut rng);
+
+    assert_eq!(verify_commitment_degree(&commitments, 3), Ok(()));
+
+    // 承诺个数与门限值不一致
+    assert_eq!(
+        verify_commitment_degree(&commitments[..2], 3),
+        Err(CommitmentError::LengthMismatch { expected: 3, actual: 2 })
+    );
+    assert_eq!(
+        verify_commitment_degree(&commitments, 4),
+        Err(CommitmentError::LengthMismatch { expected: 4, actual: 3 })
+    );
+
+    // 最高次项为 0，实际次数低于声称的次数
+    let mut degenerate = commitments.clone();
+    degenerate[2] = ProjectivePoint::IDENTITY;
+    assert_eq!(
+        verify_commitment_degree(&degenerate, 3),
+        Err(CommitmentError::IdentityCommitment { index: 2 })
+    );
+}
+
+#[test]
+fn test_point_secret_sharing() {
+    let mut rng = OsRng;
+    let secret = ProjectivePoint::random(&mut rng);
+    let shares = generate_point_shares(secret, 5, 3, &mut rng).unwrap();
+
+    assert_eq!(reconstruct_point_secret(&shares[0..3]), Ok(secret));
+    assert_eq!(reconstruct_point_secret(&shares[2..5]), Ok(secret));
+    assert_eq!(reconstruct_point_secret(&[shares[0], shares[3], shares[4]]), Ok(secret));
+    // 少于 t 个份额得不到秘密点
+    assert_ne!(reconstruct_point_secret(&shares[0..2]), Ok(secret));
+    assert_eq!(
+        reconstruct_point_secret(&[shares[0], shares[0]]),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_point_secret_sharing_rejects_invalid_threshold() {
+    let mut rng = OsRng;
+    let secret = ProjectivePoint::random(&mut rng);
+    // t = 0 时没有随机系数，每个份额都会是秘密点本身
+    assert_eq!(generate_point_shares(secret, 5, 0, &mut rng), Err(ShareError::ZeroThreshold));
+    assert_eq!(generate_point_shares(secret, 3, 4, &mut rng), Err(ShareError::ThresholdExceedsShares));
+    assert_eq!(generate_point_shares(secret, 0, 1, &mut rng), Err(ShareError::ZeroShares));
+}
+
+#[test]
+fn test_secret_sharing_error_variants() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let g = ProjectivePoint::GENERATOR;
+    let h = ProjectivePoint::random(&mut rng);
+
+    // InvalidThreshold：t = 0 和 t > n 不再 panic
+    assert_eq!(
+        try_generate_shares_with_feldman_vss(secret, 5, 0, g, &mut rng),
+        Err(SecretSharingError::InvalidThreshold)
+    );
+    assert_eq!(
+        try_generate_shares_with_pedersen_vss(secret, 3, 4, g, h, &mut rng),
+        Err(SecretSharingError::InvalidThreshold)
+    );
+
+    let (shares, commitments) = try_generate_shares_with_feldman_vss(secret, 5, 3, g, &mut rng).unwrap();
+    assert_eq!(try_reconstruct_secret(&shares[1..4]), Ok(secret));
+    assert_eq!(try_verify_share_with_feldman_vss(shares[0], &commitments, g, 3), Ok(true));
+
+    // InsufficientShares
+    assert_eq!(try_reconstruct_secret::<Scalar>(&[]), Err(SecretSharingError::InsufficientShares));
+
+    // DuplicateShareIndex
+    assert_eq!(
+        try_reconstruct_secret(&[shares[0], shares[1], shares[0]]),
+        Err(SecretSharingError::DuplicateShareIndex)
+    );
+
+    // ZeroShareIndex：x = 0 处的份额就是秘密本身
+    let leaked = (Scalar::ZERO, secret);
+    assert_eq!(try_reconstruct_secret(&[leaked, shares[0]]), Err(SecretSharingError::ZeroShareIndex));
+    assert_eq!(
+        try_verify_share_with_feldman_vss(leaked, &commitments, g, 3),
+        Err(SecretSharingError::ZeroShareIndex)
+    );
+
+    // CommitmentLengthMismatch
+    assert_eq!(
+        try_verify_share_with_feldman_vss(shares[0], &commitments[..2], g, 3),
+        Err(SecretSharingError::CommitmentLengthMismatch { expected: 3, actual: 2 })
+    );
+
+    // 从 ShareError 的转换
+    assert_eq!(SecretSharingError::from(ShareError::ZeroShares), SecretSharingError::InvalidThreshold);
+    assert_eq!(SecretSharingError::from(ShareError::DuplicateShareIndex), SecretSharingError::DuplicateShareIndex);
+}
+
+#[test]
+fn test_reconstruct_secret_ct_matches_reconstruct_secret() {
+    let mut rng = OsRng;
+    for _ in 0..50 {
+        let t = rng.gen_range(1..=16);
+        let secret = Scalar::random(&mut rng);
+        let xs: Vec<Scalar> = (0..t).map(|_| Scalar::random(&mut rng)).collect();
+        let shares = generate_shares_at(secret, &xs, t, &mut rng).unwrap();
+        assert_eq!(reconstruct_secret_ct(&shares), Ok(reconstruct_secret(&shares)));
+        assert_eq!(reconstruct_secret_ct(&shares), Ok(secret));
+
+        // 任意输入（不一定来自同一多项式）也必须一致
+        let arbitrary: Vec<(Scalar, Scalar)> = (0..t).map(|_| (Scalar::random(&mut rng), Scalar::random(&mut rng))).collect();
+        assert_eq!(reconstruct_secret_ct(&arbitrary), Ok(reconstruct_secret(&arbitrary)));
+    }
+
+    let shares = generate_shares(Scalar::random(&mut rng), 5, 3, &mut rng);
+    assert_eq!(
+        reconstruct_secret_ct(&[shares[0], shares[1], shares[0]]),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_batched_inversion_matches_per_term() {
+    let mut rng = OsRng;
+    let t = 64;
+    let secret = Scalar::random(&mut rng);
+    let xs: Vec<Scalar> = (0..t).map(|_| Scalar::random(&mut rng)).collect();
+    let shares = generate_shares_at(secret, &xs, t, &mut rng).unwrap();
+    let x = Scalar::random(&mut rng);
+
+    // 逐项求逆的拉格朗日插值
+    let mut expected = Scalar::ZERO;
+    for (i, &(x_i, y_i)) in shares.iter().enumerate() {
+        let mut numerator = Scalar::ONE;
+        let mut denominator = Scalar::ONE;
+        for (j, &(x_j, _)) in shares.iter().enumerate() {
+            if i != j {
+                numerator *= x - x_j;
+                denominator *= x_i - x_j;
+            }
+        }
+        expected += y_i * numerator * denominator.invert().unwrap();
+    }
+
+    assert_eq!(interpolate_at(&shares, x), Ok(expected));
+    assert_eq!(reconstruct_secret(&shares), secret);
+}
+
+#[test]
+fn test_verify_secret_commitment() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let private_key = Scalar::random(&mut rng);
+    let pubkey = g * private_key;
+
+    let (_, commitments) = generate_shares_with_feldman_vss(private_key, 5, 3, g, &mut rng);
+    assert!(verify_secret_commitment(&commitments, pubkey, g));
+
+    // 其他私钥对应的公钥
+    let other = g * Scalar::random(&mut rng);
+    assert!(!verify_secret_commitment(&commitments, other, g));
+    assert!(!verify_secret_commitment(&[], pubkey, g));
+}
+
+#[test]
+fn test_indexed_api_matches_scalar_api() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let indices = [7u32, 42, 1000, u32::MAX];
+    let seed = [3u8; 32];
+
+    // 相同的随机数序列下，与基于标量的 API 结果一致
+    let indexed = generate_shares_indexed(secret, &indices, 3, &mut StdRng::from_seed(seed)).unwrap();
+    let xs: Vec<Scalar> = indices.iter().map(|&i| Scalar::from(u64::from(i))).collect();
+    let scalar = generate_shares_at(secret, &xs, 3, &mut StdRng::from_seed(seed)).unwrap();
+    for (&(i, y), &(x, y2)) in indexed.iter().zip(&scalar) {
+        assert_eq!(Scalar::from(u64::from(i)), x);
+        assert_eq!(y, y2);
+    }
+
+    assert_eq!(reconstruct_secret_indexed(&indexed[1..]), Ok(secret));
+    assert_eq!(reconstruct_secret_indexed(&indexed[..3]), Ok(reconstruct_secret(&scalar[..3])));
+
+    // 编号 0 会被拒绝
+    assert_eq!(generate_shares_indexed(secret, &[0, 1], 2, &mut rng), Err(ShareError::ZeroShareIndex));
+    assert_eq!(
+        reconstruct_secret_indexed(&[(0, secret), indexed[0]]),
+        Err(SecretSharingError::ZeroShareIndex)
+    );
+    assert_eq!(generate_shares_indexed(secret, &[5, 5], 2, &mut rng), Err(ShareError::DuplicateShareIndex));
+}
+
+#[test]
+fn test_commitments_bytes_round_trip() {
+    let mut rng = OsRng;
+    let (_, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), 5, 4, ProjectivePoint::GENERATOR, &mut rng);
+    let bytes = commitments_to_bytes(&commitments);
+    assert_eq!(bytes.len(), 4 + 33 * 4);
+    assert_eq!(commitments_from_bytes(&bytes), Ok(commitments));
+    assert_eq!(commitments_from_bytes(&commitments_to_bytes(&[])), Ok(Vec::new()));
+}
+
+#[test]
+fn test_commitments_from_bytes_rejects_malformed_input() {
+    let mut rng = OsRng;
+    let (_, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), 3, 2, ProjectivePoint::GENERATOR, &mut rng);
+    let bytes = commitments_to_bytes(&commitments);
+
+    assert_eq!(commitments_from_bytes(&bytes[..2]), Err(DecodeError::Truncated));
+    assert_eq!(commitments_from_bytes(&bytes[..bytes.len() - 1]), Err(DecodeError::Truncated));
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(commitments_from_bytes(&trailing), Err(DecodeError::TrailingBytes));
+
+    // 无效的压缩标签
+    let mut bad_tag = bytes.clone();
+    bad_tag[4] = 0x07;
+    assert_eq!(commitments_from_bytes(&bad_tag), Err(DecodeError::InvalidPoint));
+    // 紧凑编码不是压缩 SEC1 编码
+    bad_tag[4] = 0x05;
+    assert_eq!(commitments_from_bytes(&bad_tag), Err(DecodeError::InvalidPoint));
+
+    // x 坐标不在曲线上：在第二个点的 x 坐标上逐个尝试，直到解码失败
+    let mut off_curve = bytes.clone();
+    loop {
+        off_curve[4 + 33 + 32] = off_curve[4 + 33 + 32].wrapping_add(1);
+        if commitments_from_bytes(&off_curve).is_err() {
+            break;
+        }
+    }
+    assert_eq!(commitments_from_bytes(&off_curve), Err(DecodeError::InvalidPoint));
+}
+
+#[test]
+fn test_shares_consistent_with_threshold() {
+    let mut rng = OsRng;
+    let mut shares = generate_shares(Scalar::random(&mut rng), 6, 3, &mut rng);
+    assert!(shares_consistent_with_threshold(&shares, 3));
+    assert!(shares_consistent_with_threshold(&shares, 6));
+    // 6 个份额确实需要 3 个才能恢复，不与 t = 2 相符
+    assert!(!shares_consistent_with_threshold(&shares, 2));
+
+    // 篡改一个份额后，6 个点不再位于 2 次多项式上
+    shares[4].1 += Scalar::ONE;
+    assert!(!shares_consistent_with_threshold(&shares, 3));
+    assert!(!shares_consistent_with_threshold(&[shares[0], shares[0]], 3));
+    assert!(!shares_consistent_with_threshold(&shares, 0));
+}
+
+#[test]
+fn test_share_at_zero_is_rejected() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 3, 2, &mut rng);
+
+    // x = 0 处的份额就是秘密本身，所有接受 x 坐标的签发路径都必须拒绝
+    assert_eq!(
+        generate_shares_at(secret, &[Scalar::ONE, Scalar::ZERO], 2, &mut rng),
+        Err(ShareError::ZeroShareIndex)
+    );
+    assert_eq!(generate_shares_indexed(secret, &[0], 1, &mut rng), Err(ShareError::ZeroShareIndex));
+    assert_eq!(
+        reconstruct_secret_at_many(&shares, &[Scalar::from(9u64), Scalar::ZERO]),
+        Err(ReconstructError::ZeroShareIndex)
+    );
+}
+
+#[test]
+fn test_generate_shares_iter_matches_eager() {
+    let secret = Scalar::from(2024u64);
+    let eager = generate_shares(secret, 20, 7, &mut StdRng::seed_from_u64(9));
+    let lazy: Vec<_> = generate_shares_iter(secret, 20, 7, &mut StdRng::seed_from_u64(9)).collect();
+    assert_eq!(lazy, eager);
+
+    // 只取前 t 个份额即可恢复，不需要计算其余份额
+    let first: Vec<_> = generate_shares_iter(secret, 1_000_000, 7, &mut OsRng).take(7).collect();
+    assert_eq!(reconstruct_secret(&first), secret);
+}
+
+#[test]
+fn test_verify_share_set_consistency() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let mut shares = generate_shares(secret, 8, 3, &mut rng);
+    assert!(verify_share_set_consistency(&shares, 3));
+
+    // 混入一个来自其他多项式的份额
+    let planted = generate_shares(secret, 8, 3, &mut rng)[6];
+    shares[6] = planted;
+    assert!(!verify_share_set_consistency(&shares, 3));
+
+    assert!(!verify_share_set_consistency(&shares[..2], 3));
+    assert!(!verify_share_set_consistency(&shares, 0));
+    let duplicated = [shares[0], shares[1], shares[2], shares[0]];
+    assert!(!verify_share_set_consistency(&duplicated, 3));
+}
+
+#[test]
+fn test_reconstruct_secret_overdetermined() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let mut shares = generate_shares(secret, 6, 3, &mut rng);
+    assert_eq!(reconstruct_secret_overdetermined(&shares, 3), Ok(secret));
+    assert_eq!(reconstruct_secret_overdetermined(&shares[2..5], 3), Ok(secret));
+
+    // 多出的一个份额被篡改，前 t 个份额仍然正确，但不能悄悄返回结果
+    shares[5].1 += Scalar::ONE;
+    assert_eq!(reconstruct_secret_overdetermined(&shares, 3), Err(ReconstructError::InconsistentShares));
+    assert_eq!(reconstruct_secret_overdetermined(&shares[..2], 3), Err(ReconstructError::InsufficientShares));
+}
+
+#[test]
+fn test_reconstruct_secret_fast_matches() {
+    let mut rng = OsRng;
+    for t in 1..=9 {
+        let secret = Scalar::random(&mut rng);
+        let mut shares = generate_shares(secret, t + 3, t, &mut rng);
+        shares.shuffle(&mut rng);
+        let subset = &shares[..t];
+        let fast = reconstruct_secret_fast(subset).unwrap();
+        assert_eq!(fast.to_bytes(), reconstruct_secret(subset).to_bytes());
+        assert_eq!(fast, secret);
+    }
+
+    let shares = generate_shares(Scalar::ONE, 3, 2, &mut rng);
+    assert_eq!(reconstruct_secret_fast(&[shares[0], shares[0]]), Err(ReconstructError::DuplicateShareIndex));
+    assert_eq!(
+        reconstruct_secret_fast(&[shares[0], (Scalar::ZERO, Scalar::ONE)]),
+        Err(ReconstructError::ZeroShareIndex)
+    );
+}
+
+#[test]
+fn test_threshold_one_every_share_is_the_secret() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_shares(secret, 5, 1, &mut OsRng);
+    assert_eq!(shares.len(), 5);
+    for share in &shares {
+        assert_eq!(share.1, secret);
+        assert_eq!(reconstruct_secret(&[*share]), secret);
+    }
+    assert_eq!(try_reconstruct_secret(&shares[3..4]).unwrap(), secret);
+    assert_eq!(reconstruct_secret_fast(&shares[..1]).unwrap(), secret);
+    assert_eq!(generate_shares_iter(secret, 5, 1, &mut OsRng).collect::<Vec<_>>(), shares);
+}
+
+#[test]
+fn test_threshold_equals_n_requires_all_shares() {
+    let secret = Scalar::random(&mut OsRng);
+    let n = 5;
+    let shares = generate_shares(secret, n, n, &mut OsRng);
+    assert_eq!(reconstruct_secret(&shares), secret);
+    assert_eq!(reconstruct_secret(&shares.iter().rev().copied().collect::<Vec<_>>()), secret);
+    // 去掉任意一个份额后剩余 n-1 个份额得不到正确的秘密
+    for skip in 0..n {
+        let subset: Vec<_> = shares.iter().enumerate().filter(|&(i, _)| i != skip).map(|(_, s)| *s).collect();
+        assert_ne!(reconstruct_secret(&subset), secret);
+    }
+}
+
+#[test]
+fn test_reconstruct_and_verify() {
+    let secret = Scalar::random(&mut OsRng);
+    let g = ProjectivePoint::GENERATOR;
+    let (shares, commitments) = generate_shares_with_feldman_vss(secret, 5, 3, g, &mut OsRng);
+    assert_eq!(reconstruct_and_verify(&shares[1..4], &commitments, g), Ok(secret));
+
+    // 篡改 t 个份额中的一个，恢复出的值与 C_0 不符
+    let mut tampered = shares[..3].to_vec();
+    tampered[1].1 += Scalar::ONE;
+    assert_eq!(reconstruct_and_verify(&tampered, &commitments, g), Err(ReconstructError::CommitmentMismatch));
+
+    assert_eq!(reconstruct_and_verify(&shares[..3], &[], g), Err(ReconstructError::CommitmentMismatch));
+    assert_eq!(reconstruct_and_verify::<ProjectivePoint>(&[], &commitments, g), Err(ReconstructError::InsufficientShares));
+    assert_eq!(
+        reconstruct_and_verify(&[shares[0], shares[0]], &commitments, g),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_generate_shares_with_polynomial() {
+    let secret = Scalar::random(&mut OsRng);
+    let (shares, poly) = generate_shares_with_polynomial(secret, 6, 4, &mut OsRng);
+    assert_eq!(shares.len(), 6);
+    assert_eq!(poly.degree(), 3);
+    assert_eq!(poly.evaluate(Scalar::ZERO), secret);
+    for &(x, y) in &shares {
+        assert_eq!(poly.evaluate(x), y);
+    }
+
+    // 与相同随机数状态下的 generate_shares 结果一致
+    let (shares, _) = generate_shares_with_polynomial(secret, 6, 4, &mut StdRng::seed_from_u64(7));
+    assert_eq!(shares, generate_shares_with_rng_unchecked(secret, 6, 4, &mut StdRng::seed_from_u64(7)));
+}
+
+#[test]
+fn test_split_private_key() {
+    let g = ProjectivePoint::GENERATOR;
+    let sk = Scalar::random(&mut OsRng);
+    let pk = g * sk;
+    let (shares, commitments) = split_private_key(sk, pk, 5, 3, g, &mut OsRng).unwrap();
+    assert_eq!(commitments[0], pk);
+    assert!(verify_shares_with_feldman_vss(&shares, &commitments, g).into_iter().all(|ok| ok));
+    assert_eq!(reconstruct_secret(&shares[2..]), sk);
+
+    let other = g * Scalar::random(&mut OsRng);
+    assert_eq!(split_private_key(sk, other, 5, 3, g, &mut OsRng), Err(SecretSharingError::PublicKeyMismatch));
+    assert_eq!(split_private_key(sk, pk, 2, 3, g, &mut OsRng), Err(SecretSharingError::InvalidThreshold));
+}
+
+#[test]
+fn test_generate_shares_const() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares: [(Scalar, Scalar); 5] = generate_shares_const(secret, 3, &mut OsRng);
+    for i in 0..5 {
+        for j in i + 1..5 {
+            for k in j + 1..5 {
+                assert_eq!(reconstruct_secret(&[shares[i], shares[j], shares[k]]), secret);
+            }
+        }
+    }
+
+    let expected = generate_shares_with_rng_unchecked(secret, 5, 3, &mut StdRng::seed_from_u64(3));
+    let shares = generate_shares_const::<_, _, 5>(secret, 3, &mut StdRng::seed_from_u64(3));
+    assert_eq!(shares.to_vec(), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_generate_shares_const_threshold_exceeds_n() {
+    let _: [(Scalar, Scalar); 2] = generate_shares_const(Scalar::ONE, 3, &mut OsRng);
+}
+
+#[test]
+fn test_verify_share_pedersen_precomputed() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let h = g * Scalar::random(&mut rng);
+    let secret = Scalar::random(&mut rng);
+    let (shares, commitments, blinding_values) = generate_shares_with_pedersen_vss(secret, 5, 3, g, h, &mut rng);
+
+    for (share, &(bx, r)) in shares.iter().zip(&blinding_values) {
+        assert_eq!(share.0, bx);
+        assert!(verify_share_pedersen_precomputed(*share, r, &commitments, g, h));
+        assert!(verify_share_with_pedersen_vss(*share, r, &commitments, g, h));
+    }
+
+    // 篡改份额的值、盲化值，或把份额与其他参与者的盲化值搭配，两个验证函数都拒绝
+    let (x, y) = shares[1];
+    let r = blinding_values[1].1;
+    let cases = [
+        ((x, y + Scalar::ONE), r),
+        ((x, y), r + Scalar::ONE),
+        ((x, y), blinding_values[2].1),
+        ((x + Scalar::ONE, y), r),
+    ];
+    for (share, r) in cases {
+        assert!(!verify_share_pedersen_precomputed(share, r, &commitments, g, h));
+        assert_eq!(
+            verify_share_pedersen_precomputed(share, r, &commitments, g, h),
+            verify_share_with_pedersen_vss(share, r, &commitments, g, h)
+        );
+    }
+}
+
+#[test]
+fn test_independent_generator() {
+    let h = independent_generator(b"example/pedersen/h");
+    assert_eq!(h, independent_generator(b"example/pedersen/h"));
+    assert!(!bool::from(h.is_identity()));
+    assert_ne!(h, ProjectivePoint::GENERATOR);
+    assert_ne!(h, independent_generator(b"example/pedersen/h2"));
+
+    // 压缩编码可以解码回同一个曲线上的点
+    let encoded = h.to_bytes();
+    assert_eq!(ProjectivePoint::from_bytes(&encoded).unwrap(), h);
+
+    // 作为 Pedersen 的 h 使用
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut OsRng);
+    let (shares, commitments, blinding_values) = generate_shares_with_pedersen_vss(secret, 4, 2, g, h, &mut OsRng);
+    assert!(verify_share_with_pedersen_vss(shares[3], blinding_values[3].1, &commitments, g, h));
+}
+
+#[test]
+fn test_reconstruct_from_iter() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_shares(secret, 6, 3, &mut OsRng);
+
+    // 重复的份额被跳过，取够 3 个不同的份额后不再读取
+    let stream = vec![shares[0], shares[0], shares[4], shares[0], shares[2], shares[1], shares[5]];
+    let mut iter = stream.into_iter();
+    assert_eq!(reconstruct_from_iter(iter.by_ref(), 3), Ok(secret));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![shares[1], shares[5]]);
+
+    let stream = vec![shares[0], shares[1], shares[1]];
+    assert_eq!(reconstruct_from_iter(stream.into_iter(), 3), Err(ReconstructError::InsufficientShares));
+
+    let conflicting = (shares[1].0, shares[1].1 + Scalar::ONE);
+    let stream = vec![shares[1], conflicting, shares[2]];
+    assert_eq!(reconstruct_from_iter(stream.into_iter(), 3), Err(ReconstructError::DuplicateShareIndex));
+
+    let stream = vec![shares[1], (Scalar::ZERO, secret)];
+    assert_eq!(reconstruct_from_iter(stream.into_iter(), 2), Err(ReconstructError::ZeroShareIndex));
+}
+
+#[test]
+fn test_add_share_sets() {
+    let secret_a = Scalar::random(&mut OsRng);
+    let secret_b = Scalar::random(&mut OsRng);
+    let a = generate_shares(secret_a, 5, 3, &mut OsRng);
+    let b = generate_shares(secret_b, 5, 3, &mut OsRng);
+    let sum = add_share_sets(&a, &b).unwrap();
+    assert_eq!(sum.len(), 5);
+    assert_eq!(reconstruct_secret(&sum[..3]), secret_a + secret_b);
+    assert_eq!(reconstruct_secret(&sum[2..]), secret_a + secret_b);
+
+    // 长度不同或 x 坐标不对齐
+    assert_eq!(add_share_sets(&a, &b[..4]), Err(IndexMismatch));
+    let reversed: Vec<_> = b.iter().rev().copied().collect();
+    assert_eq!(add_share_sets(&a, &reversed), Err(IndexMismatch));
+}
+
+#[test]
+fn test_scale_share_set() {
+    let secret = Scalar::random(&mut OsRng);
+    let factor = Scalar::random(&mut OsRng);
+    let shares = generate_shares(secret, 5, 3, &mut OsRng);
+    let scaled = scale_share_set(&shares, factor);
+    assert!(scaled.iter().zip(&shares).all(|(s, o)| s.0 == o.0));
+    assert_eq!(reconstruct_secret(&scaled[..3]), secret * factor);
+    assert_eq!(reconstruct_secret(&scaled[1..4]), secret * factor);
+
+    // 多项式的次数不变
+    assert_eq!(Polynomial::lagrange_interpolate(&scaled).unwrap().degree(), 2);
+    assert!(shares_consistent_with_threshold(&scaled, 3));
+}
+
+#[test]
+fn test_reconstruct_secret_checked() {
+    let secret = Scalar::random(&mut OsRng);
+    let shares = generate_shares(secret, 5, 3, &mut OsRng);
+    assert_eq!(reconstruct_secret_checked(&shares[..3], 3), Ok(secret));
+    assert_eq!(reconstruct_secret_checked(&shares, 3), Ok(secret));
+
+    // t-1 个份额返回错误，而不是一个错误的标量
+    assert_eq!(reconstruct_secret_checked(&shares[..2], 3), Err(ReconstructError::InsufficientShares));
+    assert_eq!(reconstruct_secret_checked(&shares, 0), Err(ReconstructError::InsufficientShares));
+    assert_eq!(
+        reconstruct_secret_checked(&[shares[0], shares[1], shares[0]], 3),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_lagrange_coefficients_reused_across_y_vectors() {
+    let mut rng = OsRng;
+    let first = generate_shares(Scalar::random(&mut rng), 5, 3, &mut rng);
+    let second = generate_shares(Scalar::random(&mut rng), 5, 3, &mut rng);
+    let subset = [0, 2, 4];
+    let xs: Vec<Scalar> = subset.iter().map(|&i| first[i].0).collect();
+
+    // 同一组 x 坐标的系数只计算一次，分别作用于两组 y 值
+    let coefficients = lagrange_coefficients(&xs, Scalar::ZERO).unwrap();
+    for shares in [&first, &second] {
+        let selected: Vec<(Scalar, Scalar)> = subset.iter().map(|&i| shares[i]).collect();
+        let secret = selected
+            .iter()
+            .zip(&coefficients)
+            .fold(Scalar::ZERO, |acc, (&(_, y), &c)| acc + y * c);
+        assert_eq!(secret, reconstruct_secret(&selected));
+    }
+
+    // 在其他点处的系数给出该点的份额
+    let at = first[1].0;
+    let coefficients = lagrange_coefficients(&xs, at).unwrap();
+    let value = subset
+        .iter()
+        .zip(&coefficients)
+        .fold(Scalar::ZERO, |acc, (&i, &c)| acc + first[i].1 * c);
+    assert_eq!(value, first[1].1);
+
+    assert_eq!(
+        lagrange_coefficients(&[xs[0], xs[0]], Scalar::ZERO),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_threshold_zero_and_above_max_rejected_before_allocation() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+
+    assert_eq!(try_generate_shares(secret, 5, 0, &mut rng), Err(ShareError::ZeroThreshold));
+    assert_eq!(
+        try_generate_shares(secret, MAX_THRESHOLD + 1, MAX_THRESHOLD + 1, &mut rng),
+        Err(ShareError::ThresholdTooLarge)
+    );
+    // 上限的检查先于 t > n 的检查，t 接近 usize::MAX 时也不会分配内存
+    assert_eq!(try_generate_shares(secret, 5, usize::MAX, &mut rng), Err(ShareError::ThresholdTooLarge));
+    assert_eq!(generate_shares_at(secret, &[Scalar::ONE], 0, &mut rng), Err(ShareError::ZeroThreshold));
+    assert_eq!(generate_shares_indexed(secret, &[1, 2], usize::MAX, &mut rng), Err(ShareError::ThresholdTooLarge));
+    assert_eq!(
+        SecretSharingError::from(ShareError::ThresholdTooLarge),
+        SecretSharingError::InvalidThreshold
+    );
+}
+
+#[test]
+#[should_panic(expected = "threshold must be between 1 and MAX_THRESHOLD")]
+fn test_generate_shares_panics_on_zero_threshold() {
+    let mut rng = OsRng;
+    generate_shares(Scalar::ONE, 5, 0, &mut rng);
+}
+
+#[test]
+#[should_panic(expected = "threshold must be between 1 and MAX_THRESHOLD")]
+fn test_feldman_panics_on_threshold_above_max() {
+    let mut rng = OsRng;
+    generate_shares_with_feldman_vss(Scalar::ONE, 5, usize::MAX, ProjectivePoint::GENERATOR, &mut rng);
+}
+
+#[test]
+fn test_reconstruct_secret_columnar_matches_tuples() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 6, 4, &mut rng);
+    let xs: Vec<Scalar> = shares.iter().map(|&(x, _)| x).collect();
+    let ys: Vec<Scalar> = shares.iter().map(|&(_, y)| y).collect();
+
+    assert_eq!(reconstruct_secret_columnar(&xs, &ys), Ok(reconstruct_secret(&shares)));
+    assert_eq!(reconstruct_secret_columnar(&xs[2..], &ys[2..]), Ok(secret));
+
+    assert_eq!(reconstruct_secret_columnar(&xs, &ys[1..]), Err(ReconstructError::InconsistentShares));
+    assert_eq!(
+        reconstruct_secret_columnar::<Scalar>(&[], &[]),
+        Err(ReconstructError::InsufficientShares)
+    );
+    assert_eq!(
+        reconstruct_secret_columnar(&[xs[0], xs[0]], &ys[..2]),
+        Err(ReconstructError::DuplicateShareIndex)
+    );
+}
+
+#[test]
+fn test_generate_shares_random_indices() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let (n, t) = (6, 3);
+    let shares = generate_shares_random_indices(secret, n, t, &mut rng).unwrap();
+    assert_eq!(shares.len(), n);
+
+    let xs: BTreeSet<[u8; 32]> = shares.iter().map(|(x, _)| x.to_bytes().into()).collect();
+    assert_eq!(xs.len(), n);
+    assert!(shares.iter().all(|(x, _)| !bool::from(x.is_zero())));
+    // x 坐标不是 1 到 n
+    assert!(shares.iter().all(|&(x, _)| (1..=n as u64).all(|i| x != Scalar::from(i))));
+
+    // 任意 t 个份额都能恢复秘密
+    for i in 0..n {
+        for j in i + 1..n {
+            for k in j + 1..n {
+                assert_eq!(reconstruct_secret(&[shares[i], shares[j], shares[k]]), secret);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generate_shares_random_indices_rejects_invalid_parameters() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    assert_eq!(generate_shares_random_indices(secret, 2, 3, &mut rng), Err(ShareError::ThresholdExceedsShares));
+    assert_eq!(generate_shares_random_indices(secret, 3, 0, &mut rng), Err(ShareError::ZeroThreshold));
+    assert_eq!(generate_shares_random_indices(secret, 0, 1, &mut rng), Err(ShareError::ZeroShares));
+
+    // GF(257) 只有 256 个非零元素，n = 257 时不可能抽出互不相同的 x 坐标
+    let secret = Gf257::new(7);
+    assert_eq!(generate_shares_random_indices(secret, 257, 2, &mut rng), Err(ShareError::ZeroShareIndex));
+    let shares = generate_shares_random_indices(secret, 256, 2, &mut rng).unwrap();
+    let xs: BTreeSet<u16> = shares.iter().map(|(x, _)| x.value()).collect();
+    assert_eq!(xs.len(), 256);
+    assert!(!xs.contains(&0));
+    assert_eq!(reconstruct_secret(&shares[100..102]), secret);
+}
+
+#[test]
+fn test_feldman_verifier_matches_standalone_verification() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let (shares, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), 20, 5, g, &mut rng);
+    let verifier = FeldmanVerifier::new(&commitments, g);
+
+    for &(x, y) in &shares {
+        assert!(verifier.verify((x, y)));
+        assert!(!verifier.verify((x, y + Scalar::ONE)));
+        assert!(!verifier.verify((x + Scalar::ONE, y)));
+    }
+
+    // x 超过 64 比特时走通用路径
+    let x = Scalar::random(&mut rng);
+    let y = interpolate_at(&shares[..5], x).unwrap();
+    assert!(verify_share_with_feldman_vss((x, y), &commitments, g));
+    assert!(verifier.verify((x, y)));
+    assert!(!verifier.verify((x, y + Scalar::ONE)));
+
+    // 其他生成元
+    let h = independent_generator(b"feldman_verifier_test");
+    let (shares, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), 4, 3, h, &mut rng);
+    let verifier = FeldmanVerifier::new(&commitments, h);
+    assert!(shares.iter().all(|&share| verifier.verify(share)));
+    assert!(!FeldmanVerifier::new(&commitments, g).verify(shares[0]));
+}
+
+#[test]
+fn test_reconstruct_secret_with_contributions() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+    let (reconstructed, contributions) = reconstruct_secret_with_contributions(&shares[1..4]);
+    assert_eq!(reconstructed, secret);
+    assert_eq!(contributions.len(), 3);
+    assert_eq!(contributions.iter().fold(Scalar::ZERO, |acc, c| acc + c), secret);
+
+    // f(x) = 7 + 2x，份额 (1, 9) 和 (2, 11)：λ_1 = 2，λ_2 = -1
+    let shares = [
+        (Scalar::from(1u64), Scalar::from(9u64)),
+        (Scalar::from(2u64), Scalar::from(11u64)),
+    ];
+    let (reconstructed, contributions) = reconstruct_secret_with_contributions(&shares);
+    assert_eq!(reconstructed, Scalar::from(7u64));
+    assert_eq!(contributions, vec![Scalar::from(18u64), -Scalar::from(11u64)]);
+}
+
+#[test]
+fn test_default_pedersen_generators() {
+    let (g, h) = default_pedersen_generators();
+    assert_eq!(g, ProjectivePoint::GENERATOR);
+    // 可复现：每次得到相同的 h，且与按文档中的域分隔字符串派生的结果一致
+    assert_eq!(default_pedersen_generators(), (g, h));
+    assert_eq!(h, independent_generator(b"shamir_secret_sharing/pedersen/h"));
+    let encoded: String = h.to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(encoded, "0357f66be1f2e63bcdec654cb7d1a405ffc51d1c06b933f0e535ddfcb77e27c007");
+    // h 由哈希决定，不是 g 的小倍数，也不是单位元
+    assert!(!bool::from(h.is_identity()));
+    assert!((1u64..=16).all(|k| h != g * Scalar::from(k) && h != -(g * Scalar::from(k))));
+
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let (shares, commitments, blinding) = split_pedersen_default(secret, 5, 3, &mut rng);
+    for (&share, &(_, r)) in shares.iter().zip(&blinding) {
+        assert!(verify_share_with_pedersen_vss(share, r, &commitments, g, h));
+    }
+    assert_eq!(reconstruct_secret(&shares[..3]), secret);
+}
+
+#[test]
+fn test_truncated_commitments_report_length_mismatch() {
+    let mut rng = OsRng;
+    let g = ProjectivePoint::GENERATOR;
+    let (shares, commitments) = generate_shares_with_feldman_vss(Scalar::random(&mut rng), 5, 3, g, &mut rng);
+    let truncated = &commitments[..2];
+
+    // 不检查个数的验证只能得到 false，无法区分份额错误与承诺被截断
+    assert!(!verify_share_with_feldman_vss(shares[0], truncated, g));
+
+    // 知道门限值的验证明确报告承诺个数不符
+    assert_eq!(
+        try_verify_share_with_feldman_vss(shares[0], truncated, g, 3),
+        Err(SecretSharingError::CommitmentLengthMismatch { expected: 3, actual: 2 })
+    );
+    assert!(matches!(
+        FeldmanVerifier::with_threshold(truncated, g, 3),
+        Err(CommitmentError::LengthMismatch { expected: 3, actual: 2 })
+    ));
+
+    let verifier = FeldmanVerifier::with_threshold(&commitments, g, 3).unwrap();
+    assert!(shares.iter().all(|&share| verifier.verify(share)));
+    assert_eq!(try_verify_share_with_feldman_vss(shares[0], &commitments, g, 3), Ok(true));
+}
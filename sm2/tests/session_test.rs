@@ -0,0 +1,80 @@
+use rand::{rngs::OsRng, RngCore};
+use shamir_secret_sharing::error::ShareError;
+use shamir_secret_sharing::secret_sharing::generate_shares;
+use shamir_secret_sharing::session::{session_message, SessionReconstructor, SubmitError};
+use sm2::dsa::signature::Signer;
+use sm2::dsa::{Signature, SigningKey};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{Scalar, SecretKey};
+
+#[test]
+fn test_session_rejects_replay_and_accepts_fresh() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let n = 5;
+    let t = 3;
+    let shares = generate_shares(secret, n, t, &mut rng);
+
+    // 每个参与者持有一个 SM2 签名私钥
+    let signing_keys: Vec<SigningKey> = (0..n)
+        .map(|_| SigningKey::new("participant", &SecretKey::random(&mut rng)).unwrap())
+        .collect();
+    let participant_keys = shares
+        .iter()
+        .zip(&signing_keys)
+        .map(|(&(x, _), key)| (x, key.verifying_key().clone()))
+        .collect();
+
+    // 上一次会话的挑战值和本次会话的挑战值
+    let mut old_challenge = [0u8; 32];
+    let mut challenge = [0u8; 32];
+    rng.fill_bytes(&mut old_challenge);
+    rng.fill_bytes(&mut challenge);
+
+    let mut session = SessionReconstructor::new(challenge, t, participant_keys).unwrap();
+
+    // 重放上一次会话中的提交应当被拒绝
+    let (x, y) = shares[0];
+    let old_sig: Signature = signing_keys[0].sign(&session_message(&old_challenge, x, y));
+    assert_eq!(session.submit(x, y, old_challenge, &old_sig), Err(SubmitError::StaleNonce));
+
+    // 使用错误的密钥签名应当被拒绝
+    let bad_sig: Signature = signing_keys[1].sign(&session_message(&challenge, x, y));
+    assert_eq!(session.submit(x, y, challenge, &bad_sig), Err(SubmitError::InvalidSignature));
+    assert_eq!(session.reconstruct(), None);
+
+    // 新鲜的提交被接受
+    for i in 0..t {
+        let (x, y) = shares[i];
+        let sig: Signature = signing_keys[i].sign(&session_message(&challenge, x, y));
+        assert_eq!(session.submit(x, y, challenge, &sig), Ok(()));
+    }
+
+    // 同一参与者不能重复提交
+    let sig: Signature = signing_keys[0].sign(&session_message(&challenge, x, y));
+    assert_eq!(session.submit(x, y, challenge, &sig), Err(SubmitError::DuplicateSubmission));
+
+    assert_eq!(session.accepted(), t);
+    assert_eq!(session.reconstruct(), Some(secret));
+}
+
+#[test]
+fn test_session_rejects_invalid_threshold() {
+    let mut rng = OsRng;
+    let participant_keys: Vec<_> = (1..=3u64)
+        .map(|i| {
+            let key = SigningKey::new("participant", &SecretKey::random(&mut rng)).unwrap();
+            (Scalar::from(i), key.verifying_key().clone())
+        })
+        .collect();
+
+    // t = 0 的会话会在没有任何提交时“恢复”出 0
+    assert!(matches!(
+        SessionReconstructor::new([0u8; 32], 0, participant_keys.clone()),
+        Err(ShareError::ZeroThreshold)
+    ));
+    assert!(matches!(
+        SessionReconstructor::new([0u8; 32], 4, participant_keys),
+        Err(ShareError::ThresholdExceedsShares)
+    ));
+}
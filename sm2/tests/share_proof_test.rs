@@ -0,0 +1,46 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::secret_sharing::generate_shares_with_feldman_vss;
+use shamir_secret_sharing::share_proof::{prove_share_knowledge, verify_share_proof, ShareProof};
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+#[test]
+fn test_valid_share_proof() {
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut OsRng);
+    let (shares, commitments) = generate_shares_with_feldman_vss(secret, 5, 3, g, &mut OsRng);
+    for &share in &shares {
+        let proof = prove_share_knowledge(share, &commitments, g, &mut OsRng);
+        assert!(verify_share_proof(&proof, share.0, &commitments, g));
+    }
+}
+
+#[test]
+fn test_forged_share_proof() {
+    let g = ProjectivePoint::GENERATOR;
+    let secret = Scalar::random(&mut OsRng);
+    let (shares, commitments) = generate_shares_with_feldman_vss(secret, 5, 3, g, &mut OsRng);
+
+    // 篡改过的份额值无法给出有效证明
+    let (x, y) = shares[0];
+    let proof = prove_share_knowledge((x, y + Scalar::ONE), &commitments, g, &mut OsRng);
+    assert!(!verify_share_proof(&proof, x, &commitments, g));
+
+    // 有效的证明不能挪用到其他 x 坐标或其他承诺
+    let proof = prove_share_knowledge(shares[1], &commitments, g, &mut OsRng);
+    assert!(!verify_share_proof(&proof, shares[2].0, &commitments, g));
+    let (_, other_commitments) = generate_shares_with_feldman_vss(secret, 5, 3, g, &mut OsRng);
+    assert!(!verify_share_proof(&proof, shares[1].0, &other_commitments, g));
+
+    // 随意构造的证明无法通过验证
+    let forged = ShareProof {
+        challenge: Scalar::random(&mut OsRng),
+        response: Scalar::random(&mut OsRng),
+    };
+    assert!(!verify_share_proof(&forged, x, &commitments, g));
+    let tampered = ShareProof {
+        response: proof.response + Scalar::ONE,
+        ..proof
+    };
+    assert!(!verify_share_proof(&tampered, shares[1].0, &commitments, g));
+}
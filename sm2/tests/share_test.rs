@@ -0,0 +1,140 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::{DecodeError, IndexMismatch};
+use shamir_secret_sharing::secret_sharing;
+use shamir_secret_sharing::share::{generate_shares, reconstruct_secret, Share, ShareSet};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_share_arithmetic() {
+    let mut rng = OsRng;
+    let a = Scalar::random(&mut rng);
+    let b = Scalar::random(&mut rng);
+    let c = Scalar::random(&mut rng);
+    let t = 3;
+
+    let shares_a = generate_shares(a, 5, t, &mut rng);
+    let shares_b = generate_shares(b, 5, t, &mut rng);
+
+    // 加法
+    let sum: Vec<Share> = shares_a.iter().zip(&shares_b).map(|(&x, &y)| x + y).collect();
+    assert_eq!(reconstruct_secret(&sum[0..t]), a + b);
+
+    // 减法
+    let diff: Vec<Share> = shares_a.iter().zip(&shares_b).map(|(&x, &y)| x - y).collect();
+    assert_eq!(reconstruct_secret(&diff[0..t]), a - b);
+
+    // 乘以公开常数
+    let scaled: Vec<Share> = shares_a.iter().map(|&x| x * c).collect();
+    assert_eq!(reconstruct_secret(&scaled[0..t]), a * c);
+}
+
+#[test]
+fn test_share_index_mismatch() {
+    let a = Share::new(Scalar::from(1u64), Scalar::from(10u64));
+    let b = Share::new(Scalar::from(2u64), Scalar::from(20u64));
+
+    assert_eq!(a.checked_add(&b), Err(IndexMismatch));
+    assert_eq!(a.checked_sub(&b), Err(IndexMismatch));
+    assert_eq!(a.checked_add(&a).unwrap().value(), Scalar::from(20u64));
+}
+
+#[test]
+#[should_panic]
+fn test_share_add_panics_on_mismatch() {
+    let a = Share::new(Scalar::from(1u64), Scalar::ONE);
+    let b = Share::new(Scalar::from(2u64), Scalar::ONE);
+    let _ = a + b;
+}
+
+#[test]
+fn test_typed_share_round_trip() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.index(), Scalar::from(i as u64 + 1));
+    }
+    assert_eq!(reconstruct_secret(&shares[1..4]), secret);
+
+    // 与元组形式的接口互相转换
+    let tuples: Vec<(Scalar, Scalar)> = shares.iter().map(|&s| s.into()).collect();
+    assert_eq!(secret_sharing::reconstruct_secret(&tuples[0..3]), secret);
+    let back: Vec<Share> = tuples.into_iter().map(Share::from).collect();
+    assert_eq!(back, shares);
+}
+
+#[test]
+fn test_share_set_round_trip() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let set = ShareSet::new(secret_sharing::generate_shares(secret, 5, 3, &mut rng));
+
+    let bytes = set.to_bytes();
+    assert_eq!(bytes.len(), 7 + 5 * 64);
+
+    let decoded = ShareSet::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, set);
+    assert_eq!(secret_sharing::reconstruct_secret(&decoded.shares()[0..3]), secret);
+}
+
+#[test]
+fn test_share_set_rejects_malformed_input() {
+    let mut rng = OsRng;
+    let set = ShareSet::new(secret_sharing::generate_shares(Scalar::random(&mut rng), 5, 3, &mut rng));
+    let bytes = set.to_bytes();
+
+    // 截断的输入
+    assert_eq!(ShareSet::from_bytes(&bytes[..bytes.len() - 1]), Err(DecodeError::Truncated));
+    assert_eq!(ShareSet::from_bytes(&bytes[..5]), Err(DecodeError::Truncated));
+
+    // 多余的字节
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert_eq!(ShareSet::from_bytes(&extended), Err(DecodeError::TrailingBytes));
+
+    // 不支持的版本
+    let mut wrong_version = bytes.clone();
+    wrong_version[2] = 9;
+    assert_eq!(ShareSet::from_bytes(&wrong_version), Err(DecodeError::UnsupportedVersion));
+
+    // 不规范的标量
+    let mut non_canonical = bytes.clone();
+    non_canonical[7..39].copy_from_slice(&[0xff; 32]);
+    assert_eq!(ShareSet::from_bytes(&non_canonical), Err(DecodeError::NonCanonicalScalar));
+}
+
+#[test]
+fn test_share_formatting_masks_value() {
+    let value = Scalar::random(&mut OsRng);
+    let share = Share::new(Scalar::from(3u64), value);
+    let value_hex: String = value.to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+
+    assert_eq!(format!("{}", share), "share #3 (value redacted)");
+    assert_eq!(format!("{:?}", share), "Share { index: 3, value: .. }");
+    assert_eq!(format!("{}", share.reveal()), format!("share #3 = 0x{}", value_hex));
+    assert!(!format!("{:?}", ShareSet::new(vec![(share.index(), value)])).contains(&value_hex));
+}
+
+#[test]
+fn test_share_fingerprint_is_stable() {
+    let share = Share::new(Scalar::from(3u64), Scalar::random(&mut OsRng));
+    let copy = Share::new(share.index(), share.value());
+    assert_eq!(share.fingerprint(), share.fingerprint());
+    assert_eq!(share.fingerprint(), copy.fingerprint());
+}
+
+#[test]
+fn test_share_fingerprint_distinguishes_shares() {
+    let shares = generate_shares(Scalar::random(&mut OsRng), 20, 3, &mut OsRng);
+    let mut fingerprints: Vec<[u8; 8]> = shares.iter().map(Share::fingerprint).collect();
+    fingerprints.sort_unstable();
+    fingerprints.dedup();
+    assert_eq!(fingerprints.len(), shares.len());
+
+    // 只改变 x 坐标或只改变值，指纹都会不同
+    let share = shares[0];
+    assert_ne!(share.fingerprint(), Share::new(share.index() + Scalar::ONE, share.value()).fingerprint());
+    assert_ne!(share.fingerprint(), Share::new(share.index(), share.value() + Scalar::ONE).fingerprint());
+}
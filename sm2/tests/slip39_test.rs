@@ -0,0 +1,40 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::error::{ReconstructError, ShareError};
+use shamir_secret_sharing::slip39::{reconstruct_slip39, split_slip39};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_slip39_two_of_three_groups() {
+    let secret = Scalar::random(&mut OsRng);
+    let groups = split_slip39(secret, 2, &[(2, 3), (2, 3), (2, 3)], &mut OsRng).unwrap();
+    assert_eq!(groups.len(), 3);
+    assert!(groups.iter().all(|members| members.len() == 3));
+
+    // 组 1 和组 3 各有两名成员到场
+    let shares = vec![groups[0][0], groups[0][2], groups[2][1], groups[2][2]];
+    assert_eq!(reconstruct_slip39(&shares), Ok(secret));
+
+    // 组 2 只有一名成员，不计入；加上完整的组 1 仍然只有一个组满足门限
+    let shares = vec![groups[0][0], groups[0][1], groups[1][0]];
+    assert_eq!(reconstruct_slip39(&shares), Err(ReconstructError::InsufficientShares));
+
+    // 三个组全部到场且某组多出成员也可以恢复
+    let shares: Vec<_> = groups.iter().flatten().copied().collect();
+    assert_eq!(reconstruct_slip39(&shares), Ok(secret));
+}
+
+#[test]
+fn test_slip39_rejects_invalid_input() {
+    let secret = Scalar::ONE;
+    assert_eq!(split_slip39(secret, 3, &[(2, 3), (2, 3)], &mut OsRng), Err(ShareError::ThresholdExceedsShares));
+    assert_eq!(split_slip39(secret, 1, &[(2, 3), (0, 3)], &mut OsRng), Err(ShareError::ZeroThreshold));
+    assert_eq!(reconstruct_slip39::<Scalar>(&[]), Err(ReconstructError::InsufficientShares));
+
+    let groups = split_slip39(secret, 1, &[(2, 3)], &mut OsRng).unwrap();
+    assert_eq!(reconstruct_slip39(&[groups[0][0], groups[0][0]]), Err(ReconstructError::DuplicateShareIndex));
+
+    let mut tampered = groups[0][1];
+    tampered.group_threshold = 2;
+    assert_eq!(reconstruct_slip39(&[groups[0][0], tampered]), Err(ReconstructError::InconsistentShares));
+}
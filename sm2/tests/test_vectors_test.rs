@@ -0,0 +1,73 @@
+use shamir_secret_sharing::secret_sharing::{reconstruct_secret, verify_share_with_feldman_vss, verify_share_with_pedersen_vss};
+use shamir_secret_sharing::test_vectors::{test_vector_h, verify_test_vector, Scheme, TestVector, TEST_VECTORS};
+use sm2::elliptic_curve::group::GroupEncoding;
+use sm2::elliptic_curve::PrimeField;
+use sm2::{FieldBytes, ProjectivePoint, Scalar};
+
+fn decode(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+fn scalar(bytes: &[u8]) -> Scalar {
+    let bytes: [u8; 32] = bytes.try_into().unwrap();
+    Scalar::from_repr(FieldBytes::from(bytes)).unwrap()
+}
+
+fn point(hex: &str) -> ProjectivePoint {
+    let mut repr = <ProjectivePoint as GroupEncoding>::Repr::default();
+    repr.copy_from_slice(&decode(hex));
+    ProjectivePoint::from_bytes(&repr).unwrap()
+}
+
+#[test]
+fn test_recomputation_matches_vectors() {
+    for vector in &TEST_VECTORS {
+        assert!(verify_test_vector(vector), "{:?} vector does not match", vector.scheme);
+    }
+}
+
+#[test]
+fn test_vectors_are_self_consistent() {
+    let g = ProjectivePoint::GENERATOR;
+    for vector in &TEST_VECTORS {
+        let secret = scalar(&decode(vector.secret));
+        let shares: Vec<(Scalar, Scalar)> = vector
+            .shares
+            .iter()
+            .map(|hex| {
+                let bytes = decode(hex);
+                (scalar(&bytes[..32]), scalar(&bytes[32..]))
+            })
+            .collect();
+        assert_eq!(shares.len(), vector.n);
+        assert_eq!(reconstruct_secret(&shares[..vector.t]), secret);
+
+        let commitments: Vec<ProjectivePoint> = vector.commitments.iter().map(|hex| point(hex)).collect();
+        match vector.scheme {
+            Scheme::Shamir => assert!(commitments.is_empty()),
+            Scheme::Feldman => {
+                assert_eq!(commitments[0], g * secret);
+                assert!(shares.iter().all(|&share| verify_share_with_feldman_vss(share, &commitments, g)));
+            }
+            Scheme::Pedersen => {
+                for (&share, hex) in shares.iter().zip(vector.blinding_values) {
+                    let r = scalar(&decode(hex));
+                    assert!(verify_share_with_pedersen_vss(share, r, &commitments, g, test_vector_h()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_modified_vector_is_rejected() {
+    let original = TEST_VECTORS[1];
+    let other_seed = TestVector {
+        seed: "0303030303030303030303030303030303030303030303030303030303030303",
+        ..original
+    };
+    assert!(!verify_test_vector(&other_seed));
+    assert!(!verify_test_vector(&TestVector { shares: &original.shares[1..], ..original }));
+    assert!(!verify_test_vector(&TestVector { commitments: &original.commitments[..2], ..original }));
+    assert!(!verify_test_vector(&TestVector { scheme: Scheme::Shamir, ..original }));
+}
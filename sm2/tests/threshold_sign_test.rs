@@ -0,0 +1,60 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::secret_sharing::verify_share_with_feldman_vss;
+use shamir_secret_sharing::threshold_sign::{
+    challenge, combine_partial_signatures, commit_nonce, message_digest, partial_sign, split_signing_key,
+    ThresholdSignError,
+};
+use sm2::dsa::signature::Verifier;
+use sm2::dsa::VerifyingKey;
+use sm2::elliptic_curve::ff::Field;
+use sm2::{ProjectivePoint, Scalar};
+
+const DISTID: &str = "example@rustcrypto.org";
+
+#[test]
+fn test_three_of_five_threshold_signature() {
+    let mut rng = OsRng;
+    let d = Scalar::random(&mut rng);
+    let key = split_signing_key(d, 5, 3, &mut rng).unwrap();
+    assert_eq!(key.public_key, ProjectivePoint::GENERATOR * d);
+    for &share in &key.shares {
+        assert!(verify_share_with_feldman_vss(share, &key.commitments, ProjectivePoint::GENERATOR));
+    }
+
+    let msg = b"threshold signed message";
+    let digest = message_digest(DISTID, &key.public_key, msg);
+
+    // 参与者 1、3、5 签名
+    let signers = [key.shares[0], key.shares[2], key.shares[4]];
+    let signer_xs: Vec<Scalar> = signers.iter().map(|&(x, _)| x).collect();
+    let (nonces, nonce_commitments): (Vec<_>, Vec<_>) =
+        signers.iter().map(|_| commit_nonce(&key.public_key, &mut rng)).unzip();
+    let r = challenge(&digest, &nonce_commitments).unwrap();
+    let partials: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(&share, nonce)| partial_sign(share, nonce, &signer_xs, r).unwrap())
+        .collect();
+    let signature = combine_partial_signatures(&partials, r).unwrap();
+
+    let verifying_key = VerifyingKey::from_affine(DISTID, key.public_key.to_affine()).unwrap();
+    assert!(verifying_key.verify(msg, &signature).is_ok());
+    assert!(verifying_key.verify(b"another message", &signature).is_err());
+}
+
+#[test]
+fn test_threshold_sign_errors() {
+    let mut rng = OsRng;
+    assert_eq!(split_signing_key(Scalar::ZERO, 5, 3, &mut rng).unwrap_err(), ThresholdSignError::InvalidKey);
+    assert_eq!(split_signing_key(-Scalar::ONE, 5, 3, &mut rng).unwrap_err(), ThresholdSignError::InvalidKey);
+
+    let key = split_signing_key(Scalar::random(&mut rng), 5, 3, &mut rng).unwrap();
+    let xs = [key.shares[0].0, key.shares[1].0];
+    let (nonce, _) = commit_nonce(&key.public_key, &mut rng);
+    assert_eq!(partial_sign(key.shares[3], nonce, &xs, Scalar::ONE), Err(ThresholdSignError::SignerNotInSet));
+    let (nonce, _) = commit_nonce(&key.public_key, &mut rng);
+    assert_eq!(
+        partial_sign(key.shares[0], nonce, &[xs[0], xs[0]], Scalar::ONE),
+        Err(ThresholdSignError::InvalidSignerSet)
+    );
+}
@@ -0,0 +1,33 @@
+// 非密码学安全的随机数生成器不能用于生成份额
+use rand::RngCore;
+use shamir_secret_sharing::secret_sharing::generate_shares;
+use sm2::Scalar;
+
+struct CounterRng(u64);
+
+impl RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 += 1;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.next_u64() as u8;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+fn main() {
+    let mut rng = CounterRng(0);
+    let _ = generate_shares(Scalar::from(1u64), 5, 3, &mut rng);
+}
@@ -0,0 +1,30 @@
+// 这些测试只在 wasm32 目标上编译，本机的 cargo test 会跳过它们。在浏览器中运行：
+//
+//     wasm-pack test --headless --firefox --features wasm
+//
+// 也可以改用 --chrome。
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use shamir_secret_sharing::wasm::{generate_shares, reconstruct_secret};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const SECRET: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+#[wasm_bindgen_test]
+fn test_wasm_split_and_reconstruct() {
+    let json = generate_shares(SECRET, 5, 3).unwrap();
+    let shares: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+    assert_eq!(shares.len(), 5);
+
+    let subset = serde_json::Value::Array(shares[1..4].to_vec()).to_string();
+    assert_eq!(reconstruct_secret(&subset).unwrap(), SECRET);
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_rejects_invalid_input() {
+    assert!(generate_shares("not hex", 5, 3).is_err());
+    assert!(generate_shares(SECRET, 3, 5).is_err());
+    assert!(reconstruct_secret("[{\"x\": 1}]").is_err());
+}
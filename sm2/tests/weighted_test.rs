@@ -0,0 +1,27 @@
+use rand::rngs::OsRng;
+use shamir_secret_sharing::secret_sharing::reconstruct_secret;
+use shamir_secret_sharing::weighted::generate_weighted_shares;
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+
+#[test]
+fn test_weighted_shares() {
+    let mut rng = OsRng;
+    let secret = Scalar::random(&mut rng);
+    let participants = generate_weighted_shares(secret, &[1, 2, 3], 4, &mut rng);
+
+    // 每个参与者得到与权重相同个数的份额，x 坐标互不重复
+    let counts: Vec<usize> = participants.iter().map(|p| p.len()).collect();
+    assert_eq!(counts, vec![1, 2, 3]);
+    let xs: Vec<Scalar> = participants.iter().flatten().map(|&(x, _)| x).collect();
+    let expected: Vec<Scalar> = (1..=6u64).map(Scalar::from).collect();
+    assert_eq!(xs, expected);
+
+    // 参与者 1 和 2 的权重之和为 3，无法恢复
+    let combined: Vec<(Scalar, Scalar)> = [&participants[0], &participants[1]].into_iter().flatten().copied().collect();
+    assert_ne!(reconstruct_secret(&combined), secret);
+
+    // 参与者 1 和 3 的权重之和为 4，可以恢复
+    let combined: Vec<(Scalar, Scalar)> = [&participants[0], &participants[2]].into_iter().flatten().copied().collect();
+    assert_eq!(reconstruct_secret(&combined), secret);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "zeroize")]
+
+use std::mem::MaybeUninit;
+use std::ptr;
+use shamir_secret_sharing::gf257::Gf257;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::secret::SecretScalar;
+use shamir_secret_sharing::secret_sharing::{generate_shares, reconstruct_and_consume, reconstruct_secret};
+use sm2::elliptic_curve::ff::Field;
+use sm2::Scalar;
+use zeroize::Zeroize;
+
+#[test]
+fn test_secret_scalar_cleared_on_drop() {
+    let mut rng = rand::thread_rng();
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+    let recovered = SecretScalar::from(reconstruct_secret(&shares[0..3]));
+    assert_eq!(*recovered.reveal(), secret);
+
+    // 在原地执行析构，析构后的内存仍然属于当前栈帧，可以逐字节检查
+    let mut slot = MaybeUninit::new(recovered);
+    let bytes = unsafe {
+        ptr::drop_in_place(slot.as_mut_ptr());
+        ptr::read(slot.as_ptr() as *const [u8; std::mem::size_of::<SecretScalar>()])
+    };
+    assert!(bytes.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_polynomial_coefficients_cleared() {
+    let mut rng = rand::thread_rng();
+    let mut poly = Polynomial::new(Scalar::random(&mut rng), 4, &mut rng);
+    let len = poly.coefficients().len();
+    let buffer = poly.coefficients().as_ptr();
+
+    // 清除后系数缓冲区仍未释放，其中的每个系数都应为 0
+    poly.zeroize();
+    assert!(poly.coefficients().is_empty());
+    for i in 0..len {
+        assert_eq!(unsafe { ptr::read(buffer.add(i)) }, Scalar::ZERO);
+    }
+}
+
+#[test]
+fn test_polynomial_over_gf257_cleared() {
+    let mut rng = rand::thread_rng();
+    let mut poly = Polynomial::new(Gf257::new(200), 3, &mut rng);
+    let len = poly.coefficients().len();
+    let buffer = poly.coefficients().as_ptr();
+
+    poly.zeroize();
+    assert!(poly.coefficients().is_empty());
+    for i in 0..len {
+        assert_eq!(unsafe { ptr::read(buffer.add(i)) }, Gf257::ZERO);
+    }
+}
+
+#[test]
+fn test_reconstruct_and_consume() {
+    let mut rng = rand::thread_rng();
+    let secret = Scalar::random(&mut rng);
+    let shares = generate_shares(secret, 5, 3, &mut rng);
+    assert_eq!(reconstruct_and_consume(shares[1..4].to_vec()), secret);
+    assert_eq!(reconstruct_and_consume(shares), secret);
+}
+
+#[test]
+#[should_panic]
+fn test_reconstruct_and_consume_duplicate_index() {
+    let mut rng = rand::thread_rng();
+    let shares = generate_shares(Scalar::random(&mut rng), 3, 2, &mut rng);
+    reconstruct_and_consume(vec![shares[0], shares[0]]);
+}